@@ -1,23 +1,50 @@
+use crate::badge_service::{BadgeBackend, BadgeHosts};
 use crate::badges::{
-    Badge, badge_for_codecov, badge_for_crates, badge_for_crates_downloads, badge_for_crates_msrv,
-    badge_for_docs_rs, badge_for_docs_url, badge_for_github_downloads, badge_for_github_forks,
-    badge_for_github_issues, badge_for_github_last_commit, badge_for_github_pull_requests,
-    badge_for_github_release, badge_for_github_stars, badge_for_license, badge_for_license_text,
-    badge_for_moonbit, badge_for_npm, badge_for_npm_downloads, badge_for_workflow, dedupe_badges,
+    Badge, BadgeKind, badge_for_azure_pipelines, badge_for_best_practices, badge_for_buildkite,
+    badge_for_circleci, badge_for_codacy, badge_for_codecov, badge_for_coveralls, badge_for_crates,
+    badge_for_crates_downloads, badge_for_crates_msrv, badge_for_docker_image_size,
+    badge_for_docker_pulls, badge_for_docker_version, badge_for_docs_rs, badge_for_docs_url,
+    badge_for_forgejo_workflow, badge_for_ghcr_image_size, badge_for_ghcr_version,
+    badge_for_github_downloads, badge_for_github_forks, badge_for_github_issues,
+    badge_for_github_last_commit, badge_for_github_pull_requests, badge_for_github_stars,
+    badge_for_gitlab_coverage, badge_for_gitlab_pipeline, badge_for_go_reference,
+    badge_for_go_report_card, badge_for_go_version, badge_for_jenkins, badge_for_jsr,
+    badge_for_jsr_score, badge_for_license, badge_for_license_text, badge_for_moonbit,
+    badge_for_node_engine, badge_for_npm, badge_for_npm_downloads, badge_for_pypi,
+    badge_for_pypi_downloads, badge_for_pypi_python_versions, badge_for_release,
+    badge_for_rust_edition, badge_for_rust_version, badge_for_scorecard, badge_for_security_policy,
+    badge_for_self_hosted_codecov, badge_for_sonarcloud, badge_for_travis, badge_for_woodpecker,
+    badge_for_workflow, dedupe_badges, render_picture_badge,
 };
-use crate::config::{Config, load_config};
+use crate::config::{Config, RenderMode, Theme, load_config};
 use crate::core::{ProjectContext, build_context};
+use crate::coverage::{
+    CoverageProvider, codecov_url, detect_coverage_providers, select_coverage_providers,
+    sonar_project,
+};
+use crate::forge::{ForgeKind, RepoRef};
+use crate::github::latest_run_status;
 use crate::inspect::build_list_json;
-use crate::manifest::{read_moon_mod, read_resolved_cargo_package};
+use crate::manifest::{
+    ResolvedCargoPackage, cargo_workspace_members, find_cargo_workspace_manifest,
+    read_declared_readme, read_go_mod, read_jsr_manifest, read_moon_mod, read_npm_workspace,
+    read_resolved_cargo_package, read_resolved_python_package,
+};
 use crate::plan::ReadmePlan;
-use crate::project::{infer_owner_repo, local_npm_packages, resolve_metadata};
+use crate::project::{NpmPackage, local_npm_packages, repository_to_string, resolve_metadata};
 use crate::readme::{
-    ensure_marker_block, extract_managed_block, readme_newline_info, remove_marker_block,
-    resolve_readme, rewrite_marker_block, rewrite_marker_block_lines,
+    ReadmeFormat, badge_block_lines, ensure_marker_block, extract_managed_block,
+    is_block_container, readme_newline_info, remove_marker_block, rewrite_marker_block,
+    rewrite_marker_block_lines, select_readme,
 };
-use crate::readme_remove::remove_block_lines_by_id_kind;
+use crate::readme_remove::remove_block_lines_by_id_kind_with;
+use crate::security::detect_security;
 use crate::version::VersionOptions;
-use crate::workflows::{detect_workflows, detects_codecov, gh_latest_status_json_in};
+use crate::workflows::{
+    CiService, ContainerRegistry, WorkflowInfo, detect_ci_services, detect_container_images,
+    detect_forgejo_workflows, detect_gitlab_ci, detect_woodpecker, detect_workflows,
+    is_selected_ci_workflow, select_ci_workflows, workflow_badge_scope,
+};
 use serde::Serialize;
 use std::collections::HashSet;
 use std::path::Path;
@@ -32,6 +59,7 @@ enum AddMode {
 
 pub fn cmd_add(
     current_dir: &Path,
+    readme: Option<&Path>,
     yes: bool,
     only: &[String],
     allow_yy_calver: bool,
@@ -40,6 +68,7 @@ pub fn cmd_add(
 ) -> anyhow::Result<i32> {
     cmd_add_inner(
         current_dir,
+        readme,
         AddMode::Add,
         yes,
         only,
@@ -51,6 +80,7 @@ pub fn cmd_add(
 
 pub fn cmd_sync(
     current_dir: &Path,
+    readme: Option<&Path>,
     only: &[String],
     allow_yy_calver: bool,
     dry_run: bool,
@@ -58,6 +88,7 @@ pub fn cmd_sync(
 ) -> anyhow::Result<i32> {
     cmd_add_inner(
         current_dir,
+        readme,
         AddMode::Sync,
         true,
         only,
@@ -67,88 +98,204 @@ pub fn cmd_sync(
     )
 }
 
-#[allow(clippy::too_many_arguments)]
-fn cmd_add_inner(
+/// Syncs the own README of every Cargo workspace member and npm/pnpm/yarn
+/// workspace package, plus an aggregate block in the workspace README. Members
+/// without a README (or with `readme = false`) and private packages are skipped.
+/// A `--readme` override applies to the workspace README only.
+pub fn cmd_sync_workspace(
     current_dir: &Path,
-    mode: AddMode,
-    yes: bool,
+    readme: Option<&Path>,
     only: &[String],
-    allow_yy_calver: bool,
     dry_run: bool,
     json: bool,
 ) -> anyhow::Result<i32> {
     let context = build_context(current_dir)?;
     let config = load_config_for_context(current_dir, &context)?;
-    let options = version_options(&context, Some((allow_yy_calver, &config)));
-    let readme_path = resolve_readme(&context.root, context.has_moonbit());
-    let npm_packages = local_npm_packages(&context);
-    let metadata = resolve_metadata(&context, Some(&npm_packages))?;
-    let (owner, repo) = infer_owner_repo(&metadata.repository);
-    let workflows = detect_workflows(&context.root);
+    let cargo_workspace = find_cargo_workspace_manifest(current_dir, &context.root);
+    let workspace_root = match &cargo_workspace {
+        Some(manifest) => manifest.parent().unwrap_or(&context.root).to_path_buf(),
+        None if read_npm_workspace(&context.root).is_some() => context.root.clone(),
+        None => anyhow::bail!("no Cargo or npm workspace found for --workspace"),
+    };
+    let crates = match &cargo_workspace {
+        Some(manifest) => cargo_workspace_members(manifest)?
+            .into_iter()
+            .filter_map(|path| read_resolved_cargo_package(&path).ok().flatten())
+            .collect::<Vec<_>>(),
+        None => Vec::new(),
+    };
 
-    let mut candidates = Vec::new();
-    for package in npm_packages.iter().filter(|package| package.published) {
-        candidates.push(badge_for_npm(&package.name));
-        candidates.push(badge_for_npm_downloads(&package.name));
-        if let Some(homepage) = package
-            .registry
-            .homepage
-            .as_deref()
-            .map(str::trim)
-            .filter(|homepage| !homepage.is_empty())
-        {
-            candidates.push(badge_for_docs_url(homepage));
+    let root_context = build_context(&workspace_root)?;
+    let root_readme = select_readme(&workspace_root, &root_context, &config, readme).path;
+    let npm_packages = local_npm_packages(&root_context)
+        .into_iter()
+        .filter(|package| package.path.parent() != Some(workspace_root.as_path()))
+        .collect::<Vec<_>>();
+    let published = crates
+        .iter()
+        .filter(|package| package.is_publishable())
+        .filter_map(|package| package.name.clone())
+        .collect::<Vec<_>>();
+    let root_candidates = collect_candidates(
+        &root_context,
+        &CandidateScope::Workspace { crates: published },
+        &config,
+    )?;
+    let repo_ref = project_repo_ref(&root_context, None, &config);
+
+    let mut members = Vec::new();
+    for package in &crates {
+        let (Some(name), Some(readme)) = (package.name.as_deref(), package.readme.clone()) else {
+            continue;
+        };
+        let mut candidates = Vec::new();
+        if package.is_publishable() {
+            candidates.extend(crate_badges(name, package));
         }
+        candidates.extend(cargo_toolchain_badges(package));
+        candidates.extend(member_license_badge(
+            package.license.as_deref(),
+            package.repository.as_deref(),
+            repo_ref.as_ref(),
+        ));
+        members.push((readme, candidates));
     }
-    if let Some(path) = &context.manifests.cargo_toml
-        && let Ok(package) = read_resolved_cargo_package(path)
-        && let Some(name) = package.and_then(|package| package.name)
-    {
-        candidates.push(badge_for_crates(&name));
-        candidates.push(badge_for_crates_downloads(&name));
-        candidates.push(badge_for_crates_msrv(&name));
-        candidates.push(badge_for_docs_rs(&name));
-    }
-    if let Some(path) = &context.manifests.moon_mod
-        && let Ok(module) = read_moon_mod(path)
-        && let Some(name) = module.name.as_deref()
-    {
-        candidates.push(badge_for_moonbit(name));
-        if name.contains('/') {
-            candidates.push(badge_for_docs_url(&format!(
-                "https://mooncakes.io/docs/{}",
-                name
-            )));
+    for package in &npm_packages {
+        let Some(readme) = read_declared_readme(&package.path)
+            .or_else(|| package.path.parent().map(|dir| dir.join("README.md")))
+            .filter(|path| path.is_file())
+        else {
+            continue;
+        };
+        let mut candidates = Vec::new();
+        if package.published {
+            candidates.extend(npm_badges(package));
         }
-    }
-    if let Some(license) = metadata
-        .license
-        .as_deref()
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-    {
-        candidates.push(badge_for_license_text(
-            license,
-            metadata.repository.as_deref(),
+        candidates.extend(package.node_engine.as_deref().map(badge_for_node_engine));
+        let repository = package
+            .registry
+            .repository
+            .clone()
+            .or_else(|| repository_to_string(package.repository.clone()));
+        candidates.extend(member_license_badge(
+            package
+                .registry
+                .license
+                .as_deref()
+                .or(package.license.as_deref()),
+            repository.as_deref(),
+            repo_ref.as_ref(),
         ));
-    } else if let (Some(owner), Some(repo)) = (owner.as_deref(), repo.as_deref()) {
-        candidates.push(badge_for_license(owner, repo));
+        members.push((readme, candidates));
     }
-    if let (Some(owner), Some(repo)) = (owner.as_deref(), repo.as_deref()) {
-        candidates.push(badge_for_github_release(owner, repo));
-        candidates.push(badge_for_github_downloads(owner, repo));
-        candidates.push(badge_for_github_stars(owner, repo));
-        candidates.push(badge_for_github_forks(owner, repo));
-        candidates.push(badge_for_github_issues(owner, repo));
-        candidates.push(badge_for_github_pull_requests(owner, repo));
-        candidates.push(badge_for_github_last_commit(owner, repo));
-        if detects_codecov(&context.root) {
-            candidates.push(badge_for_codecov(owner, repo));
+
+    let mut plans = vec![plan_sync(&root_readme, root_candidates, only, &config)?];
+    for (readme, candidates) in members {
+        if plans.iter().any(|plan| same_file(plan.path(), &readme)) {
+            continue;
         }
-        for workflow in workflows {
-            candidates.push(badge_for_workflow(owner, repo, &workflow.file));
+        plans.push(plan_sync(&readme, candidates, only, &config)?);
+    }
+
+    let changed = plans.iter().any(ReadmePlan::changed);
+    if dry_run {
+        if json {
+            let payload = WorkspaceDryRunJson {
+                schema: "bdg.dryrun.workspace/v1".to_string(),
+                plans: plans
+                    .iter()
+                    .map(|plan| DryRunJson {
+                        schema: "bdg.dryrun/v1".to_string(),
+                        path: plan.path().to_string_lossy().to_string(),
+                        diff: plan.diff_with_path(&relative_display(plan.path(), &workspace_root)),
+                        removed_ids: None,
+                        missing_ids: None,
+                        removed_kinds: None,
+                        warnings: Vec::new(),
+                    })
+                    .collect(),
+            };
+            serde_json::to_writer_pretty(std::io::stdout(), &payload)?;
+            println!();
+        } else {
+            for plan in &plans {
+                print_diff(&plan.diff_with_path(&relative_display(plan.path(), &workspace_root)));
+            }
         }
+        return Ok(if changed { 2 } else { 0 });
+    }
+    for plan in &plans {
+        plan.apply()?;
+    }
+    Ok(0)
+}
+
+fn member_license_badge(
+    license: Option<&str>,
+    repository: Option<&str>,
+    repo: Option<&RepoRef>,
+) -> Option<Badge> {
+    if let Some(license) = license.map(str::trim).filter(|license| !license.is_empty()) {
+        return Some(badge_for_license_text(license, repository));
     }
+    repo.filter(|repo| repo.shields_reachable())
+        .and_then(badge_for_license)
+}
+
+fn plan_sync(
+    readme_path: &Path,
+    candidates: Vec<Badge>,
+    only: &[String],
+    config: &Config,
+) -> anyhow::Result<ReadmePlan> {
+    let mut filtered = filter_badges(dedupe_badges(candidates), only, config);
+    if only.is_empty() {
+        filtered.retain(|badge| badge.sync_default);
+    }
+    let content = ensure_marker_block(readme_path)?;
+    let format = ReadmeFormat::of(readme_path, &content);
+    let hosts = BadgeHosts::from_config(config);
+    let rendered = filtered
+        .iter()
+        .map(|badge| render_badge(badge, format, &hosts, config))
+        .collect();
+    let updated = rewrite_marker_block(
+        &content,
+        &badge_block_lines(rendered, format, &config.render),
+    )?;
+    Ok(ReadmePlan::new(readme_path, content, updated))
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn relative_display(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cmd_add_inner(
+    current_dir: &Path,
+    readme: Option<&Path>,
+    mode: AddMode,
+    yes: bool,
+    only: &[String],
+    allow_yy_calver: bool,
+    dry_run: bool,
+    json: bool,
+) -> anyhow::Result<i32> {
+    let context = build_context(current_dir)?;
+    let config = load_config_for_context(current_dir, &context)?;
+    let options = version_options(&context, Some((allow_yy_calver, &config)));
+    let readme_path = select_readme(current_dir, &context, &config, readme).path;
+    let candidates = collect_candidates(&context, &CandidateScope::Project, &config)?;
 
     let mut filtered = filter_badges(dedupe_badges(candidates), only, &config);
     if mode == AddMode::Sync && only.is_empty() {
@@ -164,7 +311,7 @@ fn cmd_add_inner(
     } else {
         let items: Vec<String> = filtered
             .iter()
-            .map(|badge| format_badge_label(badge, &context, &options))
+            .map(|badge| format_badge_label(badge, &context, &config, &options))
             .collect();
         let recommended = recommended_indices(&filtered);
         let selection = crate::tui::run_multi_select(
@@ -182,9 +329,17 @@ fn cmd_add_inner(
             .filter_map(|idx| filtered.get(idx).cloned())
             .collect()
     };
-    let markdown: Vec<String> = selected.into_iter().map(|b| b.render_markdown()).collect();
     let content = ensure_marker_block(&readme_path)?;
-    let updated = rewrite_marker_block(&content, &markdown)?;
+    let format = ReadmeFormat::of(&readme_path, &content);
+    let hosts = BadgeHosts::from_config(&config);
+    let rendered = selected
+        .iter()
+        .map(|badge| render_badge(badge, format, &hosts, &config))
+        .collect();
+    let updated = rewrite_marker_block(
+        &content,
+        &badge_block_lines(rendered, format, &config.render),
+    )?;
     let plan = ReadmePlan::new(readme_path.clone(), content, updated);
     let diff = plan.diff();
     if dry_run {
@@ -209,8 +364,462 @@ fn cmd_add_inner(
     Ok(0)
 }
 
+/// Labels a workflow badge with the workflow's `name:` when it has one.
+fn workflow_badge(badge: Badge, workflow: &WorkflowInfo) -> Badge {
+    if workflow.named {
+        Badge {
+            label: workflow.name.clone(),
+            ..badge
+        }
+    } else {
+        badge
+    }
+}
+
+/// Which packages contribute registry candidates.
+enum CandidateScope {
+    /// The packages chosen by manifest detection.
+    Project,
+    /// Workspace aggregate: one registry version badge per publishable member
+    /// crate and published npm package.
+    Workspace { crates: Vec<String> },
+}
+
+/// Repository that forge badges point at: the manifest repository URL, else the
+/// selected git remote, with the `.bdg.toml` `[forge]` settings applied.
+fn project_repo_ref(
+    context: &ProjectContext,
+    repository: Option<&str>,
+    config: &Config,
+) -> Option<RepoRef> {
+    repository
+        .and_then(|url| config.forge.parse_repo_url(url))
+        .or_else(|| {
+            context
+                .git
+                .as_ref()
+                .and_then(|git| git.repo_ref(&config.forge))
+        })
+}
+
+/// Woodpecker server for `repo`: the configured one, or Codeberg's public
+/// instance for Codeberg repositories.
+fn woodpecker_server(repo: &RepoRef, config: &Config) -> Option<String> {
+    config
+        .forge
+        .woodpecker_url
+        .clone()
+        .or_else(|| (repo.host == "codeberg.org").then(|| "https://ci.codeberg.org".to_string()))
+}
+
+/// Status badge for a detected CI service. Services whose badge URL cannot be
+/// derived from the repository need their `[ci.<service>]` settings. Every
+/// service reports on the remote default branch, or on its own default when
+/// that is unknown, so the badge does not depend on the checked-out branch.
+fn ci_service_badge(
+    service: CiService,
+    repo: Option<&RepoRef>,
+    context: &ProjectContext,
+    config: &Config,
+) -> Option<Badge> {
+    let default_branch = context
+        .git
+        .as_ref()
+        .and_then(|git| git.default_branch.as_deref());
+    match service {
+        CiService::CircleCi => badge_for_circleci(repo?, default_branch),
+        CiService::Travis => badge_for_travis(repo?, default_branch),
+        CiService::AzurePipelines => {
+            let azure = config.ci.azure_pipelines.as_ref()?;
+            let pipeline = match &azure.pipeline {
+                Some(pipeline) => pipeline.clone(),
+                None => repo.map(|repo| format!("{}.{}", repo.owner, repo.repo))?,
+            };
+            Some(badge_for_azure_pipelines(
+                &azure.organization,
+                &azure.project,
+                &pipeline,
+                default_branch,
+            ))
+        }
+        CiService::Buildkite => {
+            let buildkite = config.ci.buildkite.as_ref()?;
+            Some(badge_for_buildkite(
+                &buildkite.badge,
+                buildkite.pipeline.as_deref(),
+                default_branch,
+            ))
+        }
+        CiService::Jenkins => {
+            let jenkins = config.ci.jenkins.as_ref()?;
+            let job = match &jenkins.job {
+                Some(job) => job.clone(),
+                None => repo?.repo.clone(),
+            };
+            Some(badge_for_jenkins(&jenkins.url, &job))
+        }
+    }
+}
+
+/// Opt-in `security` badges: OpenSSF Scorecard and Best Practices, dependency
+/// audit workflows, and the security policy.
+fn security_badges(repo: &RepoRef, context: &ProjectContext, config: &Config) -> Vec<Badge> {
+    let workflows = match repo.kind {
+        ForgeKind::GitHub => detect_workflows(&context.root),
+        ForgeKind::Forgejo => detect_forgejo_workflows(&context.root),
+        ForgeKind::GitLab => Vec::new(),
+    };
+    let security = detect_security(&context.root, &workflows);
+    let default_branch = context
+        .git
+        .as_ref()
+        .and_then(|git| git.default_branch.as_deref());
+    let mut badges = Vec::new();
+    if security.scorecard_workflow.is_some() {
+        badges.extend(badge_for_scorecard(repo));
+    }
+    if let Some(project) = config.security.best_practices_project {
+        badges.push(badge_for_best_practices(project));
+    }
+    // A workflow that also gets a CI badge would render the same image twice.
+    for workflow in workflows.iter().filter(|workflow| {
+        security.audit_workflows.contains(&workflow.file)
+            && !is_selected_ci_workflow(workflow, context.ci_branch(), &config.ci)
+    }) {
+        let (branch, event) = workflow_badge_scope(workflow, default_branch, &config.ci);
+        let badge = match repo.kind {
+            ForgeKind::Forgejo => badge_for_forgejo_workflow(repo, &workflow.file, branch, event),
+            _ => badge_for_workflow(repo, &workflow.file, branch, event),
+        };
+        badges.push(Badge {
+            kind: BadgeKind::Security,
+            label: if workflow.named {
+                workflow.name.clone()
+            } else {
+                "security audit".to_string()
+            },
+            sync_default: false,
+            ..badge
+        });
+    }
+    if let Some(policy) = &security.policy {
+        badges.push(badge_for_security_policy(repo, policy, context.ci_branch()));
+    }
+    badges
+}
+
+fn coverage_badge(
+    provider: CoverageProvider,
+    repo: &RepoRef,
+    context: &ProjectContext,
+    config: &Config,
+) -> Option<Badge> {
+    let coverage = &config.coverage;
+    match provider {
+        CoverageProvider::Codecov => {
+            let server = coverage
+                .codecov
+                .as_ref()
+                .and_then(|codecov| codecov.url.clone())
+                .or_else(|| codecov_url(&context.root))
+                .filter(|url| !url.contains("://codecov.io"));
+            match server {
+                Some(server) => badge_for_self_hosted_codecov(&server, repo),
+                None => badge_for_codecov(repo),
+            }
+        }
+        CoverageProvider::Coveralls => badge_for_coveralls(repo),
+        CoverageProvider::Codacy => {
+            let codacy = coverage.codacy.as_ref()?;
+            Some(badge_for_codacy(repo, &codacy.project_id))
+        }
+        CoverageProvider::SonarCloud => {
+            let key = coverage
+                .sonarcloud
+                .as_ref()
+                .and_then(|sonar| sonar.project_key.clone())
+                .or_else(|| sonar_project(&context.root).and_then(|project| project.project_key))
+                .unwrap_or_else(|| format!("{}_{}", repo.owner.replace('/', "_"), repo.repo));
+            Some(badge_for_sonarcloud(repo, &key))
+        }
+    }
+}
+
+fn collect_candidates(
+    context: &ProjectContext,
+    scope: &CandidateScope,
+    config: &Config,
+) -> anyhow::Result<Vec<Badge>> {
+    let npm_packages = local_npm_packages(context);
+    let metadata = resolve_metadata(context, Some(&npm_packages))?;
+    let repo_ref = project_repo_ref(context, metadata.repository.as_deref(), config);
+    let owner = repo_ref.as_ref().map(|repo| repo.owner.clone());
+    let repo = repo_ref.as_ref().map(|repo| repo.repo.clone());
+    let workflows = detect_workflows(&context.root);
+    let default_branch = context
+        .git
+        .as_ref()
+        .and_then(|git| git.default_branch.as_deref());
+
+    let mut candidates = Vec::new();
+    for package in npm_packages.iter().filter(|package| package.published) {
+        match scope {
+            CandidateScope::Project => candidates.extend(npm_badges(package)),
+            CandidateScope::Workspace { .. } => candidates.push(badge_for_npm(&package.name)),
+        }
+    }
+    if let CandidateScope::Project = scope {
+        candidates.extend(
+            npm_packages
+                .iter()
+                .filter_map(|package| package.node_engine.as_deref())
+                .map(badge_for_node_engine),
+        );
+    }
+    match scope {
+        CandidateScope::Project => {
+            if let Some(path) = &context.manifests.cargo_toml
+                && let Ok(Some(package)) = read_resolved_cargo_package(path)
+            {
+                if let Some(name) = package.name.as_deref() {
+                    candidates.extend(crate_badges(name, &package));
+                }
+                candidates.extend(cargo_toolchain_badges(&package));
+            }
+        }
+        CandidateScope::Workspace { crates } => {
+            candidates.extend(crates.iter().map(|name| badge_for_crates(name)));
+        }
+    }
+    if let Some(path) = &context.manifests.pyproject_toml
+        && let Ok(package) = read_resolved_python_package(path)
+        && let Some(name) = package.and_then(|package| package.name)
+    {
+        candidates.push(badge_for_pypi(&name));
+        candidates.push(badge_for_pypi_downloads(&name));
+        candidates.push(badge_for_pypi_python_versions(&name));
+    }
+    if let Some(path) = &context.manifests.go_mod
+        && let Ok(module) = read_go_mod(path)
+        && let Some(name) = module.module.as_deref()
+    {
+        candidates.push(badge_for_go_reference(name));
+        if let Some(go) = module.go.as_deref() {
+            candidates.push(badge_for_go_version(go));
+        }
+        candidates.push(badge_for_go_report_card(name));
+    }
+    if let Some(path) = &context.manifests.jsr_manifest
+        && let Ok(manifest) = read_jsr_manifest(path)
+        && let Some(name) = manifest.name.as_deref()
+    {
+        candidates.push(badge_for_jsr(name));
+        candidates.push(badge_for_jsr_score(name));
+    }
+    if let Some(path) = &context.manifests.moon_mod
+        && let Ok(module) = read_moon_mod(path)
+        && let Some(name) = module.name.as_deref()
+    {
+        // `metadata` already carries the mooncakes.io version when the module
+        // is the project whose metadata won the merge.
+        let version = if metadata.name.as_deref() == Some(name) {
+            metadata.version.clone()
+        } else {
+            module.version.clone()
+        };
+        if let Some(version) = version {
+            candidates.push(badge_for_moonbit(name, &version));
+        }
+        if name.contains('/') {
+            candidates.push(badge_for_docs_url(&format!(
+                "https://mooncakes.io/docs/{}",
+                name
+            )));
+        }
+    }
+    if let Some(license) = metadata
+        .license
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    {
+        candidates.push(badge_for_license_text(
+            license,
+            metadata.repository.as_deref(),
+        ));
+    } else if let Some(repo) = repo_ref.as_ref().filter(|repo| repo.shields_reachable()) {
+        candidates.extend(badge_for_license(repo));
+    }
+    if let Some(repo) = &repo_ref {
+        if repo.shields_reachable() {
+            candidates.push(badge_for_release(repo));
+        }
+        let detected = detect_coverage_providers(&context.root);
+        for provider in select_coverage_providers(&detected, &config.coverage) {
+            candidates.extend(coverage_badge(provider, repo, context, config));
+        }
+        if detect_woodpecker(&context.root).is_some()
+            && let Some(server) = woodpecker_server(repo, config)
+        {
+            candidates.push(badge_for_woodpecker(&server, repo));
+        }
+    }
+    for found in detect_ci_services(&context.root) {
+        candidates.extend(ci_service_badge(
+            found.service,
+            repo_ref.as_ref(),
+            context,
+            config,
+        ));
+    }
+    match repo_ref.as_ref() {
+        Some(repo) if repo.kind == ForgeKind::GitHub => {
+            if repo.shields_reachable() {
+                candidates.push(badge_for_github_downloads(repo));
+                candidates.push(badge_for_github_stars(repo));
+                candidates.push(badge_for_github_forks(repo));
+                candidates.push(badge_for_github_issues(repo));
+                candidates.push(badge_for_github_pull_requests(repo));
+                candidates.push(badge_for_github_last_commit(repo));
+            }
+            for workflow in select_ci_workflows(workflows, context.ci_branch(), &config.ci) {
+                let (branch, event) = workflow_badge_scope(&workflow, default_branch, &config.ci);
+                candidates.push(workflow_badge(
+                    badge_for_workflow(repo, &workflow.file, branch, event),
+                    &workflow,
+                ));
+            }
+        }
+        Some(repo) if repo.kind == ForgeKind::GitLab => {
+            if let Some(ci) = detect_gitlab_ci(&context.root) {
+                // GitLab badge URLs need a ref; the checked-out branch stands in
+                // for an unknown default branch so `check` finds it.
+                let branch = context.ci_branch();
+                candidates.push(badge_for_gitlab_pipeline(repo, branch));
+                if ci.coverage {
+                    candidates.push(badge_for_gitlab_coverage(repo, branch));
+                }
+            }
+        }
+        Some(repo) if repo.kind == ForgeKind::Forgejo => {
+            let workflows = detect_forgejo_workflows(&context.root);
+            for workflow in select_ci_workflows(workflows, context.ci_branch(), &config.ci) {
+                let (branch, event) = workflow_badge_scope(&workflow, default_branch, &config.ci);
+                candidates.push(workflow_badge(
+                    badge_for_forgejo_workflow(repo, &workflow.file, branch, event),
+                    &workflow,
+                ));
+            }
+        }
+        _ => {}
+    }
+    if let Some(repo) = &repo_ref {
+        candidates.extend(security_badges(repo, context, config));
+    }
+    for image in detect_container_images(&context.root, owner.as_deref(), repo.as_deref()) {
+        match image.registry {
+            ContainerRegistry::Ghcr if config.badges.ghcr_enabled() => {
+                candidates.push(badge_for_ghcr_version(&image.name));
+                candidates.push(badge_for_ghcr_image_size(&image.name));
+            }
+            ContainerRegistry::DockerHub => {
+                candidates.push(badge_for_docker_version(&image.name));
+                candidates.push(badge_for_docker_image_size(&image.name));
+                candidates.push(badge_for_docker_pulls(&image.name));
+            }
+            ContainerRegistry::Ghcr => {}
+        }
+    }
+    let backend = BadgeBackend::from_config(&config.badges);
+    let hosts = BadgeHosts::from_config(config);
+    Ok(candidates
+        .into_iter()
+        .map(|badge| style_badge(backend.render(badge), &hosts, config))
+        .collect())
+}
+
+/// Applies `[style]` to badges served by shields.io or a configured shields
+/// instance; other badge services do not understand the parameters.
+fn style_badge(badge: Badge, hosts: &BadgeHosts, config: &Config) -> Badge {
+    if !hosts.is_shields(&badge.image_url) {
+        return badge;
+    }
+    let params = config.style.params(badge.kind.as_str());
+    badge.with_query_params(&params)
+}
+
+/// Renders a badge in `format`. In `picture` mode, shields badges whose
+/// `[style.dark]` and `[style.light]` variants differ get both as sources.
+fn render_badge(
+    badge: &Badge,
+    format: ReadmeFormat,
+    hosts: &BadgeHosts,
+    config: &Config,
+) -> String {
+    if format == ReadmeFormat::Markdown
+        && config.render.mode == RenderMode::Picture
+        && hosts.is_shields(&badge.image_url)
+    {
+        let variants = [Theme::Dark, Theme::Light].map(|theme| {
+            let params = config.style.theme_params(badge.kind.as_str(), theme);
+            (theme, badge.clone().with_query_params(&params).image_url)
+        });
+        if variants[0].1 != variants[1].1 {
+            return render_picture_badge(
+                &badge.label,
+                &badge.image_url,
+                &variants,
+                badge.link_url.as_deref(),
+            );
+        }
+    }
+    format.render_badge(badge, config.render.mode)
+}
+
+fn npm_badges(package: &NpmPackage) -> Vec<Badge> {
+    let mut badges = vec![
+        badge_for_npm(&package.name),
+        badge_for_npm_downloads(&package.name),
+    ];
+    if let Some(homepage) = package
+        .registry
+        .homepage
+        .as_deref()
+        .map(str::trim)
+        .filter(|homepage| !homepage.is_empty())
+    {
+        badges.push(badge_for_docs_url(homepage));
+    }
+    badges
+}
+
+/// Registry badges for a crate. The crates.io MSRV shield is left out when the
+/// manifest declares `rust-version`, which `cargo_toolchain_badges` covers.
+fn crate_badges(name: &str, package: &ResolvedCargoPackage) -> Vec<Badge> {
+    let mut badges = vec![badge_for_crates(name), badge_for_crates_downloads(name)];
+    if package.rust_version.is_none() {
+        badges.push(badge_for_crates_msrv(name));
+    }
+    badges.push(badge_for_docs_rs(name));
+    badges
+}
+
+/// Static `rust-version` and `edition` badges read straight from the manifest,
+/// so they also work for unpublished and private crates.
+fn cargo_toolchain_badges(package: &ResolvedCargoPackage) -> Vec<Badge> {
+    let mut badges = Vec::new();
+    if let Some(rust_version) = package.rust_version.as_deref() {
+        badges.push(badge_for_rust_version(rust_version));
+    }
+    if let Some(edition) = package.edition.as_deref() {
+        badges.push(badge_for_rust_edition(edition));
+    }
+    badges
+}
+
 pub fn cmd_list(
     current_dir: &Path,
+    readme: Option<&Path>,
     json: bool,
     quiet: bool,
     allow_yy_calver: bool,
@@ -218,7 +827,8 @@ pub fn cmd_list(
     let context = build_context(current_dir)?;
     let config = load_config_for_context(current_dir, &context)?;
     let options = version_options(&context, Some((allow_yy_calver, &config)));
-    let readme_path = resolve_readme(&context.root, context.has_moonbit());
+    let selection = select_readme(current_dir, &context, &config, readme);
+    let readme_path = selection.path.clone();
     let content = if readme_path.exists() {
         std::fs::read_to_string(&readme_path)?
     } else {
@@ -228,7 +838,7 @@ pub fn cmd_list(
     if json {
         let payload = build_list_json(
             &context,
-            &readme_path,
+            &selection,
             &content,
             &badges,
             &options,
@@ -257,9 +867,17 @@ pub fn cmd_list(
         );
         println!("Marker block: {marker_label}");
         println!("Badges: {}", badges.len());
+        let repo_ref = context
+            .git
+            .as_ref()
+            .and_then(|git| git.repo_ref(&config.forge));
+        let default_branch = context
+            .git
+            .as_ref()
+            .and_then(|git| git.default_branch.as_deref());
         let workflows = detect_workflows(&context.root);
         for wf in workflows {
-            let status = gh_latest_status_json_in(&context.root, &wf.file);
+            let status = latest_run_status(repo_ref.as_ref(), &wf.file, default_branch);
             if status.ok {
                 if let Some(conclusion) = status.conclusion {
                     println!("- CI {} last: {}", wf.file, conclusion);
@@ -278,6 +896,7 @@ pub fn cmd_list(
 #[allow(clippy::too_many_arguments)]
 pub fn cmd_remove(
     current_dir: &Path,
+    readme: Option<&Path>,
     all: bool,
     ids: &[String],
     kinds: &[String],
@@ -288,7 +907,9 @@ pub fn cmd_remove(
     _allow_yy_calver: bool,
 ) -> anyhow::Result<i32> {
     let context = build_context(current_dir)?;
-    let readme_path = resolve_readme(&context.root, context.has_moonbit());
+    let config = load_config_for_context(current_dir, &context)?;
+    let readme_path = select_readme(current_dir, &context, &config, readme).path;
+    let hosts = BadgeHosts::from_config(&config);
     let content = ensure_marker_block(&readme_path)?;
     let existing = extract_managed_block(&content);
     if existing.is_empty() {
@@ -301,7 +922,9 @@ pub fn cmd_remove(
     let removal_result = if all {
         None
     } else if !ids.is_empty() || !kinds.is_empty() {
-        Some(remove_block_lines_by_id_kind(&content, ids, kinds, strict)?)
+        Some(remove_block_lines_by_id_kind_with(
+            &content, ids, kinds, strict, &hosts,
+        )?)
     } else {
         None
     };
@@ -310,7 +933,7 @@ pub fn cmd_remove(
     } else if let Some(removal) = &removal_result {
         removal.remaining.clone()
     } else {
-        let items = format_remove_items(&existing);
+        let items = format_remove_items(&existing, &hosts);
         let selection = crate::tui::run_multi_select("Select badges to remove", None, &items, &[])?;
         if selection.cancelled {
             return Ok(0);
@@ -328,12 +951,19 @@ pub fn cmd_remove(
             })
             .collect()
     };
-    let updated = if remaining.is_empty() {
+    let updated = if remaining.iter().all(|line| is_block_container(line)) {
         remove_marker_block(&content)?
     } else if removal_result.is_some() {
         rewrite_marker_block_lines(&content, &remaining)?
     } else {
-        rewrite_marker_block(&content, &remaining)?
+        rewrite_marker_block(
+            &content,
+            &badge_block_lines(
+                remaining.clone(),
+                ReadmeFormat::of(&readme_path, &content),
+                &config.render,
+            ),
+        )?
     };
     let plan = ReadmePlan::new(readme_path.clone(), content, updated);
     let diff = plan.diff();
@@ -344,7 +974,10 @@ pub fn cmd_remove(
         print_remove_summary(
             readme_path.to_string_lossy().as_ref(),
             removal,
-            remaining.len(),
+            remaining
+                .iter()
+                .filter(|line| !is_block_container(line))
+                .count(),
         );
     }
     if dry_run {
@@ -412,7 +1045,12 @@ fn filter_badges(badges: Vec<Badge>, only: &[String], config: &Config) -> Vec<Ba
         .collect()
 }
 
-fn format_badge_label(badge: &Badge, context: &ProjectContext, options: &VersionOptions) -> String {
+fn format_badge_label(
+    badge: &Badge,
+    context: &ProjectContext,
+    config: &Config,
+    options: &VersionOptions,
+) -> String {
     match badge.kind {
         crate::badges::BadgeKind::Ci => {
             let workflow = badge
@@ -421,7 +1059,15 @@ fn format_badge_label(badge: &Badge, context: &ProjectContext, options: &Version
                 .nth(1)
                 .and_then(|rest| rest.split('/').next())
                 .unwrap_or("workflow");
-            let status = gh_latest_status_json_in(&context.root, workflow);
+            let repo_ref = context
+                .git
+                .as_ref()
+                .and_then(|git| git.repo_ref(&config.forge));
+            let default_branch = context
+                .git
+                .as_ref()
+                .and_then(|git| git.default_branch.as_deref());
+            let status = latest_run_status(repo_ref.as_ref(), workflow, default_branch);
             if status.ok
                 && let Some(conclusion) = status.conclusion
             {
@@ -447,20 +1093,32 @@ fn format_badge_label(badge: &Badge, context: &ProjectContext, options: &Version
         crate::badges::BadgeKind::Downloads => badge.label.clone(),
         crate::badges::BadgeKind::Coverage => "coverage".to_string(),
         crate::badges::BadgeKind::Msrv => "MSRV".to_string(),
+        crate::badges::BadgeKind::PythonVersions => "Python versions".to_string(),
+        crate::badges::BadgeKind::GoVersion => "Go version".to_string(),
+        crate::badges::BadgeKind::Edition => "Rust edition".to_string(),
+        crate::badges::BadgeKind::NodeVersion => "Node version".to_string(),
+        crate::badges::BadgeKind::Quality => badge.label.clone(),
+        crate::badges::BadgeKind::Container => badge.label.clone(),
         crate::badges::BadgeKind::Stars => "GitHub stars".to_string(),
         crate::badges::BadgeKind::Forks => "GitHub forks".to_string(),
         crate::badges::BadgeKind::Issues => "GitHub issues".to_string(),
         crate::badges::BadgeKind::PullRequests => "GitHub pull requests".to_string(),
         crate::badges::BadgeKind::Activity => "GitHub last commit".to_string(),
+        crate::badges::BadgeKind::Security => badge.label.clone(),
     }
 }
 
 fn extract_version_from_badge(badge: &Badge) -> Option<String> {
-    if let Some(url) = badge.image_url.split("img.shields.io/npm/v/").nth(1) {
+    // The npm, crates.io and PyPI routes are the same on shields and badgen.
+    if let Some(url) = badge.image_url.split("/npm/v/").nth(1) {
         let segment = url.split(&['/', '?'][..]).next().unwrap_or("");
         return Some(segment.trim_end_matches(".svg").to_string());
     }
-    if let Some(url) = badge.image_url.split("img.shields.io/crates/v/").nth(1) {
+    if let Some(url) = badge.image_url.split("/crates/v/").nth(1) {
+        let segment = url.split(&['/', '?'][..]).next().unwrap_or("");
+        return Some(segment.trim_end_matches(".svg").to_string());
+    }
+    if let Some(url) = badge.image_url.split("/pypi/v/").nth(1) {
         let segment = url.split(&['/', '?'][..]).next().unwrap_or("");
         return Some(segment.trim_end_matches(".svg").to_string());
     }
@@ -502,6 +1160,8 @@ fn recommended_indices(badges: &[Badge]) -> Vec<usize> {
         if badge.kind == crate::badges::BadgeKind::Version
             && (badge.label.contains("crates")
                 || badge.label.contains("npm")
+                || badge.label.contains("PyPI")
+                || badge.label.contains("JSR")
                 || badge.label.contains("moonbit"))
         {
             selected.push(idx);
@@ -519,11 +1179,12 @@ fn recommended_indices(badges: &[Badge]) -> Vec<usize> {
     selected
 }
 
-fn format_remove_items(lines: &[String]) -> Vec<String> {
+fn format_remove_items(lines: &[String], hosts: &BadgeHosts) -> Vec<String> {
     lines
         .iter()
         .map(|line| {
-            if let Some(parsed) = crate::readme_badges::parse_badge_line_optional(line) {
+            if let Some(parsed) = crate::readme_badges::parse_badge_line_optional_with(line, hosts)
+            {
                 let mut summary = format!("{} [{}]", parsed.kind, parsed.id);
                 if !parsed.label.is_empty() {
                     summary.push_str(&format!(" \"{}\"", parsed.label));
@@ -567,6 +1228,13 @@ struct DryRunJson {
     warnings: Vec<WarningJson>,
 }
 
+/// `sync --workspace --dry-run --json`: one `bdg.dryrun/v1` plan per README.
+#[derive(Serialize)]
+struct WorkspaceDryRunJson {
+    schema: String,
+    plans: Vec<DryRunJson>,
+}
+
 fn print_diff(diff: &str) {
     if diff.is_empty() {
         return;
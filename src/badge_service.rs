@@ -0,0 +1,373 @@
+use crate::badges::Badge;
+use crate::config::{BadgesConfig, Config};
+use serde::Deserialize;
+
+pub const SHIELDS_URL: &str = "https://img.shields.io";
+pub const BADGEN_URL: &str = "https://badgen.net";
+/// Third-party service rendering GHCR badges, which shields.io has no routes
+/// for. `[badges] ghcr_url` replaces it.
+pub const GHCR_BADGE_URL: &str = "https://ghcr-badge.egpl.dev";
+
+/// Image services that render registry, license and repository badges.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BadgeService {
+    #[default]
+    Shields,
+    Badgen,
+}
+
+impl BadgeService {
+    pub const fn default_url(self) -> &'static str {
+        match self {
+            Self::Shields => SHIELDS_URL,
+            Self::Badgen => BADGEN_URL,
+        }
+    }
+}
+
+/// Shields routes with a badgen.net equivalent, as `(shields prefix, shields
+/// suffix, badgen prefix)`. The text between prefix and suffix carries over.
+const BADGEN_ROUTES: &[(&str, &str, &str)] = &[
+    ("npm/v/", "", "npm/v/"),
+    ("npm/dt/", "", "npm/dt/"),
+    ("crates/v/", "", "crates/v/"),
+    ("crates/d/", "", "crates/d/"),
+    ("pypi/v/", "", "pypi/v/"),
+    ("pypi/dm/", "", "pypi/dm/"),
+    ("pypi/pyversions/", "", "pypi/python/"),
+    ("docker/pulls/", "", "docker/pulls/"),
+    ("github/license/", "", "github/license/"),
+    ("github/v/release/", "", "github/release/"),
+    ("github/downloads/", "/total", "github/assets-dl/"),
+    ("github/stars/", "", "github/stars/"),
+    ("github/forks/", "", "github/forks/"),
+    ("github/issues/", "", "github/open-issues/"),
+    ("github/issues-pr/", "", "github/open-prs/"),
+    ("github/last-commit/", "", "github/last-commit/"),
+    ("codecov/c/github/", "", "codecov/c/github/"),
+    ("codecov/c/gitlab/", "", "codecov/c/gitlab/"),
+    ("coveralls/github/", "", "coveralls/c/github/"),
+];
+
+/// The configured service and the root it is served from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadgeBackend {
+    pub service: BadgeService,
+    pub url: String,
+    /// Root GHCR badges are served from.
+    pub ghcr_url: String,
+}
+
+impl Default for BadgeBackend {
+    fn default() -> Self {
+        Self {
+            service: BadgeService::Shields,
+            url: SHIELDS_URL.to_string(),
+            ghcr_url: GHCR_BADGE_URL.to_string(),
+        }
+    }
+}
+
+impl BadgeBackend {
+    pub fn from_config(config: &BadgesConfig) -> Self {
+        let url = config
+            .url
+            .as_deref()
+            .map(|url| url.trim_end_matches('/'))
+            .unwrap_or(config.service.default_url());
+        let ghcr_url = config
+            .ghcr_url
+            .as_deref()
+            .filter(|url| !url.is_empty())
+            .unwrap_or(GHCR_BADGE_URL);
+        Self {
+            service: config.service,
+            url: url.to_string(),
+            ghcr_url: ghcr_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Moves a badge built for public shields.io onto this backend. Badges on
+    /// other hosts, including a forge's own shields instance, are left alone,
+    /// and so are the few that badgen cannot render. GHCR badges move to
+    /// `ghcr_url`.
+    pub fn render(&self, mut badge: Badge) -> Badge {
+        if let Some(route) = route_after(&[GHCR_BADGE_URL.to_string()], &badge.image_url) {
+            badge.image_url = format!("{}/{}", self.ghcr_url, route);
+            return badge;
+        }
+        let Some(route) = badge
+            .image_url
+            .strip_prefix(SHIELDS_URL)
+            .and_then(|rest| rest.strip_prefix('/'))
+        else {
+            return badge;
+        };
+        let route = match self.service {
+            BadgeService::Shields => Some(route.to_string()),
+            BadgeService::Badgen => shields_to_badgen(route),
+        };
+        if let Some(route) = route {
+            badge.image_url = format!("{}/{}", self.url, route);
+        }
+        badge
+    }
+}
+
+/// Hosts serving shields or badgen routes, so README badges are recognized
+/// whichever of them renders them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadgeHosts {
+    shields: Vec<String>,
+    badgen: Vec<String>,
+    ghcr: Vec<String>,
+}
+
+impl Default for BadgeHosts {
+    fn default() -> Self {
+        Self {
+            shields: vec![SHIELDS_URL.to_string()],
+            badgen: vec![BADGEN_URL.to_string()],
+            ghcr: vec![GHCR_BADGE_URL.to_string()],
+        }
+    }
+}
+
+impl BadgeHosts {
+    /// Public shields.io and badgen.net, plus `[badges] url`, `[badges]
+    /// ghcr_url` and the `shields_url` of `[forge]` and its hosts.
+    pub fn from_config(config: &Config) -> Self {
+        let mut hosts = Self::default();
+        if let Some(url) = &config.badges.url {
+            match config.badges.service {
+                BadgeService::Shields => hosts.add_shields(url),
+                BadgeService::Badgen => hosts.add_badgen(url),
+            }
+        }
+        if let Some(url) = config
+            .badges
+            .ghcr_url
+            .as_deref()
+            .filter(|url| !url.is_empty())
+        {
+            let url = url.trim_end_matches('/').to_string();
+            if !hosts.ghcr.contains(&url) {
+                hosts.ghcr.push(url);
+            }
+        }
+        let forge = &config.forge;
+        for url in forge.shields_url.iter().chain(
+            forge
+                .hosts
+                .values()
+                .filter_map(|host| host.shields_url.as_ref()),
+        ) {
+            hosts.add_shields(url);
+        }
+        hosts
+    }
+
+    fn add_shields(&mut self, url: &str) {
+        let url = url.trim_end_matches('/').to_string();
+        if !self.shields.contains(&url) {
+            self.shields.push(url);
+        }
+    }
+
+    fn add_badgen(&mut self, url: &str) {
+        let url = url.trim_end_matches('/').to_string();
+        if !self.badgen.contains(&url) {
+            self.badgen.push(url);
+        }
+    }
+
+    /// Whether `image` is served by a shields instance.
+    pub fn is_shields(&self, image: &str) -> bool {
+        route_after(&self.shields, image).is_some()
+    }
+
+    /// `image` as the equivalent public shields.io URL, or unchanged when no
+    /// known shields or badgen host serves it. GHCR badges map to
+    /// [`GHCR_BADGE_URL`].
+    pub fn canonical(&self, image: &str) -> String {
+        if let Some(route) = route_after(&self.ghcr, image) {
+            return format!("{GHCR_BADGE_URL}/{route}");
+        }
+        if let Some(route) = route_after(&self.shields, image) {
+            return format!("{SHIELDS_URL}/{route}");
+        }
+        if let Some(route) = route_after(&self.badgen, image).and_then(badgen_to_shields) {
+            return format!("{SHIELDS_URL}/{route}");
+        }
+        image.to_string()
+    }
+}
+
+fn route_after<'a>(roots: &[String], image: &'a str) -> Option<&'a str> {
+    roots.iter().find_map(|root| {
+        image
+            .strip_prefix(root.as_str())
+            .and_then(|rest| rest.strip_prefix('/'))
+    })
+}
+
+fn shields_to_badgen(route: &str) -> Option<String> {
+    // Query parameters select shields-specific behaviour (another forge
+    // instance, a sort order) that badgen has no counterpart for.
+    if route.contains('?') {
+        return None;
+    }
+    let path = route.strip_suffix(".svg").unwrap_or(route);
+    if let Some(static_badge) = path.strip_prefix("badge/") {
+        let [label, message, color] = split_shields_static(static_badge)?;
+        let color = match color.as_str() {
+            "informational" => "blue",
+            color => color,
+        };
+        return Some(format!("badge/{label}/{message}/{color}"));
+    }
+    BADGEN_ROUTES.iter().find_map(|(prefix, suffix, badgen)| {
+        let rest = path.strip_prefix(prefix)?.strip_suffix(suffix)?;
+        (!rest.is_empty()).then(|| format!("{badgen}{rest}"))
+    })
+}
+
+fn badgen_to_shields(route: &str) -> Option<String> {
+    let path = route.split(['?', '#']).next().unwrap_or(route);
+    let path = path.strip_suffix(".svg").unwrap_or(path);
+    if let Some(static_badge) = path.strip_prefix("badge/") {
+        let mut parts = static_badge.split('/');
+        let (label, message) = (parts.next()?, parts.next()?);
+        let color = parts.next().unwrap_or("blue");
+        let escape = |segment: &str| segment.replace('-', "--");
+        return Some(format!(
+            "badge/{}-{}-{}.svg",
+            escape(label),
+            escape(message),
+            color
+        ));
+    }
+    BADGEN_ROUTES.iter().find_map(|(prefix, suffix, badgen)| {
+        let rest = path.strip_prefix(badgen)?;
+        (!rest.is_empty()).then(|| format!("{prefix}{rest}{suffix}.svg"))
+    })
+}
+
+/// Label, message and color of a static shields badge path, with `--`
+/// unescaped to a dash.
+fn split_shields_static(path: &str) -> Option<[String; 3]> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut chars = path.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '-' {
+            current.push(ch);
+        } else if chars.peek() == Some(&'-') {
+            chars.next();
+            current.push('-');
+        } else {
+            parts.push(std::mem::take(&mut current));
+        }
+    }
+    parts.push(current);
+    parts.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BadgeBackend, BadgeHosts, BadgeService};
+    use crate::badges::{
+        badge_for_crates, badge_for_docker_version, badge_for_ghcr_version,
+        badge_for_github_downloads, badge_for_license_text, badge_for_release,
+    };
+    use crate::config::{BadgesConfig, Config};
+    use crate::forge::parse_repo_url;
+
+    fn badgen() -> BadgeBackend {
+        BadgeBackend::from_config(&BadgesConfig {
+            service: BadgeService::Badgen,
+            ..BadgesConfig::default()
+        })
+    }
+
+    #[test]
+    fn renders_badges_on_the_configured_service() {
+        let github = parse_repo_url("https://github.com/owner/app").unwrap();
+        let shields = BadgeBackend::from_config(&BadgesConfig {
+            url: Some("https://shields.corp.example/".to_string()),
+            ..BadgesConfig::default()
+        });
+        assert_eq!(
+            shields.render(badge_for_crates("bdg")).image_url,
+            "https://shields.corp.example/crates/v/bdg.svg"
+        );
+        let ghcr = BadgeBackend::from_config(&BadgesConfig {
+            ghcr_url: Some("https://ghcr-badge.corp.example/".to_string()),
+            ..BadgesConfig::default()
+        });
+        assert_eq!(
+            ghcr.render(badge_for_ghcr_version("o/app")).image_url,
+            "https://ghcr-badge.corp.example/o/app/latest_tag?trim=major&label=ghcr"
+        );
+
+        assert_eq!(
+            badgen().render(badge_for_crates("bdg")).image_url,
+            "https://badgen.net/crates/v/bdg"
+        );
+        assert_eq!(
+            badgen()
+                .render(badge_for_github_downloads(&github))
+                .image_url,
+            "https://badgen.net/github/assets-dl/owner/app"
+        );
+        assert_eq!(
+            badgen()
+                .render(badge_for_license_text("MIT OR Apache-2.0", None))
+                .image_url,
+            "https://badgen.net/badge/license/MIT%20OR%20Apache-2.0/blue"
+        );
+        // badgen has no route for these, so shields.io keeps them.
+        assert_eq!(
+            badgen().render(badge_for_docker_version("o/app")).image_url,
+            "https://img.shields.io/docker/v/o/app.svg?sort=semver"
+        );
+        let codeberg = parse_repo_url("https://codeberg.org/team/app").unwrap();
+        assert!(
+            badgen()
+                .render(badge_for_release(&codeberg))
+                .image_url
+                .starts_with("https://img.shields.io/gitea/")
+        );
+    }
+
+    #[test]
+    fn maps_configured_hosts_back_to_shields() {
+        let config: Config = toml::from_str(
+            "[badges]\nservice = \"badgen\"\nurl = \"https://badgen.corp.example\"\nghcr_url = \"https://ghcr-badge.corp.example\"\n\n[forge]\nshields_url = \"https://shields.corp.example\"\n",
+        )
+        .unwrap();
+        let hosts = BadgeHosts::from_config(&config);
+        assert_eq!(
+            hosts.canonical("https://shields.corp.example/npm/v/pkg.svg"),
+            "https://img.shields.io/npm/v/pkg.svg"
+        );
+        assert_eq!(
+            hosts.canonical("https://badgen.corp.example/github/open-prs/o/r"),
+            "https://img.shields.io/github/issues-pr/o/r.svg"
+        );
+        assert_eq!(
+            hosts.canonical("https://badgen.net/badge/edition/2024/orange"),
+            "https://img.shields.io/badge/edition-2024-orange.svg"
+        );
+        assert_eq!(
+            hosts.canonical("https://ghcr-badge.corp.example/o/app/size"),
+            "https://ghcr-badge.egpl.dev/o/app/size"
+        );
+        assert_eq!(
+            hosts.canonical("https://example.com/npm/v/pkg"),
+            "https://example.com/npm/v/pkg"
+        );
+        assert!(!BadgeHosts::default().is_shields("https://shields.corp.example/npm/v/pkg"));
+    }
+}
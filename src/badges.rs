@@ -1,3 +1,6 @@
+use crate::badge_service::GHCR_BADGE_URL;
+use crate::config::Theme;
+use crate::forge::{ForgeKind, RepoRef};
 use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -10,11 +13,18 @@ pub enum BadgeKind {
     Downloads,
     Coverage,
     Msrv,
+    PythonVersions,
+    GoVersion,
+    Edition,
+    NodeVersion,
+    Quality,
+    Container,
     Stars,
     Forks,
     Issues,
     PullRequests,
     Activity,
+    Security,
 }
 
 impl BadgeKind {
@@ -28,11 +38,18 @@ impl BadgeKind {
             Self::Downloads => "downloads",
             Self::Coverage => "coverage",
             Self::Msrv => "msrv",
+            Self::PythonVersions => "python",
+            Self::GoVersion => "go",
+            Self::Edition => "edition",
+            Self::NodeVersion => "node",
+            Self::Quality => "quality",
+            Self::Container => "container",
             Self::Stars => "stars",
             Self::Forks => "forks",
             Self::Issues => "issues",
             Self::PullRequests => "pulls",
             Self::Activity => "activity",
+            Self::Security => "security",
         }
     }
 }
@@ -55,6 +72,122 @@ impl Badge {
             None => format!("![{}]({})", self.label, self.image_url),
         }
     }
+
+    pub fn render_html(&self) -> String {
+        render_html_badge(&self.label, &self.image_url, self.link_url.as_deref())
+    }
+
+    /// Sets query parameters on the image URL, replacing any of the same name
+    /// the badge already carries.
+    pub fn with_query_params(mut self, params: &[(&str, String)]) -> Self {
+        if params.is_empty() {
+            return self;
+        }
+        let (base, query) = self
+            .image_url
+            .split_once('?')
+            .unwrap_or((self.image_url.as_str(), ""));
+        let mut pairs = query
+            .split('&')
+            .filter(|pair| {
+                let name = pair.split('=').next().unwrap_or("");
+                !pair.is_empty() && !params.iter().any(|(key, _)| *key == name)
+            })
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        pairs.extend(
+            params
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, encode_query_value(value))),
+        );
+        self.image_url = format!("{}?{}", base, pairs.join("&"));
+        self
+    }
+}
+
+/// `<a href="link"><img alt="label" src="image"></a>`, or a bare `<img>` without
+/// a link.
+pub fn render_html_badge(label: &str, image_url: &str, link_url: Option<&str>) -> String {
+    let image = format!(
+        "<img alt=\"{}\" src=\"{}\">",
+        escape_html_attr(label),
+        escape_html_attr(image_url)
+    );
+    match link_url {
+        Some(link) => format!("<a href=\"{}\">{}</a>", escape_html_attr(link), image),
+        None => image,
+    }
+}
+
+/// A `<picture>` offering one `<source>` per theme, with `image_url` as the
+/// `<img>` fallback, optionally inside an `<a>`. It stays on one line so the
+/// block keeps one badge per line.
+pub fn render_picture_badge(
+    label: &str,
+    image_url: &str,
+    variants: &[(Theme, String)],
+    link_url: Option<&str>,
+) -> String {
+    let sources = variants
+        .iter()
+        .map(|(theme, url)| {
+            format!(
+                "<source media=\"(prefers-color-scheme: {})\" srcset=\"{}\">",
+                theme.as_str(),
+                escape_html_attr(url)
+            )
+        })
+        .collect::<String>();
+    let picture = format!(
+        "<picture>{sources}{}</picture>",
+        render_html_badge(label, image_url, None)
+    );
+    match link_url {
+        Some(link) => format!("<a href=\"{}\">{picture}</a>", escape_html_attr(link)),
+        None => picture,
+    }
+}
+
+/// A reStructuredText image directive, with its options on indented lines
+/// separated by `\n`.
+pub fn render_rst_badge(label: &str, image_url: &str, link_url: Option<&str>) -> String {
+    let mut directive = format!(".. image:: {image_url}");
+    if let Some(link) = link_url {
+        directive.push_str(&format!("\n   :target: {link}"));
+    }
+    directive.push_str(&format!("\n   :alt: {label}"));
+    directive
+}
+
+/// An AsciiDoc inline image, `image:image[label,link=link]`.
+pub fn render_asciidoc_badge(label: &str, image_url: &str, link_url: Option<&str>) -> String {
+    let alt = asciidoc_attr_value(label);
+    match link_url {
+        Some(link) => format!(
+            "image:{image_url}[{alt},link={}]",
+            asciidoc_attr_value(link)
+        ),
+        None => format!("image:{image_url}[{alt}]"),
+    }
+}
+
+/// Quotes an attribute value that would otherwise split the attribute list,
+/// and escapes the `]` that would close it.
+fn asciidoc_attr_value(value: &str) -> String {
+    let value = if value.contains([',', '=', '"']) {
+        format!("\"{}\"", value.replace('"', "\\\""))
+    } else {
+        value.to_string()
+    };
+    value.replace(']', "\\]")
+}
+
+fn escape_html_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
 }
 
 pub fn dedupe_badges(badges: Vec<Badge>) -> Vec<Badge> {
@@ -137,6 +270,171 @@ pub fn badge_for_crates_msrv(crate_name: &str) -> Badge {
     )
 }
 
+/// Static MSRV badge built from Cargo's `rust-version`; works before publishing.
+pub fn badge_for_rust_version(rust_version: &str) -> Badge {
+    badge(
+        BadgeKind::Msrv,
+        "MSRV",
+        format!(
+            "https://img.shields.io/badge/rustc-{}%2B-orange.svg",
+            encode_static_badge_segment(rust_version)
+        ),
+        Some("https://www.rust-lang.org/".to_string()),
+    )
+}
+
+pub fn badge_for_rust_edition(edition: &str) -> Badge {
+    badge(
+        BadgeKind::Edition,
+        "Rust edition",
+        format!(
+            "https://img.shields.io/badge/edition-{}-orange.svg",
+            encode_static_badge_segment(edition)
+        ),
+        Some("https://doc.rust-lang.org/edition-guide/".to_string()),
+    )
+}
+
+/// Static badge for the package.json `engines.node` range.
+pub fn badge_for_node_engine(range: &str) -> Badge {
+    badge(
+        BadgeKind::NodeVersion,
+        "Node",
+        format!(
+            "https://img.shields.io/badge/node-{}-339933.svg",
+            encode_static_badge_segment(range)
+        ),
+        Some("https://nodejs.org/".to_string()),
+    )
+}
+
+pub fn badge_for_pypi(package: &str) -> Badge {
+    badge(
+        BadgeKind::Version,
+        "PyPI",
+        format!("https://img.shields.io/pypi/v/{}.svg", package),
+        Some(format!("https://pypi.org/project/{}/", package)),
+    )
+}
+
+pub fn badge_for_pypi_downloads(package: &str) -> Badge {
+    badge(
+        BadgeKind::Downloads,
+        "PyPI downloads",
+        format!("https://img.shields.io/pypi/dm/{}.svg", package),
+        Some(format!("https://pypi.org/project/{}/", package)),
+    )
+}
+
+pub fn badge_for_pypi_python_versions(package: &str) -> Badge {
+    badge(
+        BadgeKind::PythonVersions,
+        "Python versions",
+        format!("https://img.shields.io/pypi/pyversions/{}.svg", package),
+        Some(format!("https://pypi.org/project/{}/", package)),
+    )
+}
+
+pub fn badge_for_go_reference(module: &str) -> Badge {
+    badge(
+        BadgeKind::Docs,
+        "Go Reference",
+        format!("https://pkg.go.dev/badge/{}.svg", module),
+        Some(format!("https://pkg.go.dev/{}", module)),
+    )
+}
+
+pub fn badge_for_go_version(version: &str) -> Badge {
+    badge(
+        BadgeKind::GoVersion,
+        "Go",
+        format!(
+            "https://img.shields.io/badge/go-{}-00ADD8.svg",
+            encode_static_badge_segment(version)
+        ),
+        Some("https://go.dev/".to_string()),
+    )
+}
+
+pub fn badge_for_go_report_card(module: &str) -> Badge {
+    badge(
+        BadgeKind::Quality,
+        "Go Report Card",
+        format!("https://goreportcard.com/badge/{}", module),
+        Some(format!("https://goreportcard.com/report/{}", module)),
+    )
+}
+
+pub fn badge_for_jsr(package: &str) -> Badge {
+    badge(
+        BadgeKind::Version,
+        "JSR",
+        format!("https://jsr.io/badges/{}", package),
+        Some(format!("https://jsr.io/{}", package)),
+    )
+}
+
+pub fn badge_for_jsr_score(package: &str) -> Badge {
+    badge(
+        BadgeKind::Quality,
+        "JSR score",
+        format!("https://jsr.io/badges/{}/score", package),
+        Some(format!("https://jsr.io/{}", package)),
+    )
+}
+
+pub fn badge_for_docker_version(image: &str) -> Badge {
+    badge(
+        BadgeKind::Container,
+        "Docker Hub",
+        format!("https://img.shields.io/docker/v/{}.svg?sort=semver", image),
+        Some(format!("https://hub.docker.com/r/{}", image)),
+    )
+}
+
+pub fn badge_for_docker_image_size(image: &str) -> Badge {
+    optional_badge(
+        BadgeKind::Container,
+        "Docker image size",
+        format!(
+            "https://img.shields.io/docker/image-size/{}.svg?sort=semver",
+            image
+        ),
+        Some(format!("https://hub.docker.com/r/{}", image)),
+    )
+}
+
+pub fn badge_for_docker_pulls(image: &str) -> Badge {
+    optional_badge(
+        BadgeKind::Container,
+        "Docker pulls",
+        format!("https://img.shields.io/docker/pulls/{}.svg", image),
+        Some(format!("https://hub.docker.com/r/{}", image)),
+    )
+}
+
+/// shields.io has no GHCR endpoints, so GHCR badges use [`GHCR_BADGE_URL`],
+/// moved to `[badges] ghcr_url` by [`BadgeBackend::render`].
+///
+/// [`BadgeBackend::render`]: crate::badge_service::BadgeBackend::render
+pub fn badge_for_ghcr_version(image: &str) -> Badge {
+    badge(
+        BadgeKind::Container,
+        "GHCR",
+        format!("{GHCR_BADGE_URL}/{image}/latest_tag?trim=major&label=ghcr"),
+        Some(format!("https://ghcr.io/{}", image)),
+    )
+}
+
+pub fn badge_for_ghcr_image_size(image: &str) -> Badge {
+    optional_badge(
+        BadgeKind::Container,
+        "GHCR image size",
+        format!("{GHCR_BADGE_URL}/{image}/size"),
+        Some(format!("https://ghcr.io/{}", image)),
+    )
+}
+
 pub fn badge_for_docs_rs(crate_name: &str) -> Badge {
     badge(
         BadgeKind::Docs,
@@ -155,34 +453,45 @@ pub fn badge_for_docs_url(url: &str) -> Badge {
     )
 }
 
-pub fn badge_for_moonbit(module: &str) -> Badge {
+/// shields.io has no mooncakes.io route, so the version is baked into a static
+/// badge. It changes with every release, so it is opt-in for `sync`.
+pub fn badge_for_moonbit(module: &str, version: &str) -> Badge {
     let link_url = if module.contains('/') {
         Some(format!("https://mooncakes.io/docs/{}", module))
     } else {
         Some("https://mooncakes.io/".to_string())
     };
 
-    badge(
+    optional_badge(
         BadgeKind::Version,
         "moonbit",
         format!(
             "https://img.shields.io/badge/moonbit-{}-informational",
-            module
+            encode_static_badge_segment(version)
         ),
         link_url,
     )
 }
 
-pub fn badge_for_license(owner: &str, repo: &str) -> Badge {
-    badge(
+/// License badge read from the forge's repository metadata. shields.io has no
+/// license endpoint for Forgejo, so those repositories rely on the manifest.
+pub fn badge_for_license(repo: &RepoRef) -> Option<Badge> {
+    let image_url = match repo.kind {
+        ForgeKind::GitHub => format!("{}/github/license/{}.svg", repo.shields(), repo.path()),
+        ForgeKind::GitLab => format!(
+            "{}/gitlab/license/{}.svg{}",
+            repo.shields(),
+            repo.path(),
+            gitlab_url_query(repo)
+        ),
+        ForgeKind::Forgejo => return None,
+    };
+    Some(badge(
         BadgeKind::License,
         "license",
-        format!(
-            "https://img.shields.io/github/license/{}/{}.svg",
-            owner, repo
-        ),
-        Some(format!("https://github.com/{}/{}", owner, repo)),
-    )
+        image_url,
+        Some(repo.web_url()),
+    ))
 }
 
 pub fn badge_for_license_text(license: &str, repository: Option<&str>) -> Badge {
@@ -203,111 +512,462 @@ pub fn badge_for_license_text(license: &str, repository: Option<&str>) -> Badge
     )
 }
 
-pub fn badge_for_github_release(owner: &str, repo: &str) -> Badge {
-    badge(
-        BadgeKind::Release,
-        "release",
-        format!(
-            "https://img.shields.io/github/v/release/{}/{}.svg",
-            owner, repo
+/// Latest release. GitLab serves its own release badge, which also works for
+/// self-managed instances.
+pub fn badge_for_release(repo: &RepoRef) -> Badge {
+    let image_url = match repo.kind {
+        ForgeKind::GitHub => format!("{}/github/v/release/{}.svg", repo.shields(), repo.path()),
+        ForgeKind::GitLab => format!("{}/-/badges/release.svg", repo.web_url()),
+        ForgeKind::Forgejo => format!(
+            "{}/gitea/v/release/{}.svg?gitea_url={}",
+            repo.shields(),
+            repo.path(),
+            encode_query_value(&repo.base_url)
         ),
-        Some(format!("https://github.com/{}/{}/releases", owner, repo)),
-    )
+    };
+    let link_url = match repo.kind {
+        ForgeKind::GitHub | ForgeKind::Forgejo => format!("{}/releases", repo.web_url()),
+        ForgeKind::GitLab => format!("{}/-/releases", repo.web_url()),
+    };
+    badge(BadgeKind::Release, "release", image_url, Some(link_url))
 }
 
-pub fn badge_for_github_downloads(owner: &str, repo: &str) -> Badge {
+pub fn badge_for_github_downloads(repo: &RepoRef) -> Badge {
     optional_badge(
         BadgeKind::Downloads,
         "GitHub downloads",
         format!(
-            "https://img.shields.io/github/downloads/{}/{}/total.svg",
-            owner, repo
+            "{}/github/downloads/{}/total.svg",
+            repo.shields(),
+            repo.path()
         ),
-        Some(format!("https://github.com/{}/{}/releases", owner, repo)),
+        Some(format!("{}/releases", repo.web_url())),
     )
 }
 
-pub fn badge_for_github_stars(owner: &str, repo: &str) -> Badge {
+pub fn badge_for_github_stars(repo: &RepoRef) -> Badge {
     optional_badge(
         BadgeKind::Stars,
         "GitHub stars",
-        format!("https://img.shields.io/github/stars/{}/{}.svg", owner, repo),
-        Some(format!("https://github.com/{}/{}/stargazers", owner, repo)),
+        format!("{}/github/stars/{}.svg", repo.shields(), repo.path()),
+        Some(format!("{}/stargazers", repo.web_url())),
     )
 }
 
-pub fn badge_for_github_forks(owner: &str, repo: &str) -> Badge {
+pub fn badge_for_github_forks(repo: &RepoRef) -> Badge {
     optional_badge(
         BadgeKind::Forks,
         "GitHub forks",
-        format!("https://img.shields.io/github/forks/{}/{}.svg", owner, repo),
-        Some(format!("https://github.com/{}/{}/forks", owner, repo)),
+        format!("{}/github/forks/{}.svg", repo.shields(), repo.path()),
+        Some(format!("{}/forks", repo.web_url())),
     )
 }
 
-pub fn badge_for_github_issues(owner: &str, repo: &str) -> Badge {
+pub fn badge_for_github_issues(repo: &RepoRef) -> Badge {
     optional_badge(
         BadgeKind::Issues,
         "GitHub issues",
-        format!(
-            "https://img.shields.io/github/issues/{}/{}.svg",
-            owner, repo
-        ),
-        Some(format!("https://github.com/{}/{}/issues", owner, repo)),
+        format!("{}/github/issues/{}.svg", repo.shields(), repo.path()),
+        Some(format!("{}/issues", repo.web_url())),
     )
 }
 
-pub fn badge_for_github_pull_requests(owner: &str, repo: &str) -> Badge {
+pub fn badge_for_github_pull_requests(repo: &RepoRef) -> Badge {
     optional_badge(
         BadgeKind::PullRequests,
         "GitHub pull requests",
-        format!(
-            "https://img.shields.io/github/issues-pr/{}/{}.svg",
-            owner, repo
-        ),
-        Some(format!("https://github.com/{}/{}/pulls", owner, repo)),
+        format!("{}/github/issues-pr/{}.svg", repo.shields(), repo.path()),
+        Some(format!("{}/pulls", repo.web_url())),
     )
 }
 
-pub fn badge_for_github_last_commit(owner: &str, repo: &str) -> Badge {
+pub fn badge_for_github_last_commit(repo: &RepoRef) -> Badge {
     optional_badge(
         BadgeKind::Activity,
         "GitHub last commit",
+        format!("{}/github/last-commit/{}.svg", repo.shields(), repo.path()),
+        Some(format!("{}/commits", repo.web_url())),
+    )
+}
+
+/// Codecov's hosted service integrates with github.com and GitLab among the
+/// supported forges.
+pub fn badge_for_codecov(repo: &RepoRef) -> Option<Badge> {
+    let (service, short) = match repo.kind {
+        ForgeKind::GitHub if repo.is_public_host() => ("github", "gh"),
+        ForgeKind::GitLab => ("gitlab", "gl"),
+        ForgeKind::GitHub | ForgeKind::Forgejo => return None,
+    };
+    Some(badge(
+        BadgeKind::Coverage,
+        "codecov",
         format!(
-            "https://img.shields.io/github/last-commit/{}/{}.svg",
-            owner, repo
+            "{}/codecov/c/{}/{}.svg",
+            repo.shields(),
+            service,
+            repo.path()
         ),
-        Some(format!("https://github.com/{}/{}/commits", owner, repo)),
+        Some(format!("https://codecov.io/{}/{}", short, repo.path())),
+    ))
+}
+
+/// OpenSSF Scorecard results, published for public GitHub and GitLab
+/// repositories that run the Scorecard action.
+pub fn badge_for_scorecard(repo: &RepoRef) -> Option<Badge> {
+    if !repo.is_public_host() || repo.kind == ForgeKind::Forgejo {
+        return None;
+    }
+    let project = format!("{}/{}", repo.host.trim_start_matches("www."), repo.path());
+    Some(optional_badge(
+        BadgeKind::Security,
+        "OpenSSF Scorecard",
+        format!("https://api.scorecard.dev/projects/{}/badge", project),
+        Some(format!("https://scorecard.dev/viewer/?uri={}", project)),
+    ))
+}
+
+pub fn badge_for_best_practices(project: u64) -> Badge {
+    optional_badge(
+        BadgeKind::Security,
+        "OpenSSF Best Practices",
+        format!("https://www.bestpractices.dev/projects/{}/badge", project),
+        Some(format!(
+            "https://www.bestpractices.dev/projects/{}",
+            project
+        )),
     )
 }
 
-pub fn badge_for_codecov(owner: &str, repo: &str) -> Badge {
-    badge(
+/// Links the security policy where each forge presents it: GitHub's security
+/// tab, or the file itself on the default branch.
+pub fn badge_for_security_policy(repo: &RepoRef, file: &str, branch: &str) -> Badge {
+    let link = match repo.kind {
+        ForgeKind::GitHub => format!("{}/security/policy", repo.web_url()),
+        ForgeKind::GitLab => format!("{}/-/blob/{}/{}", repo.web_url(), branch, file),
+        ForgeKind::Forgejo => format!("{}/src/branch/{}/{}", repo.web_url(), branch, file),
+    };
+    optional_badge(
+        BadgeKind::Security,
+        "security policy",
+        format!("{}/badge/security-policy-blue.svg", repo.shields()),
+        Some(link),
+    )
+}
+
+/// Coverage from a self-hosted Codecov server, which also integrates with
+/// GitHub Enterprise and self-managed GitLab.
+pub fn badge_for_self_hosted_codecov(server: &str, repo: &RepoRef) -> Option<Badge> {
+    let service = match repo.kind {
+        ForgeKind::GitHub if repo.is_public_host() => "gh",
+        ForgeKind::GitHub => "ghe",
+        ForgeKind::GitLab if repo.is_public_host() => "gl",
+        ForgeKind::GitLab => "gle",
+        ForgeKind::Forgejo => return None,
+    };
+    let project = format!(
+        "{}/{}/{}",
+        server.trim_end_matches('/'),
+        service,
+        repo.path()
+    );
+    Some(badge(
         BadgeKind::Coverage,
         "codecov",
+        format!("{}/graph/badge.svg", project),
+        Some(project),
+    ))
+}
+
+/// Coveralls tracks github.com and GitLab repositories.
+pub fn badge_for_coveralls(repo: &RepoRef) -> Option<Badge> {
+    let service = match repo.kind {
+        ForgeKind::GitHub | ForgeKind::GitLab if repo.is_public_host() => repo.kind.as_str(),
+        _ => return None,
+    };
+    Some(badge(
+        BadgeKind::Coverage,
+        "coveralls",
+        format!(
+            "{}/coveralls/{}/{}.svg",
+            repo.shields(),
+            service,
+            repo.path()
+        ),
+        Some(format!("https://coveralls.io/{}/{}", service, repo.path())),
+    ))
+}
+
+/// Codacy coverage, addressed by the project token shown in Codacy's badge
+/// settings.
+pub fn badge_for_codacy(repo: &RepoRef, project_id: &str) -> Badge {
+    let link = match repo.kind {
+        ForgeKind::GitHub if repo.is_public_host() => Some("gh"),
+        ForgeKind::GitLab if repo.is_public_host() => Some("gl"),
+        _ => None,
+    }
+    .map(|service| {
+        format!(
+            "https://app.codacy.com/{}/{}/dashboard",
+            service,
+            repo.path()
+        )
+    });
+    badge(
+        BadgeKind::Coverage,
+        "codacy",
+        format!("{}/codacy/coverage/{}.svg", repo.shields(), project_id),
+        link,
+    )
+}
+
+pub fn badge_for_sonarcloud(repo: &RepoRef, project_key: &str) -> Badge {
+    badge(
+        BadgeKind::Coverage,
+        "sonarcloud",
         format!(
-            "https://img.shields.io/codecov/c/github/{}/{}.svg",
-            owner, repo
+            "{}/sonar/coverage/{}.svg?server=https%3A%2F%2Fsonarcloud.io",
+            repo.shields(),
+            project_key
         ),
-        Some(format!("https://codecov.io/gh/{}/{}", owner, repo)),
+        Some(format!(
+            "https://sonarcloud.io/summary/new_code?id={}",
+            encode_query_value(project_key)
+        )),
     )
 }
 
-pub fn badge_for_workflow(owner: &str, repo: &str, workflow_file: &str) -> Badge {
+/// GitHub Actions workflow status, served by the repository's own host so it
+/// also works on GitHub Enterprise Server. It can be scoped to runs on
+/// `branch` and/or triggered by `event`, so that, say, failing pull request
+/// runs do not turn the README badge red.
+pub fn badge_for_workflow(
+    repo: &RepoRef,
+    workflow_file: &str,
+    branch: Option<&str>,
+    event: Option<&str>,
+) -> Badge {
+    let filters = branch
+        .map(|branch| format!("branch:{branch}"))
+        .into_iter()
+        .chain(event.map(|event| format!("event:{event}")))
+        .map(|filter| encode_query_value(&filter))
+        .collect::<Vec<_>>();
+    let link_query = if filters.is_empty() {
+        String::new()
+    } else {
+        format!("?query={}", filters.join("+"))
+    };
     badge(
         BadgeKind::Ci,
         "CI",
         format!(
-            "https://github.com/{}/{}/actions/workflows/{}/badge.svg",
-            owner, repo, workflow_file
+            "{}/actions/workflows/{}/badge.svg{}",
+            repo.web_url(),
+            workflow_file,
+            workflow_badge_query(branch, event)
         ),
         Some(format!(
-            "https://github.com/{}/{}/actions/workflows/{}",
-            owner, repo, workflow_file
+            "{}/actions/workflows/{}{}",
+            repo.web_url(),
+            workflow_file,
+            link_query
         )),
     )
 }
 
+pub fn badge_for_gitlab_pipeline(repo: &RepoRef, branch: &str) -> Badge {
+    badge(
+        BadgeKind::Ci,
+        "pipeline",
+        format!("{}/badges/{}/pipeline.svg", repo.web_url(), branch),
+        Some(format!("{}/-/commits/{}", repo.web_url(), branch)),
+    )
+}
+
+/// GitLab's native coverage badge, populated by the `coverage:` keyword in
+/// `.gitlab-ci.yml`.
+pub fn badge_for_gitlab_coverage(repo: &RepoRef, branch: &str) -> Badge {
+    badge(
+        BadgeKind::Coverage,
+        "coverage",
+        format!("{}/badges/{}/coverage.svg", repo.web_url(), branch),
+        Some(format!("{}/-/commits/{}", repo.web_url(), branch)),
+    )
+}
+
+/// Forgejo Actions serves the same badge path as GitHub Actions, under the
+/// forge's own web root.
+pub fn badge_for_forgejo_workflow(
+    repo: &RepoRef,
+    workflow_file: &str,
+    branch: Option<&str>,
+    event: Option<&str>,
+) -> Badge {
+    badge(
+        BadgeKind::Ci,
+        "CI",
+        format!(
+            "{}/actions/workflows/{}/badge.svg{}",
+            repo.web_url(),
+            workflow_file,
+            workflow_badge_query(branch, event)
+        ),
+        Some(format!(
+            "{}/actions?workflow={}",
+            repo.web_url(),
+            workflow_file
+        )),
+    )
+}
+
+pub fn badge_for_woodpecker(server: &str, repo: &RepoRef) -> Badge {
+    let server = server.trim_end_matches('/');
+    badge(
+        BadgeKind::Ci,
+        "Woodpecker CI",
+        format!("{}/api/badges/{}/status.svg", server, repo.path()),
+        Some(format!("{}/repos/{}", server, repo.path())),
+    )
+}
+
+/// CircleCI status for `branch`, else for the project's default branch. Only
+/// GitHub projects have a slug derivable from the repository.
+pub fn badge_for_circleci(repo: &RepoRef, branch: Option<&str>) -> Option<Badge> {
+    if repo.kind != ForgeKind::GitHub || !repo.is_public_host() {
+        return None;
+    }
+    let tree = branch
+        .map(|branch| format!("/tree/{}", encode_query_value(branch)))
+        .unwrap_or_default();
+    Some(badge(
+        BadgeKind::Ci,
+        "CircleCI",
+        format!(
+            "https://dl.circleci.com/status-badge/img/gh/{}{}.svg?style=svg",
+            repo.path(),
+            tree
+        ),
+        Some(format!(
+            "https://dl.circleci.com/status-badge/redirect/gh/{}{}",
+            repo.path(),
+            tree
+        )),
+    ))
+}
+
+pub fn badge_for_travis(repo: &RepoRef, branch: Option<&str>) -> Option<Badge> {
+    if repo.kind != ForgeKind::GitHub || !repo.is_public_host() {
+        return None;
+    }
+    let query = branch
+        .map(|branch| format!("?branch={}", encode_query_value(branch)))
+        .unwrap_or_default();
+    Some(badge(
+        BadgeKind::Ci,
+        "Travis CI",
+        format!("https://app.travis-ci.com/{}.svg{}", repo.path(), query),
+        Some(format!("https://app.travis-ci.com/{}", repo.path())),
+    ))
+}
+
+pub fn badge_for_azure_pipelines(
+    organization: &str,
+    project: &str,
+    pipeline: &str,
+    branch: Option<&str>,
+) -> Badge {
+    let base = format!(
+        "https://dev.azure.com/{}/{}",
+        encode_query_value(organization),
+        encode_query_value(project)
+    );
+    let query = branch
+        .map(|branch| format!("?branchName={}", encode_query_value(branch)))
+        .unwrap_or_default();
+    badge(
+        BadgeKind::Ci,
+        "Azure Pipelines",
+        format!(
+            "{}/_apis/build/status/{}{}",
+            base,
+            encode_query_value(pipeline),
+            query
+        ),
+        Some(format!("{}/_build", base)),
+    )
+}
+
+/// Buildkite badge for the pipeline whose badge URL uses `key`.
+pub fn badge_for_buildkite(key: &str, pipeline: Option<&str>, branch: Option<&str>) -> Badge {
+    let query = branch
+        .map(|branch| format!("?branch={}", encode_query_value(branch)))
+        .unwrap_or_default();
+    badge(
+        BadgeKind::Ci,
+        "Buildkite",
+        format!("https://badge.buildkite.com/{}.svg{}", key, query),
+        pipeline.map(|pipeline| format!("https://buildkite.com/{}", pipeline)),
+    )
+}
+
+/// Jenkins Embeddable Build Status badge. `job` separates folders with `/`.
+pub fn badge_for_jenkins(server: &str, job: &str) -> Badge {
+    let server = server.trim_end_matches('/');
+    let job_path = job
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| format!("job/{}", encode_query_value(segment)))
+        .collect::<Vec<_>>()
+        .join("/");
+    badge(
+        BadgeKind::Ci,
+        "Jenkins",
+        format!(
+            "{}/buildStatus/icon?job={}",
+            server,
+            encode_query_value(job)
+        ),
+        Some(format!("{}/{}/", server, job_path)),
+    )
+}
+
+/// shields.io queries gitlab.com unless told about a self-managed instance.
+fn gitlab_url_query(repo: &RepoRef) -> String {
+    if repo.is_public_host() {
+        String::new()
+    } else {
+        format!("?gitlab_url={}", encode_query_value(&repo.base_url))
+    }
+}
+
+/// `?branch=...&event=...` accepted by GitHub and Forgejo workflow badges.
+fn workflow_badge_query(branch: Option<&str>, event: Option<&str>) -> String {
+    let params = branch
+        .map(|branch| format!("branch={}", encode_query_value(branch)))
+        .into_iter()
+        .chain(event.map(|event| format!("event={}", encode_query_value(event))))
+        .collect::<Vec<_>>();
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", params.join("&"))
+    }
+}
+
+/// Percent-encodes everything but RFC 3986 unreserved characters.
+pub(crate) fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
 fn encode_static_badge_segment(value: &str) -> String {
     let mut encoded = String::new();
     for byte in value.bytes() {
@@ -327,12 +987,27 @@ fn is_dual_license_expression(license: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::{
-        badge_for_codecov, badge_for_crates_downloads, badge_for_crates_msrv, badge_for_docs_rs,
-        badge_for_docs_url, badge_for_github_downloads, badge_for_github_forks,
-        badge_for_github_issues, badge_for_github_last_commit, badge_for_github_pull_requests,
-        badge_for_github_release, badge_for_github_stars, badge_for_license_text,
-        badge_for_npm_downloads, encode_static_badge_segment,
+        badge_for_azure_pipelines, badge_for_buildkite, badge_for_circleci, badge_for_codacy,
+        badge_for_codecov, badge_for_coveralls, badge_for_crates_downloads, badge_for_crates_msrv,
+        badge_for_docker_image_size, badge_for_docker_pulls, badge_for_docker_version,
+        badge_for_docs_rs, badge_for_docs_url, badge_for_ghcr_image_size, badge_for_ghcr_version,
+        badge_for_github_downloads, badge_for_github_forks, badge_for_github_issues,
+        badge_for_github_last_commit, badge_for_github_pull_requests, badge_for_github_stars,
+        badge_for_gitlab_coverage, badge_for_gitlab_pipeline, badge_for_go_reference,
+        badge_for_go_report_card, badge_for_go_version, badge_for_jenkins, badge_for_jsr,
+        badge_for_jsr_score, badge_for_license, badge_for_license_text, badge_for_moonbit,
+        badge_for_node_engine, badge_for_npm_downloads, badge_for_pypi, badge_for_pypi_downloads,
+        badge_for_pypi_python_versions, badge_for_release, badge_for_rust_edition,
+        badge_for_rust_version, badge_for_scorecard, badge_for_security_policy,
+        badge_for_self_hosted_codecov, badge_for_sonarcloud, badge_for_travis,
+        encode_static_badge_segment,
     };
+    use crate::forge::RepoRef;
+    use crate::forge::{ForgeKind, parse_repo_url, parse_repo_url_with};
+
+    fn github_repo() -> RepoRef {
+        parse_repo_url("https://github.com/f4ah6o/bdg-rs").expect("repo")
+    }
 
     #[test]
     fn static_badge_segment_escapes_shields_separator() {
@@ -376,25 +1051,122 @@ mod tests {
             "[![docs](https://img.shields.io/badge/docs-online-blue.svg)](https://example.com/docs)"
         );
         assert_eq!(
-            badge_for_github_release("f4ah6o", "bdg-rs").render_markdown(),
+            badge_for_release(&github_repo()).render_markdown(),
             "[![release](https://img.shields.io/github/v/release/f4ah6o/bdg-rs.svg)](https://github.com/f4ah6o/bdg-rs/releases)"
         );
         assert_eq!(
-            badge_for_codecov("f4ah6o", "bdg-rs").render_markdown(),
+            badge_for_codecov(&github_repo())
+                .expect("codecov")
+                .render_markdown(),
             "[![codecov](https://img.shields.io/codecov/c/github/f4ah6o/bdg-rs.svg)](https://codecov.io/gh/f4ah6o/bdg-rs)"
         );
     }
 
+    #[test]
+    fn pypi_badges_render_expected_markdown() {
+        assert_eq!(
+            badge_for_pypi("requests").render_markdown(),
+            "[![PyPI](https://img.shields.io/pypi/v/requests.svg)](https://pypi.org/project/requests/)"
+        );
+        assert_eq!(
+            badge_for_pypi_downloads("requests").render_markdown(),
+            "[![PyPI downloads](https://img.shields.io/pypi/dm/requests.svg)](https://pypi.org/project/requests/)"
+        );
+        let versions = badge_for_pypi_python_versions("requests");
+        assert!(versions.sync_default);
+        assert_eq!(
+            versions.image_url,
+            "https://img.shields.io/pypi/pyversions/requests.svg"
+        );
+    }
+
+    #[test]
+    fn moonbit_badge_renders_version_message() {
+        assert!(!badge_for_moonbit("example/demo", "1.0.0").sync_default);
+        assert_eq!(
+            badge_for_moonbit("example/demo", "1.0.0-beta").render_markdown(),
+            "[![moonbit](https://img.shields.io/badge/moonbit-1.0.0--beta-informational)](https://mooncakes.io/docs/example/demo)"
+        );
+    }
+
+    #[test]
+    fn go_badges_render_expected_markdown() {
+        assert_eq!(
+            badge_for_go_reference("github.com/f4ah6o/demo").render_markdown(),
+            "[![Go Reference](https://pkg.go.dev/badge/github.com/f4ah6o/demo.svg)](https://pkg.go.dev/github.com/f4ah6o/demo)"
+        );
+        assert_eq!(
+            badge_for_go_version("1.22").image_url,
+            "https://img.shields.io/badge/go-1.22-00ADD8.svg"
+        );
+        assert_eq!(
+            badge_for_go_report_card("github.com/f4ah6o/demo").render_markdown(),
+            "[![Go Report Card](https://goreportcard.com/badge/github.com/f4ah6o/demo)](https://goreportcard.com/report/github.com/f4ah6o/demo)"
+        );
+    }
+
+    #[test]
+    fn local_manifest_badges_are_static_sync_defaults() {
+        let msrv = badge_for_rust_version("1.85");
+        assert!(msrv.sync_default);
+        assert_eq!(
+            msrv.image_url,
+            "https://img.shields.io/badge/rustc-1.85%2B-orange.svg"
+        );
+        assert_eq!(
+            badge_for_rust_edition("2024").image_url,
+            "https://img.shields.io/badge/edition-2024-orange.svg"
+        );
+        assert_eq!(
+            badge_for_node_engine(">=18").render_markdown(),
+            "[![Node](https://img.shields.io/badge/node-%3E%3D18-339933.svg)](https://nodejs.org/)"
+        );
+    }
+
+    #[test]
+    fn jsr_badges_render_expected_markdown() {
+        assert_eq!(
+            badge_for_jsr("@std/path").render_markdown(),
+            "[![JSR](https://jsr.io/badges/@std/path)](https://jsr.io/@std/path)"
+        );
+        assert_eq!(
+            badge_for_jsr_score("@std/path").render_markdown(),
+            "[![JSR score](https://jsr.io/badges/@std/path/score)](https://jsr.io/@std/path)"
+        );
+    }
+
+    #[test]
+    fn container_badges_render_expected_markdown() {
+        assert_eq!(
+            badge_for_docker_version("f4ah6o/bdg").render_markdown(),
+            "[![Docker Hub](https://img.shields.io/docker/v/f4ah6o/bdg.svg?sort=semver)](https://hub.docker.com/r/f4ah6o/bdg)"
+        );
+        assert_eq!(
+            badge_for_ghcr_version("f4ah6o/bdg").render_markdown(),
+            "[![GHCR](https://ghcr-badge.egpl.dev/f4ah6o/bdg/latest_tag?trim=major&label=ghcr)](https://ghcr.io/f4ah6o/bdg)"
+        );
+        let optional = [
+            badge_for_docker_image_size("f4ah6o/bdg"),
+            badge_for_docker_pulls("f4ah6o/bdg"),
+            badge_for_ghcr_image_size("f4ah6o/bdg"),
+        ];
+        assert!(optional.iter().all(|badge| !badge.sync_default));
+        assert_eq!(
+            optional[1].image_url,
+            "https://img.shields.io/docker/pulls/f4ah6o/bdg.svg"
+        );
+    }
+
     #[test]
     fn optional_github_and_msrv_badges_are_supported_without_expanding_default_sync() {
         let badges = [
             badge_for_crates_msrv("bdg"),
-            badge_for_github_downloads("f4ah6o", "bdg-rs"),
-            badge_for_github_stars("f4ah6o", "bdg-rs"),
-            badge_for_github_forks("f4ah6o", "bdg-rs"),
-            badge_for_github_issues("f4ah6o", "bdg-rs"),
-            badge_for_github_pull_requests("f4ah6o", "bdg-rs"),
-            badge_for_github_last_commit("f4ah6o", "bdg-rs"),
+            badge_for_github_downloads(&github_repo()),
+            badge_for_github_stars(&github_repo()),
+            badge_for_github_forks(&github_repo()),
+            badge_for_github_issues(&github_repo()),
+            badge_for_github_pull_requests(&github_repo()),
+            badge_for_github_last_commit(&github_repo()),
         ];
 
         assert!(badges.iter().all(|badge| !badge.sync_default));
@@ -430,4 +1202,152 @@ mod tests {
                 .contains("/github/last-commit/f4ah6o/bdg-rs.svg")
         );
     }
+
+    #[test]
+    fn ci_service_badges_use_official_endpoints() {
+        let repo = github_repo();
+        assert_eq!(
+            badge_for_circleci(&repo, Some("main"))
+                .expect("circleci")
+                .render_markdown(),
+            "[![CircleCI](https://dl.circleci.com/status-badge/img/gh/f4ah6o/bdg-rs/tree/main.svg?style=svg)](https://dl.circleci.com/status-badge/redirect/gh/f4ah6o/bdg-rs/tree/main)"
+        );
+        assert_eq!(
+            badge_for_travis(&repo, Some("main"))
+                .expect("travis")
+                .image_url,
+            "https://app.travis-ci.com/f4ah6o/bdg-rs.svg?branch=main"
+        );
+        let gitlab = parse_repo_url("https://gitlab.com/group/app").expect("repo");
+        assert!(badge_for_circleci(&gitlab, None).is_none());
+        assert!(badge_for_travis(&gitlab, None).is_none());
+        assert_eq!(
+            badge_for_azure_pipelines("org", "My Project", "f4ah6o.bdg-rs", Some("main"))
+                .render_markdown(),
+            "[![Azure Pipelines](https://dev.azure.com/org/My%20Project/_apis/build/status/f4ah6o.bdg-rs?branchName=main)](https://dev.azure.com/org/My%20Project/_build)"
+        );
+        assert_eq!(
+            badge_for_buildkite("abc123", Some("org/app"), None).render_markdown(),
+            "[![Buildkite](https://badge.buildkite.com/abc123.svg)](https://buildkite.com/org/app)"
+        );
+        assert_eq!(
+            badge_for_jenkins("https://jenkins.example.com/", "team/app").render_markdown(),
+            "[![Jenkins](https://jenkins.example.com/buildStatus/icon?job=team%2Fapp)](https://jenkins.example.com/job/team/job/app/)"
+        );
+    }
+
+    #[test]
+    fn coverage_provider_badges() {
+        let repo = github_repo();
+        assert_eq!(
+            badge_for_coveralls(&repo)
+                .expect("coveralls")
+                .render_markdown(),
+            "[![coveralls](https://img.shields.io/coveralls/github/f4ah6o/bdg-rs.svg)](https://coveralls.io/github/f4ah6o/bdg-rs)"
+        );
+        assert_eq!(
+            badge_for_codacy(&repo, "0123abcd").render_markdown(),
+            "[![codacy](https://img.shields.io/codacy/coverage/0123abcd.svg)](https://app.codacy.com/gh/f4ah6o/bdg-rs/dashboard)"
+        );
+        assert_eq!(
+            badge_for_sonarcloud(&repo, "f4ah6o_bdg-rs").render_markdown(),
+            "[![sonarcloud](https://img.shields.io/sonar/coverage/f4ah6o_bdg-rs.svg?server=https%3A%2F%2Fsonarcloud.io)](https://sonarcloud.io/summary/new_code?id=f4ah6o_bdg-rs)"
+        );
+        assert_eq!(
+            badge_for_self_hosted_codecov("https://codecov.corp.example/", &repo)
+                .expect("codecov")
+                .render_markdown(),
+            "[![codecov](https://codecov.corp.example/gh/f4ah6o/bdg-rs/graph/badge.svg)](https://codecov.corp.example/gh/f4ah6o/bdg-rs)"
+        );
+
+        let enterprise = parse_repo_url_with("https://ghe.corp.example/team/app", |_| {
+            Some(ForgeKind::GitHub)
+        })
+        .expect("repo");
+        assert!(badge_for_coveralls(&enterprise).is_none());
+        assert_eq!(
+            badge_for_self_hosted_codecov("https://codecov.corp.example", &enterprise)
+                .expect("codecov")
+                .image_url,
+            "https://codecov.corp.example/ghe/team/app/graph/badge.svg"
+        );
+        let forgejo = parse_repo_url("https://codeberg.org/team/app").expect("repo");
+        assert!(badge_for_self_hosted_codecov("https://codecov.corp.example", &forgejo).is_none());
+        assert_eq!(badge_for_codacy(&forgejo, "id").link_url, None);
+    }
+
+    #[test]
+    fn security_badges_are_optional_and_follow_the_forge() {
+        let gitlab = parse_repo_url("https://gitlab.com/group/app").expect("repo");
+        let scorecard = badge_for_scorecard(&gitlab).expect("scorecard");
+        assert!(!scorecard.sync_default);
+        assert_eq!(
+            scorecard.image_url,
+            "https://api.scorecard.dev/projects/gitlab.com/group/app/badge"
+        );
+        assert!(
+            badge_for_scorecard(&parse_repo_url("https://codeberg.org/team/app").expect("repo"))
+                .is_none()
+        );
+        assert_eq!(
+            badge_for_security_policy(&gitlab, ".github/SECURITY.md", "trunk").link_url,
+            Some("https://gitlab.com/group/app/-/blob/trunk/.github/SECURITY.md".to_string())
+        );
+        let forgejo = parse_repo_url("https://codeberg.org/team/app").expect("repo");
+        assert_eq!(
+            badge_for_security_policy(&forgejo, "SECURITY.md", "main").link_url,
+            Some("https://codeberg.org/team/app/src/branch/main/SECURITY.md".to_string())
+        );
+    }
+
+    #[test]
+    fn gitlab_badges_use_native_endpoints() {
+        let repo = parse_repo_url("git@gitlab.com:group/sub/app.git").expect("repo");
+        assert_eq!(
+            badge_for_gitlab_pipeline(&repo, "main").render_markdown(),
+            "[![pipeline](https://gitlab.com/group/sub/app/badges/main/pipeline.svg)](https://gitlab.com/group/sub/app/-/commits/main)"
+        );
+        assert_eq!(
+            badge_for_gitlab_coverage(&repo, "main").image_url,
+            "https://gitlab.com/group/sub/app/badges/main/coverage.svg"
+        );
+        assert_eq!(
+            badge_for_release(&repo).render_markdown(),
+            "[![release](https://gitlab.com/group/sub/app/-/badges/release.svg)](https://gitlab.com/group/sub/app/-/releases)"
+        );
+        assert_eq!(
+            badge_for_codecov(&repo)
+                .and_then(|badge| badge.link_url)
+                .as_deref(),
+            Some("https://codecov.io/gl/group/sub/app")
+        );
+
+        let self_hosted = parse_repo_url("https://gitlab.example.com/team/app").expect("repo");
+        assert_eq!(
+            badge_for_license(&self_hosted).expect("license").image_url,
+            "https://img.shields.io/gitlab/license/team/app.svg?gitlab_url=https%3A%2F%2Fgitlab.example.com"
+        );
+    }
+
+    #[test]
+    fn query_params_replace_existing_values() {
+        let badge = badge_for_crates_msrv("bdg").with_query_params(&[
+            ("style", "flat-square".to_string()),
+            ("color", "#fe7d37".to_string()),
+        ]);
+        assert_eq!(
+            badge.image_url,
+            "https://img.shields.io/crates/msrv/bdg.svg?style=flat-square&color=%23fe7d37"
+        );
+
+        let gitlab = parse_repo_url("https://gitlab.example.com/team/app").expect("repo");
+        let badge = badge_for_license(&gitlab)
+            .expect("license")
+            .with_query_params(&[("style", "flat".to_string())])
+            .with_query_params(&[("style", "for-the-badge".to_string())]);
+        assert_eq!(
+            badge.image_url,
+            "https://img.shields.io/gitlab/license/team/app.svg?gitlab_url=https%3A%2F%2Fgitlab.example.com&style=for-the-badge"
+        );
+    }
 }
@@ -1,9 +1,13 @@
-use crate::config::load_config;
+use crate::config::{Config, load_config};
 use crate::core::build_context;
-use crate::manifest::{read_moon_mod, read_package_json, read_resolved_cargo_package};
+use crate::manifest::{
+    read_go_mod, read_jsr_manifest, read_moon_mod, read_package_json, read_resolved_cargo_package,
+};
 use crate::plan::ReadmePlan;
+use crate::providers::USER_AGENT;
 use crate::readme::{
-    ensure_marker_block, extract_managed_block, resolve_readme, rewrite_marker_block,
+    ReadmeFormat, badge_block_lines, ensure_marker_block, extract_managed_block,
+    rewrite_marker_block, select_readme,
 };
 use anyhow::{Context, bail};
 use serde::{Deserialize, Serialize};
@@ -12,7 +16,6 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 const BUILTIN_CATALOG: &str = include_str!("../catalog/builtin.toml");
-const USER_AGENT: &str = concat!("bdg/", env!("CARGO_PKG_VERSION"));
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct CatalogFile {
@@ -138,6 +141,7 @@ pub fn cmd_catalog_search(
 
 pub fn cmd_catalog_add(
     current_dir: &Path,
+    readme: Option<&Path>,
     ids: &[String],
     sources: &[String],
     set_values: &[String],
@@ -175,11 +179,12 @@ pub fn cmd_catalog_add(
         }
         rendered.push((id.clone(), render_badge(&loaded.badge, &values)?));
     }
-    apply_rendered_badges(current_dir, rendered, dry_run, json)
+    apply_rendered_badges(current_dir, readme, rendered, dry_run, json)
 }
 
 pub fn cmd_catalog_add_url(
     current_dir: &Path,
+    readme: Option<&Path>,
     image: &str,
     label: &str,
     link: Option<&str>,
@@ -198,6 +203,7 @@ pub fn cmd_catalog_add_url(
     };
     apply_rendered_badges(
         current_dir,
+        readme,
         vec![("external-url".to_string(), markdown)],
         dry_run,
         json,
@@ -206,13 +212,19 @@ pub fn cmd_catalog_add_url(
 
 fn apply_rendered_badges(
     current_dir: &Path,
+    readme: Option<&Path>,
     rendered: Vec<(String, String)>,
     dry_run: bool,
     json: bool,
 ) -> anyhow::Result<i32> {
     let context = build_context(current_dir)?;
-    let readme_path = resolve_readme(&context.root, context.has_moonbit());
+    let config = load_config(current_dir, &context.root).unwrap_or_else(|error| {
+        eprintln!("warning: {error:#}; using the default configuration");
+        Config::default()
+    });
+    let readme_path = select_readme(current_dir, &context, &config, readme).path;
     let content = ensure_marker_block(&readme_path)?;
+    let format = ReadmeFormat::of(&readme_path, &content);
     let mut lines = extract_managed_block(&content);
     let mut existing_images = lines
         .iter()
@@ -225,15 +237,21 @@ fn apply_rendered_badges(
     for (id, markdown) in rendered {
         let parsed = crate::readme_badges::parse_badge_line_optional(&markdown)
             .context("catalog rendered invalid badge Markdown")?;
-        if existing_images.insert(parsed.image) {
-            lines.push(markdown);
+        if existing_images.insert(parsed.image.clone()) {
+            lines.push(format.render_parts(
+                &parsed.label,
+                &parsed.image,
+                parsed.link.as_deref(),
+                config.render.mode,
+            ));
             added.push(id);
         } else {
             unchanged.push(id);
         }
     }
 
-    let updated = rewrite_marker_block(&content, &lines)?;
+    let updated =
+        rewrite_marker_block(&content, &badge_block_lines(lines, format, &config.render))?;
     let plan = ReadmePlan::new(readme_path, content, updated);
     let diff = plan.diff();
 
@@ -416,6 +434,13 @@ fn project_values(current_dir: &Path) -> anyhow::Result<BTreeMap<String, String>
         values.insert("package".to_string(), name.clone());
         values.entry("name".to_string()).or_insert(name);
     }
+    if let Some(path) = &context.manifests.jsr_manifest
+        && let Ok(manifest) = read_jsr_manifest(path)
+        && let Some(name) = manifest.name
+    {
+        values.entry("package".to_string()).or_insert(name.clone());
+        values.entry("name".to_string()).or_insert(name);
+    }
     if let Some(path) = &context.manifests.moon_mod
         && let Ok(module) = read_moon_mod(path)
         && let Some(name) = module.name
@@ -423,6 +448,13 @@ fn project_values(current_dir: &Path) -> anyhow::Result<BTreeMap<String, String>
         values.insert("module".to_string(), name.clone());
         values.entry("name".to_string()).or_insert(name);
     }
+    if let Some(path) = &context.manifests.go_mod
+        && let Ok(module) = read_go_mod(path)
+        && let Some(name) = module.module
+    {
+        values.entry("module".to_string()).or_insert(name.clone());
+        values.entry("name".to_string()).or_insert(name);
+    }
     Ok(values)
 }
 
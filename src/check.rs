@@ -1,6 +1,9 @@
+use crate::badge_service::BadgeHosts;
+use crate::config::{Config, load_config};
 use crate::core::build_context;
-use crate::readme::{extract_managed_block, marker_state, resolve_readme};
-use crate::readme_badges::parse_badge_line_optional;
+use crate::git::GitRepository;
+use crate::readme::{extract_managed_block, marker_state, select_readme};
+use crate::readme_badges::{is_ci_kind, parse_badge_line_optional_with};
 use serde::Serialize;
 use std::collections::HashSet;
 use std::path::Path;
@@ -29,7 +32,7 @@ pub struct MarkerReport {
     pub ordered: bool,
 }
 
-pub fn inspect_readme(path: &Path, content: &str, strict: bool) -> CheckReport {
+pub fn inspect_readme(path: &Path, content: &str, hosts: &BadgeHosts, strict: bool) -> CheckReport {
     let marker = marker_state(content);
     let mut issues = Vec::new();
 
@@ -57,7 +60,7 @@ pub fn inspect_readme(path: &Path, content: &str, strict: bool) -> CheckReport {
     };
     let mut ids = HashSet::new();
     for line in &badges {
-        match parse_badge_line_optional(line) {
+        match parse_badge_line_optional_with(line, hosts) {
             Some(parsed) if parsed.kind != "unknown" => {
                 if !ids.insert(parsed.id.clone()) {
                     issues.push(CheckIssue {
@@ -90,15 +93,67 @@ pub fn inspect_readme(path: &Path, content: &str, strict: bool) -> CheckReport {
     }
 }
 
-pub fn cmd_check(current_dir: &Path, json: bool, strict: bool) -> anyhow::Result<i32> {
+/// Managed CI badges pinned with `?branch=` to a branch the repository does
+/// not have, which would report no runs at all.
+pub fn pinned_branch_issues(
+    content: &str,
+    repository: &GitRepository,
+    hosts: &BadgeHosts,
+    strict: bool,
+) -> Vec<CheckIssue> {
+    if !marker_state(content).is_valid() {
+        return Vec::new();
+    }
+    extract_managed_block(content)
+        .iter()
+        .filter_map(|line| parse_badge_line_optional_with(line, hosts))
+        .filter(|parsed| is_ci_kind(&parsed.kind))
+        .filter_map(|parsed| {
+            let branch = parsed.meta.as_ref()?.get("branch")?.as_str()?.to_string();
+            (!repository.has_branch(&branch)).then(|| CheckIssue {
+                level: if strict { "error" } else { "warning" },
+                code: "UNKNOWN_BRANCH",
+                message: format!(
+                    "badge `{}` is pinned to branch `{}`, which does not exist",
+                    parsed.id, branch
+                ),
+            })
+        })
+        .collect()
+}
+
+pub fn cmd_check(
+    current_dir: &Path,
+    readme: Option<&Path>,
+    json: bool,
+    strict: bool,
+) -> anyhow::Result<i32> {
     let context = build_context(current_dir)?;
-    let readme_path = resolve_readme(&context.root, context.has_moonbit());
+    // A broken `.bdg.toml` is a finding, not a reason to skip the README.
+    let (config, config_issue) = match load_config(current_dir, &context.root) {
+        Ok(config) => (config, None),
+        Err(error) => (
+            Config::default(),
+            Some(CheckIssue {
+                level: "error",
+                code: "CONFIG_INVALID",
+                message: format!("{error:#}"),
+            }),
+        ),
+    };
+    let readme_path = select_readme(current_dir, &context, &config, readme).path;
     let content = if readme_path.exists() {
         std::fs::read_to_string(&readme_path)?
     } else {
         String::new()
     };
-    let mut report = inspect_readme(&readme_path, &content, strict);
+    let hosts = BadgeHosts::from_config(&config);
+    let mut report = inspect_readme(&readme_path, &content, &hosts, strict);
+    if let Some(repository) = GitRepository::discover(&context.root) {
+        let issues = pinned_branch_issues(&content, &repository, &hosts, strict);
+        report.ok &= !issues.iter().any(|issue| issue.level == "error");
+        report.issues.extend(issues);
+    }
     if !readme_path.exists() {
         report.issues.insert(
             0,
@@ -110,6 +165,10 @@ pub fn cmd_check(current_dir: &Path, json: bool, strict: bool) -> anyhow::Result
         );
         report.ok = false;
     }
+    if let Some(issue) = config_issue {
+        report.issues.insert(0, issue);
+        report.ok = false;
+    }
 
     if json {
         serde_json::to_writer_pretty(std::io::stdout(), &report)?;
@@ -131,14 +190,59 @@ pub fn cmd_check(current_dir: &Path, json: bool, strict: bool) -> anyhow::Result
 
 #[cfg(test)]
 mod tests {
-    use super::inspect_readme;
+    use super::{inspect_readme, pinned_branch_issues};
+    use crate::badge_service::BadgeHosts;
+    use crate::git::GitRepository;
     use std::path::Path;
 
+    #[test]
+    fn flags_ci_badges_pinned_to_missing_branches() {
+        let temp = tempfile::tempdir().unwrap();
+        let git = temp.path().join(".git");
+        std::fs::create_dir_all(git.join("refs/heads")).unwrap();
+        std::fs::create_dir_all(git.join("refs/remotes/origin")).unwrap();
+        std::fs::write(git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        std::fs::write(git.join("refs/heads/main"), "0123\n").unwrap();
+        std::fs::write(git.join("refs/remotes/origin/next"), "0123\n").unwrap();
+        std::fs::write(
+            git.join("packed-refs"),
+            "# pack-refs with: peeled\n0123 refs/remotes/origin/release/1.x\n",
+        )
+        .unwrap();
+        let repository = GitRepository::discover(temp.path()).unwrap();
+        let badge = |file: &str, query: &str| {
+            format!(
+                "[![CI](https://github.com/o/r/actions/workflows/{file}/badge.svg{query})](https://github.com/o/r/actions/workflows/{file})\n"
+            )
+        };
+        let content = [
+            "<!-- bdg:begin -->\n".to_string(),
+            badge("a.yml", "?branch=main&event=push"),
+            badge("b.yml", "?branch=next"),
+            badge("c.yml", "?branch=release%2F1.x"),
+            badge("d.yml", ""),
+            badge("e.yml", "?branch=gone"),
+            "<!-- bdg:end -->\n".to_string(),
+        ]
+        .concat();
+
+        let issues = pinned_branch_issues(&content, &repository, &BadgeHosts::default(), false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code, "UNKNOWN_BRANCH");
+        assert_eq!(issues[0].level, "warning");
+        assert!(issues[0].message.contains("`ci:e.yml`"));
+        assert_eq!(
+            pinned_branch_issues(&content, &repository, &BadgeHosts::default(), true)[0].level,
+            "error"
+        );
+    }
+
     #[test]
     fn accepts_valid_managed_badges() {
         let report = inspect_readme(
             Path::new("README.md"),
             "# demo\n<!-- bdg:begin -->\n[![CI](https://github.com/o/r/actions/workflows/ci.yml/badge.svg)](https://github.com/o/r/actions/workflows/ci.yml)\n<!-- bdg:end -->\n",
+            &BadgeHosts::default(),
             true,
         );
         assert!(report.ok);
@@ -151,6 +255,7 @@ mod tests {
         let report = inspect_readme(
             Path::new("README.md"),
             "<!-- bdg:begin -->\nplain text\n<!-- bdg:end -->\n",
+            &BadgeHosts::default(),
             true,
         );
         assert!(!report.ok);
@@ -159,13 +264,19 @@ mod tests {
 
     #[test]
     fn detects_missing_and_duplicate_markers() {
-        let missing = inspect_readme(Path::new("README.md"), "# demo\n", false);
+        let missing = inspect_readme(
+            Path::new("README.md"),
+            "# demo\n",
+            &BadgeHosts::default(),
+            false,
+        );
         assert!(!missing.ok);
         assert_eq!(missing.issues[0].code, "MARKER_MISSING");
 
         let duplicate = inspect_readme(
             Path::new("README.md"),
             "<!-- bdg:begin -->\n<!-- bdg:begin -->\n<!-- bdg:end -->\n",
+            &BadgeHosts::default(),
             false,
         );
         assert!(!duplicate.ok);
@@ -8,6 +8,7 @@ pub enum ParseOutcome {
 #[derive(Debug, PartialEq, Eq)]
 pub struct Cli {
     pub directory: Option<String>,
+    pub readme: Option<String>,
     pub command: Commands,
 }
 
@@ -26,6 +27,7 @@ pub enum Commands {
         dry_run: bool,
         check: bool,
         json: bool,
+        workspace: bool,
     },
     Check {
         json: bool,
@@ -93,6 +95,7 @@ where
     }
 
     let directory = take_single_value(&mut args, &["-C", "--directory"])?;
+    let readme = take_single_value(&mut args, &["--readme"])?;
     if args.is_empty() {
         return Err("missing command".to_string());
     }
@@ -118,6 +121,7 @@ where
             dry_run: take_bool(&mut args, "--dry-run")?,
             check: take_bool(&mut args, "--check")?,
             json: take_bool(&mut args, "--json")?,
+            workspace: take_bool(&mut args, "--workspace")?,
         },
         "check" => Commands::Check {
             json: take_bool(&mut args, "--json")?,
@@ -146,7 +150,11 @@ where
     if let Some(arg) = args.first() {
         return Err(format!("unexpected argument `{arg}`"));
     }
-    Ok(ParseOutcome::Run(Cli { directory, command }))
+    Ok(ParseOutcome::Run(Cli {
+        directory,
+        readme,
+        command,
+    }))
 }
 
 fn parse_catalog_command(args: &mut Vec<String>) -> Result<CatalogCommand, String> {
@@ -213,7 +221,7 @@ fn parse_catalog_command(args: &mut Vec<String>) -> Result<CatalogCommand, Strin
 }
 
 pub fn help() -> &'static str {
-    "Badge management for project READMEs\n\nUsage:\n  bdg <COMMAND> [OPTIONS]\n  bdg [GLOBAL OPTIONS] <COMMAND> [OPTIONS]\n\nCommands:\n  sync      Reconcile the managed badge block non-interactively\n  check     Validate marker structure and managed badge syntax\n  add       Add built-in badges to the managed README block\n  catalog   Search and add declarative badges from built-in/external catalogs\n  list      Inspect project metadata and managed badges\n  remove    Remove managed badges\n  skills    Print the bundled bdg Agent Skill\n\nGlobal options:\n  -C, --directory <PATH>  Run as if bdg started in PATH\n  -h, --help              Print help\n  -V, --version           Print version\n\nBadge types:\n  ci, version, license, release, docs, downloads, coverage,\n  msrv, stars, forks, issues, pulls, activity\n\nCatalog:\n  bdg catalog search [QUERY] [--source <PATH|URL>] [--json]\n  bdg catalog add <ID>... [--source <PATH|URL>] [--set KEY=VALUE] [--dry-run] [--json]\n  bdg catalog add-url <IMAGE_URL> [--label <TEXT>] [--link <URL>] [--dry-run] [--json]\n\n  Sources may be TOML or JSON using schema bdg.catalog/v1.\n  Project placeholders: {owner}, {repo}, {crate}, {package}, {module}, {name}.\n\nSync options:\n      --only <TYPES>      Comma-separated badge types\n      --allow-yy-calver\n      --dry-run           Print planned changes without writing\n      --check             Exit 2 when the README is not synchronized\n      --json\n\nCheck options:\n      --strict            Treat unknown managed lines as errors\n      --json\n\nAdd options:\n      --yes\n      --only <TYPES>      Comma-separated badge types\n      --allow-yy-calver\n      --dry-run\n      --json\n\nList options:\n      --json\n      --quiet\n      --allow-yy-calver\n\nRemove options:\n      --all\n      --id <ID>\n      --kind <KIND>\n      --strict\n      --quiet\n      --dry-run\n      --json\n      --allow-yy-calver\n\nExit codes:\n  0  success / synchronized\n  1  runtime or validation error\n  2  usage error or changes detected by --dry-run/--check\n"
+    "Badge management for project READMEs\n\nUsage:\n  bdg <COMMAND> [OPTIONS]\n  bdg [GLOBAL OPTIONS] <COMMAND> [OPTIONS]\n\nCommands:\n  sync      Reconcile the managed badge block non-interactively\n  check     Validate marker structure and managed badge syntax\n  add       Add built-in badges to the managed README block\n  catalog   Search and add declarative badges from built-in/external catalogs\n  list      Inspect project metadata and managed badges\n  remove    Remove managed badges\n  skills    Print the bundled bdg Agent Skill\n\nGlobal options:\n  -C, --directory <PATH>  Run as if bdg started in PATH\n      --readme <PATH>     Manage PATH instead of the detected README\n  -h, --help              Print help\n  -V, --version           Print version\n\nBadge types:\n  ci, version, license, release, docs, downloads, coverage,\n  python, go, quality, container, edition, node,\n  msrv, stars, forks, issues, pulls, activity, security\n\nCatalog:\n  bdg catalog search [QUERY] [--source <PATH|URL>] [--json]\n  bdg catalog add <ID>... [--source <PATH|URL>] [--set KEY=VALUE] [--dry-run] [--json]\n  bdg catalog add-url <IMAGE_URL> [--label <TEXT>] [--link <URL>] [--dry-run] [--json]\n\n  Sources may be TOML or JSON using schema bdg.catalog/v1.\n  Project placeholders: {owner}, {repo}, {crate}, {package}, {module}, {name}.\n\nSync options:\n      --only <TYPES>      Comma-separated badge types\n      --allow-yy-calver\n      --dry-run           Print planned changes without writing\n      --check             Exit 2 when the README is not synchronized\n      --workspace         Sync each Cargo workspace member README plus the root\n      --json\n\nCheck options:\n      --strict            Treat unknown managed lines as errors\n      --json\n\nAdd options:\n      --yes\n      --only <TYPES>      Comma-separated badge types\n      --allow-yy-calver\n      --dry-run\n      --json\n\nList options:\n      --json\n      --quiet\n      --allow-yy-calver\n\nRemove options:\n      --all\n      --id <ID>\n      --kind <KIND>\n      --strict\n      --quiet\n      --dry-run\n      --json\n      --allow-yy-calver\n\nExit codes:\n  0  success / synchronized\n  1  runtime or validation error\n  2  usage error or changes detected by --dry-run/--check\n"
 }
 
 fn take_bool(args: &mut Vec<String>, name: &str) -> Result<bool, String> {
@@ -288,6 +296,7 @@ mod tests {
             parsed,
             ParseOutcome::Run(super::Cli {
                 directory: None,
+                readme: None,
                 command: Commands::Add {
                     yes: true,
                     only: vec!["ci".to_string(), "version".to_string()],
@@ -313,6 +322,7 @@ mod tests {
             .unwrap(),
             ParseOutcome::Run(super::Cli {
                 directory: None,
+                readme: None,
                 command: Commands::Catalog(CatalogCommand::Search {
                     query: Some("github".to_string()),
                     source: vec!["extra.toml".to_string()],
@@ -333,6 +343,7 @@ mod tests {
             .unwrap(),
             ParseOutcome::Run(super::Cli {
                 directory: None,
+                readme: None,
                 command: Commands::Catalog(CatalogCommand::Add {
                     ids: vec![
                         "custom".to_string(),
@@ -360,6 +371,7 @@ mod tests {
             .unwrap(),
             ParseOutcome::Run(super::Cli {
                 directory: None,
+                readme: None,
                 command: Commands::Catalog(CatalogCommand::AddUrl {
                     image: "https://example.com/status.svg".to_string(),
                     label: "status".to_string(),
@@ -386,19 +398,22 @@ mod tests {
             parsed,
             ParseOutcome::Run(super::Cli {
                 directory: Some("repo".to_string()),
+                readme: None,
                 command: Commands::Sync {
                     only: vec!["ci".to_string(), "license".to_string()],
                     allow_yy_calver: false,
                     dry_run: false,
                     check: true,
                     json: true,
+                    workspace: false,
                 }
             })
         );
         assert_eq!(
-            parse_args(["check", "--strict"]).unwrap(),
+            parse_args(["--readme", "docs/INDEX.md", "check", "--strict"]).unwrap(),
             ParseOutcome::Run(super::Cli {
                 directory: None,
+                readme: Some("docs/INDEX.md".to_string()),
                 command: Commands::Check {
                     json: false,
                     strict: true,
@@ -422,6 +437,7 @@ mod tests {
             parsed,
             ParseOutcome::Run(super::Cli {
                 directory: None,
+                readme: None,
                 command: Commands::Remove {
                     all: false,
                     id: vec!["ci:rust.yaml".to_string(), "npm:bdg".to_string()],
@@ -443,6 +459,7 @@ mod tests {
             parsed,
             ParseOutcome::Run(super::Cli {
                 directory: None,
+                readme: None,
                 command: Commands::List {
                     json: true,
                     quiet: true,
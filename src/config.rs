@@ -1,5 +1,10 @@
+use crate::badge_service::BadgeService;
+use crate::coverage::CoverageProvider;
+use crate::forge::{ForgeKind, RepoRef, parse_repo_url_with};
+use anyhow::Context;
 use serde::Deserialize;
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct Config {
@@ -9,6 +14,23 @@ pub struct Config {
     pub badges: BadgesConfig,
     #[serde(default)]
     pub catalog: CatalogConfig,
+    #[serde(default)]
+    pub forge: ForgeConfig,
+    #[serde(default)]
+    pub git: GitConfig,
+    #[serde(default)]
+    pub ci: CiConfig,
+    #[serde(default)]
+    pub coverage: CoverageConfig,
+    #[serde(default)]
+    pub security: SecurityConfig,
+    #[serde(default)]
+    pub style: StyleConfig,
+    #[serde(default)]
+    pub render: RenderConfig,
+    /// README to manage instead of the detected one. Relative paths are resolved
+    /// against the directory containing `.bdg.toml`.
+    pub readme: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -35,6 +57,52 @@ impl Default for VersionConfig {
 pub struct BadgesConfig {
     #[serde(default)]
     pub exclude: Vec<String>,
+    /// Image service for registry, license and repository badges: `shields`
+    /// (the default) or `badgen`.
+    #[serde(default)]
+    pub service: BadgeService,
+    /// Root of a self-hosted instance of `service`.
+    pub url: Option<String>,
+    /// Root of the service rendering GHCR badges, `https://ghcr-badge.egpl.dev`
+    /// by default. An empty string turns GHCR badges off.
+    pub ghcr_url: Option<String>,
+}
+
+impl BadgesConfig {
+    pub fn ghcr_enabled(&self) -> bool {
+        self.ghcr_url.as_deref() != Some("")
+    }
+}
+
+/// How badges are written into the managed block.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RenderConfig {
+    #[serde(default)]
+    pub mode: RenderMode,
+    /// Wraps HTML badges in a `<p align="center">` container.
+    #[serde(default)]
+    pub center: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderMode {
+    /// One `[![label](image)](link)` line per badge.
+    #[default]
+    Markdown,
+    /// One `<a href><img alt src></a>` element per line, for READMEs that lay
+    /// badges out with HTML.
+    Html,
+    /// Like `html`, with each shields badge in a `<picture>` offering the
+    /// `[style.dark]` and `[style.light]` variants by `prefers-color-scheme`.
+    Picture,
+}
+
+impl RenderMode {
+    /// Whether badges are written as HTML elements.
+    pub const fn is_html(self) -> bool {
+        matches!(self, Self::Html | Self::Picture)
+    }
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -43,6 +111,256 @@ pub struct CatalogConfig {
     pub sources: Vec<String>,
 }
 
+/// Overrides for which Actions workflows get CI badges. Entries name a
+/// workflow by file name, file stem, or `name:`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CiConfig {
+    /// Workflows badged even though they do not run on push or pull request
+    /// to the default branch.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Workflows never badged.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Per-workflow badge settings, e.g. `[ci.workflows."ci.yml"]`.
+    #[serde(default)]
+    pub workflows: BTreeMap<String, CiWorkflowConfig>,
+    pub azure_pipelines: Option<AzurePipelinesConfig>,
+    pub buildkite: Option<BuildkiteConfig>,
+    pub jenkins: Option<JenkinsConfig>,
+}
+
+/// Azure DevOps location of the pipeline, which the repository cannot tell.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AzurePipelinesConfig {
+    pub organization: String,
+    pub project: String,
+    /// Pipeline name. Pipelines created from a GitHub repository default to
+    /// `owner.repo`.
+    pub pipeline: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BuildkiteConfig {
+    /// Key from the pipeline's badge URL, `https://badge.buildkite.com/<key>.svg`.
+    pub badge: String,
+    /// `organization/pipeline` slug the badge links to.
+    pub pipeline: Option<String>,
+}
+
+/// Jenkins server with the Embeddable Build Status plugin.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JenkinsConfig {
+    pub url: String,
+    /// Job path, with folders separated by `/`. Defaults to the repository
+    /// name.
+    pub job: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CiWorkflowConfig {
+    /// Branch the badge reports on instead of the remote default branch. An
+    /// empty string reports on every branch.
+    pub branch: Option<String>,
+    /// Only report runs triggered by this event, e.g. `push`.
+    pub event: Option<String>,
+}
+
+/// Coverage badge settings. Every detected provider gets a badge unless
+/// `provider` picks one.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CoverageConfig {
+    /// The only provider to badge, used even when it is not detected.
+    pub provider: Option<CoverageProvider>,
+    pub codecov: Option<CodecovConfig>,
+    pub codacy: Option<CodacyConfig>,
+    pub sonarcloud: Option<SonarCloudConfig>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CodecovConfig {
+    /// Self-hosted Codecov server. Defaults to the `codecov.url` setting of
+    /// `codecov.yml`, then codecov.io.
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CodacyConfig {
+    /// Project token from the Codacy badge URL, which the repository cannot
+    /// tell.
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SonarCloudConfig {
+    /// Defaults to `sonar.projectKey` from `sonar-project.properties`, then
+    /// `<owner>_<repo>`.
+    pub project_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SecurityConfig {
+    /// OpenSSF Best Practices project number, from
+    /// `https://www.bestpractices.dev/projects/<number>`.
+    pub best_practices_project: Option<u64>,
+}
+
+/// shields.io appearance applied to every shields badge, e.g.
+/// `style = "flat-square"`. `[style.kinds.<kind>]` overrides individual
+/// settings for one badge kind; an empty string there drops a global setting.
+/// `[style.dark]` and `[style.light]` override them again for the theme
+/// variants of `[render] mode = "picture"`, where an empty string likewise
+/// drops the setting from that variant.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StyleConfig {
+    #[serde(flatten)]
+    pub badge: BadgeStyle,
+    #[serde(default)]
+    pub kinds: BTreeMap<String, BadgeStyle>,
+    #[serde(default)]
+    pub dark: BadgeStyle,
+    #[serde(default)]
+    pub light: BadgeStyle,
+}
+
+/// Color scheme a `<picture>` badge variant is shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    Dark,
+    Light,
+}
+
+impl Theme {
+    /// The `prefers-color-scheme` value selecting this variant.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Light => "light",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BadgeStyle {
+    pub style: Option<String>,
+    pub logo: Option<String>,
+    #[serde(alias = "logoColor")]
+    pub logo_color: Option<String>,
+    pub color: Option<String>,
+    #[serde(alias = "labelColor")]
+    pub label_color: Option<String>,
+}
+
+impl StyleConfig {
+    /// shields.io query parameters for badges of `kind`.
+    pub fn params(&self, kind: &str) -> Vec<(&'static str, String)> {
+        self.layered_params(kind, None)
+    }
+
+    /// shields.io query parameters for the `theme` variant of badges of
+    /// `kind`: the theme's settings over those of [`Self::params`].
+    pub fn theme_params(&self, kind: &str, theme: Theme) -> Vec<(&'static str, String)> {
+        let overrides = match theme {
+            Theme::Dark => &self.dark,
+            Theme::Light => &self.light,
+        };
+        self.layered_params(kind, Some(overrides))
+    }
+
+    fn layered_params(
+        &self,
+        kind: &str,
+        theme: Option<&BadgeStyle>,
+    ) -> Vec<(&'static str, String)> {
+        let specific = self.kinds.get(kind);
+        let pick = |field: fn(&BadgeStyle) -> &Option<String>| {
+            theme
+                .and_then(|style| field(style).clone())
+                .or_else(|| specific.and_then(|style| field(style).clone()))
+                .or_else(|| field(&self.badge).clone())
+                .filter(|value| !value.is_empty())
+        };
+        [
+            ("style", pick(|style| &style.style)),
+            ("logo", pick(|style| &style.logo)),
+            ("logoColor", pick(|style| &style.logo_color)),
+            ("color", pick(|style| &style.color)),
+            ("labelColor", pick(|style| &style.label_color)),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value?)))
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GitConfig {
+    /// Remotes to read the repository from, most preferred first, e.g.
+    /// `["upstream", "origin"]`. `origin`, then the first declared remote, is
+    /// used when none of them exist.
+    #[serde(default)]
+    pub remotes: Vec<String>,
+}
+
+/// Self-hosted forge settings for remotes bdg cannot classify by host name.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ForgeConfig {
+    /// Forge serving remotes on unrecognised hosts: `github`, `gitlab`, or
+    /// `forgejo` (also accepted as `gitea` or `codeberg`).
+    pub kind: Option<ForgeKind>,
+    /// Web root used in badge and link URLs instead of `https://<remote host>`.
+    pub url: Option<String>,
+    /// shields.io-compatible instance used for shields badges.
+    pub shields_url: Option<String>,
+    /// Woodpecker CI server. Codeberg repositories default to
+    /// `https://ci.codeberg.org`.
+    pub woodpecker_url: Option<String>,
+    /// Per-host settings, e.g. `[forge.hosts."ghe.corp.example"]`. These take
+    /// precedence over the built-in host names and the fields above.
+    #[serde(default)]
+    pub hosts: BTreeMap<String, ForgeHostConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ForgeHostConfig {
+    pub kind: ForgeKind,
+    pub url: Option<String>,
+    pub shields_url: Option<String>,
+}
+
+impl ForgeConfig {
+    /// Forge serving `host`: a `[forge.hosts]` entry, then the built-in host
+    /// names, then `kind`.
+    pub fn kind_for_host(&self, host: &str) -> Option<ForgeKind> {
+        self.host(host)
+            .map(|entry| entry.kind)
+            .or_else(|| ForgeKind::from_host(host))
+            .or(self.kind)
+    }
+
+    /// Parses a repository URL and applies the configured web root and shields
+    /// instance for its host.
+    pub fn parse_repo_url(&self, url: &str) -> Option<RepoRef> {
+        let repo = parse_repo_url_with(url, |host| self.kind_for_host(host))?;
+        let entry = self.host(&repo.host);
+        let (url, shields_url) = match entry {
+            Some(entry) => (entry.url.as_deref(), entry.shields_url.as_deref()),
+            None => (self.url.as_deref(), self.shields_url.as_deref()),
+        };
+        Some(RepoRef {
+            host_configured: entry.is_some(),
+            ..repo.with_base_url(url).with_shields_url(shields_url)
+        })
+    }
+
+    fn host(&self, host: &str) -> Option<&ForgeHostConfig> {
+        self.hosts
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(host))
+            .map(|(_, entry)| entry)
+    }
+}
+
 pub fn load_config(current_dir: &Path, git_root: &Path) -> anyhow::Result<Config> {
     let mut dir = current_dir.to_path_buf();
     loop {
@@ -62,7 +380,11 @@ pub fn load_config(current_dir: &Path, git_root: &Path) -> anyhow::Result<Config
 
 fn read_config(path: &Path) -> anyhow::Result<Config> {
     let content = std::fs::read_to_string(path)?;
-    let config: Config = toml::from_str(&content)?;
+    let mut config: Config =
+        toml::from_str(&content).with_context(|| format!("invalid config {}", path.display()))?;
+    if let (Some(readme), Some(dir)) = (&config.readme, path.parent()) {
+        config.readme = Some(dir.join(readme));
+    }
     Ok(config)
 }
 
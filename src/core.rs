@@ -1,4 +1,9 @@
-use crate::manifest::cargo_manifest_has_package;
+use crate::config::{ForgeConfig, load_config};
+use crate::forge::{ForgeKind, RepoRef, parse_repo_url_with, repo_url_host};
+use crate::git::{GitRepository, select_remote};
+use crate::manifest::{
+    cargo_manifest_has_package, jsr_manifest_has_package, pyproject_has_package,
+};
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -6,6 +11,22 @@ pub enum Ecosystem {
     Node,
     MoonBit,
     Rust,
+    Python,
+    Go,
+    Deno,
+}
+
+impl Ecosystem {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Node => "node",
+            Self::MoonBit => "moon",
+            Self::Rust => "rust",
+            Self::Python => "python",
+            Self::Go => "go",
+            Self::Deno => "deno",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -13,28 +34,60 @@ pub struct ManifestPaths {
     pub package_json: Option<PathBuf>,
     pub moon_mod: Option<PathBuf>,
     pub cargo_toml: Option<PathBuf>,
+    pub pyproject_toml: Option<PathBuf>,
+    pub go_mod: Option<PathBuf>,
+    pub jsr_manifest: Option<PathBuf>,
     pub package_json_all: Vec<PathBuf>,
     pub moon_mod_all: Vec<PathBuf>,
     pub cargo_toml_all: Vec<PathBuf>,
+    pub pyproject_toml_all: Vec<PathBuf>,
+    pub go_mod_all: Vec<PathBuf>,
+    pub jsr_manifest_all: Vec<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ProjectContext {
     pub root: PathBuf,
-    pub ecosystem: Option<Ecosystem>,
+    /// Every detected ecosystem, in metadata precedence order (see `detect_ecosystems`).
+    pub ecosystems: Vec<Ecosystem>,
     pub manifests: ManifestPaths,
     pub git: Option<GitContext>,
 }
 
 impl ProjectContext {
+    /// Branch that workflow branch filters are checked against: the remote
+    /// default branch, else the checked-out branch, else `main`.
+    pub fn ci_branch(&self) -> &str {
+        self.git
+            .as_ref()
+            .and_then(|git| git.default_branch.as_deref().or(git.branch.as_deref()))
+            .unwrap_or("main")
+    }
+
     pub fn has_moonbit(&self) -> bool {
         self.manifests.moon_mod.is_some()
     }
+
+    pub fn primary_ecosystem(&self) -> Option<Ecosystem> {
+        self.ecosystems.first().copied()
+    }
+
+    pub fn manifest_path(&self, ecosystem: Ecosystem) -> Option<&Path> {
+        let path = match ecosystem {
+            Ecosystem::Node => &self.manifests.package_json,
+            Ecosystem::MoonBit => &self.manifests.moon_mod,
+            Ecosystem::Rust => &self.manifests.cargo_toml,
+            Ecosystem::Python => &self.manifests.pyproject_toml,
+            Ecosystem::Go => &self.manifests.go_mod,
+            Ecosystem::Deno => &self.manifests.jsr_manifest,
+        };
+        path.as_deref()
+    }
 }
 
 pub fn detect_project_root(current_dir: &Path) -> anyhow::Result<PathBuf> {
-    if let Ok(root) = git_root(current_dir) {
-        return Ok(root);
+    if let Some(repository) = GitRepository::discover(current_dir) {
+        return Ok(repository.work_tree);
     }
     Ok(current_dir.to_path_buf())
 }
@@ -48,15 +101,26 @@ pub fn detect_manifests(
         package_json: None,
         moon_mod: None,
         cargo_toml: None,
+        pyproject_toml: None,
+        go_mod: None,
+        jsr_manifest: None,
         package_json_all: Vec::new(),
         moon_mod_all: Vec::new(),
         cargo_toml_all: Vec::new(),
+        pyproject_toml_all: Vec::new(),
+        go_mod_all: Vec::new(),
+        jsr_manifest_all: Vec::new(),
     };
 
     let candidates = [
         root.join("package.json"),
         root.join("moon.mod.json"),
         root.join("Cargo.toml"),
+        root.join("pyproject.toml"),
+        root.join("go.mod"),
+        root.join("jsr.json"),
+        root.join("deno.json"),
+        root.join("deno.jsonc"),
     ];
 
     for candidate in candidates {
@@ -65,6 +129,11 @@ pub fn detect_manifests(
                 Some("package.json") => manifests.package_json_all.push(candidate),
                 Some("moon.mod.json") => manifests.moon_mod_all.push(candidate),
                 Some("Cargo.toml") => manifests.cargo_toml_all.push(candidate),
+                Some("pyproject.toml") => manifests.pyproject_toml_all.push(candidate),
+                Some("go.mod") => manifests.go_mod_all.push(candidate),
+                Some("jsr.json" | "deno.json" | "deno.jsonc") => {
+                    manifests.jsr_manifest_all.push(candidate)
+                }
                 _ => {}
             }
         }
@@ -84,6 +153,13 @@ pub fn detect_manifests(
             "package.json" => manifests.package_json_all.push(entry.path().to_path_buf()),
             "moon.mod.json" => manifests.moon_mod_all.push(entry.path().to_path_buf()),
             "Cargo.toml" => manifests.cargo_toml_all.push(entry.path().to_path_buf()),
+            "pyproject.toml" => manifests
+                .pyproject_toml_all
+                .push(entry.path().to_path_buf()),
+            "go.mod" => manifests.go_mod_all.push(entry.path().to_path_buf()),
+            "jsr.json" | "deno.json" | "deno.jsonc" => {
+                manifests.jsr_manifest_all.push(entry.path().to_path_buf())
+            }
             _ => {}
         }
     }
@@ -91,129 +167,97 @@ pub fn detect_manifests(
     manifests.package_json = choose_closest(current_dir, &manifests.package_json_all);
     manifests.moon_mod = choose_closest(current_dir, &manifests.moon_mod_all);
     manifests.cargo_toml = choose_cargo_manifest(current_dir, &manifests.cargo_toml_all);
+    manifests.pyproject_toml =
+        choose_pyproject_manifest(current_dir, &manifests.pyproject_toml_all);
+    manifests.go_mod = choose_closest(current_dir, &manifests.go_mod_all);
+    manifests.jsr_manifest = choose_jsr_manifest(current_dir, &manifests.jsr_manifest_all);
 
     Ok(manifests)
 }
 
-pub fn detect_ecosystem(manifests: &ManifestPaths) -> Option<Ecosystem> {
-    if manifests.package_json.is_some() {
-        return Some(Ecosystem::Node);
-    }
-    if manifests.moon_mod.is_some() {
-        return Some(Ecosystem::MoonBit);
-    }
-    if manifests.cargo_toml.is_some() {
-        return Some(Ecosystem::Rust);
-    }
-    None
+/// Returns every ecosystem with a chosen manifest, in precedence order:
+/// Node > MoonBit > Rust > Python > Go > Deno. Metadata resolution merges
+/// fields across them and the earliest ecosystem that provides a value wins.
+pub fn detect_ecosystems(manifests: &ManifestPaths) -> Vec<Ecosystem> {
+    [
+        (Ecosystem::Node, &manifests.package_json),
+        (Ecosystem::MoonBit, &manifests.moon_mod),
+        (Ecosystem::Rust, &manifests.cargo_toml),
+        (Ecosystem::Python, &manifests.pyproject_toml),
+        (Ecosystem::Go, &manifests.go_mod),
+        (Ecosystem::Deno, &manifests.jsr_manifest),
+    ]
+    .into_iter()
+    .filter(|(_, path)| path.is_some())
+    .map(|(ecosystem, _)| ecosystem)
+    .collect()
 }
 
 pub fn build_context(current_dir: &Path) -> anyhow::Result<ProjectContext> {
     let root = detect_project_root(current_dir)?;
     let manifests = detect_manifests(&root, current_dir, 3)?;
-    let ecosystem = detect_ecosystem(&manifests);
-    let git = git_context(&root).ok();
+    let ecosystems = detect_ecosystems(&manifests);
+    // A broken `.bdg.toml` is reported by the command that loads it; here it
+    // only means no remote preference.
+    let remote_preference = load_config(current_dir, &root)
+        .map(|config| config.git.remotes)
+        .unwrap_or_default();
+    let git = GitRepository::discover(current_dir)
+        .map(|repository| git_context(&repository, &remote_preference));
     Ok(ProjectContext {
         root,
-        ecosystem,
+        ecosystems,
         manifests,
         git,
     })
 }
 
-fn git_root(current_dir: &Path) -> anyhow::Result<PathBuf> {
-    let output = std::process::Command::new("git")
-        .arg("rev-parse")
-        .arg("--show-toplevel")
-        .current_dir(current_dir)
-        .output()?;
-    if !output.status.success() {
-        anyhow::bail!("git root not found");
-    }
-    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    if text.is_empty() {
-        anyhow::bail!("git root empty");
-    }
-    Ok(PathBuf::from(text))
-}
-
 #[derive(Debug, Clone)]
 pub struct GitContext {
     pub root: PathBuf,
+    /// Name of the remote the repository was read from.
+    pub remote_name: Option<String>,
     pub remote: Option<String>,
+    pub host: Option<String>,
+    pub forge: Option<ForgeKind>,
     pub owner: Option<String>,
     pub repo: Option<String>,
     pub default_branch: Option<String>,
+    /// Branch checked out in the working tree, `None` when `HEAD` is detached.
+    pub branch: Option<String>,
 }
 
-fn git_context(root: &Path) -> anyhow::Result<GitContext> {
-    let remote = git_remote_url(root).ok();
-    let (owner, repo) = infer_owner_repo(&remote);
-    let default_branch = git_default_branch(root).ok();
-    Ok(GitContext {
-        root: root.to_path_buf(),
-        remote,
-        owner,
-        repo,
-        default_branch,
-    })
-}
-
-fn git_remote_url(root: &Path) -> anyhow::Result<String> {
-    let output = std::process::Command::new("git")
-        .arg("remote")
-        .arg("get-url")
-        .arg("origin")
-        .current_dir(root)
-        .output()?;
-    if !output.status.success() {
-        anyhow::bail!("git remote missing");
+impl GitContext {
+    /// The selected remote's repository, classified with the `.bdg.toml`
+    /// `[forge]` settings.
+    pub fn repo_ref(&self, forge: &ForgeConfig) -> Option<RepoRef> {
+        forge.parse_repo_url(self.remote.as_deref()?)
     }
-    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    if text.is_empty() {
-        anyhow::bail!("git remote empty");
-    }
-    Ok(text)
-}
-
-fn git_default_branch(root: &Path) -> anyhow::Result<String> {
-    let output = std::process::Command::new("git")
-        .arg("symbolic-ref")
-        .arg("refs/remotes/origin/HEAD")
-        .current_dir(root)
-        .output()?;
-    if !output.status.success() {
-        anyhow::bail!("default branch not found");
-    }
-    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    let branch = text.split('/').next_back().unwrap_or("").to_string();
-    if branch.is_empty() {
-        anyhow::bail!("default branch empty");
-    }
-    Ok(branch)
 }
 
-fn infer_owner_repo(remote: &Option<String>) -> (Option<String>, Option<String>) {
-    let url = match remote {
-        Some(url) => url,
-        None => return (None, None),
-    };
-    let cleaned = url
-        .trim()
-        .trim_end_matches(".git")
-        .replace("git+", "")
-        .replace("git://", "https://")
-        .replace(':', "/");
-    let parts: Vec<&str> = cleaned.split('/').collect();
-    if parts.len() < 2 {
-        return (None, None);
-    }
-    let repo = parts.last().unwrap_or(&"").to_string();
-    let owner = parts.get(parts.len() - 2).unwrap_or(&"").to_string();
-    if owner.is_empty() || repo.is_empty() {
-        return (None, None);
+fn git_context(repository: &GitRepository, remote_preference: &[String]) -> GitContext {
+    let remotes = repository.remotes();
+    let selected = select_remote(&remotes, remote_preference);
+    let remote = selected.map(|remote| remote.url.clone());
+    let host = remote.as_deref().and_then(repo_url_host);
+    let forge = host.as_deref().and_then(ForgeKind::from_host);
+    // Owner and repository are kept for unrecognised hosts too, read as a
+    // GitHub-style `owner/repo` path.
+    let repo_ref = remote.as_deref().and_then(|remote| {
+        parse_repo_url_with(remote, |_| Some(forge.unwrap_or(ForgeKind::GitHub)))
+    });
+    let default_branch = selected.and_then(|remote| repository.remote_default_branch(&remote.name));
+    GitContext {
+        root: repository.work_tree.clone(),
+        remote_name: selected.map(|remote| remote.name.clone()),
+        remote,
+        host,
+        forge,
+        owner: repo_ref.as_ref().map(|repo| repo.owner.clone()),
+        repo: repo_ref.map(|repo| repo.repo),
+        default_branch,
+        branch: repository.head_branch(),
     }
-    (Some(owner), Some(repo))
 }
 
 fn is_ignored(entry: &walkdir::DirEntry, root: &Path) -> bool {
@@ -225,7 +269,7 @@ fn is_ignored(entry: &walkdir::DirEntry, root: &Path) -> bool {
     };
     if matches!(
         first.as_ref(),
-        ".git" | "target" | "node_modules" | "dist" | "build" | "out" | "vendor"
+        ".git" | "target" | "node_modules" | "dist" | "build" | "out" | "vendor" | ".venv" | ".tox"
     ) {
         return true;
     }
@@ -268,6 +312,31 @@ fn choose_cargo_manifest(current_dir: &Path, paths: &[PathBuf]) -> Option<PathBu
     choose_closest(current_dir, &package_manifests)
 }
 
+fn choose_pyproject_manifest(current_dir: &Path, paths: &[PathBuf]) -> Option<PathBuf> {
+    let package_manifests = paths
+        .iter()
+        .filter(|path| pyproject_has_package(path))
+        .cloned()
+        .collect::<Vec<_>>();
+    choose_closest(current_dir, &package_manifests)
+}
+
+/// Prefers `jsr.json` over `deno.json`/`deno.jsonc` in the same directory, since
+/// JSR reads it first, and skips Deno configs that do not declare a package name.
+fn choose_jsr_manifest(current_dir: &Path, paths: &[PathBuf]) -> Option<PathBuf> {
+    let package_manifests = paths
+        .iter()
+        .filter(|path| jsr_manifest_has_package(path))
+        .cloned()
+        .collect::<Vec<_>>();
+    let closest = choose_closest(current_dir, &package_manifests)?;
+    let dir = closest.parent()?;
+    ["jsr.json", "deno.json", "deno.jsonc"]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| package_manifests.contains(path))
+}
+
 fn path_distance(from: &Path, to_file: &Path) -> usize {
     let to_dir = to_file.parent().unwrap_or_else(|| Path::new(""));
     let from_components: Vec<_> = from.components().collect();
@@ -0,0 +1,336 @@
+use crate::config::CoverageConfig;
+use crate::workflows::{detect_gitlab_ci, is_action, referenced_actions, shell_commands};
+use serde::Deserialize;
+use std::path::Path;
+
+/// Hosted services that display a repository's test coverage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoverageProvider {
+    Codecov,
+    Coveralls,
+    Codacy,
+    SonarCloud,
+}
+
+impl CoverageProvider {
+    pub const ALL: [Self; 4] = [
+        Self::Codecov,
+        Self::Coveralls,
+        Self::Codacy,
+        Self::SonarCloud,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Codecov => "codecov",
+            Self::Coveralls => "coveralls",
+            Self::Codacy => "codacy",
+            Self::SonarCloud => "sonarcloud",
+        }
+    }
+
+    /// Configuration files, in lookup order.
+    const fn files(self) -> &'static [&'static str] {
+        match self {
+            Self::Codecov => &[
+                "codecov.yml",
+                ".codecov.yml",
+                "codecov.yaml",
+                ".codecov.yaml",
+                ".github/codecov.yml",
+                ".github/.codecov.yml",
+            ],
+            Self::Coveralls => &[".coveralls.yml"],
+            Self::Codacy => &[".codacy.yml", ".codacy.yaml"],
+            Self::SonarCloud => &["sonar-project.properties", ".sonarcloud.properties"],
+        }
+    }
+
+    /// Actions whose `uses:` reference uploads to the service.
+    const fn actions(self) -> &'static [&'static str] {
+        match self {
+            Self::Codecov => &["codecov/codecov-action"],
+            Self::Coveralls => &["coverallsapp/github-action"],
+            Self::Codacy => &["codacy/codacy-coverage-reporter-action"],
+            Self::SonarCloud => &[
+                "sonarsource/sonarcloud-github-action",
+                "sonarsource/sonarqube-scan-action",
+            ],
+        }
+    }
+
+    /// Uploader executables, matched as the command of a shell line.
+    const fn commands(self) -> &'static [&'static str] {
+        match self {
+            Self::Codecov => &["codecov", "codecovcli"],
+            Self::Coveralls => &["coveralls"],
+            Self::Codacy => &["codacy-coverage-reporter"],
+            Self::SonarCloud => &["sonar-scanner"],
+        }
+    }
+
+    /// Uploader scripts, flags and build goals, matched anywhere on a line.
+    const fn markers(self) -> &'static [&'static str] {
+        match self {
+            Self::Codecov => &["codecov.io/bash", "uploader.codecov.io", "cli.codecov.io"],
+            Self::Coveralls => &["--coveralls"],
+            Self::Codacy => &["coverage.codacy.com"],
+            Self::SonarCloud => &["sonar:sonar"],
+        }
+    }
+}
+
+/// A detected provider and the file that revealed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageSource {
+    pub provider: CoverageProvider,
+    pub file: String,
+}
+
+/// Providers the repository reports coverage to, found from their
+/// configuration files, then from the actions and uploaders run by GitHub,
+/// Forgejo, or GitLab CI.
+pub fn detect_coverage_providers(root: &Path) -> Vec<CoverageSource> {
+    let ci_files = ci_files(root);
+    let sonar_host = sonar_project(root).and_then(|project| project.host_url);
+    CoverageProvider::ALL
+        .into_iter()
+        .filter(|provider| {
+            // A server other than SonarCloud is a self-hosted SonarQube.
+            *provider != CoverageProvider::SonarCloud
+                || sonar_host
+                    .as_deref()
+                    .is_none_or(|host| host.contains("sonarcloud.io"))
+        })
+        .filter_map(|provider| {
+            let file = provider
+                .files()
+                .iter()
+                .find(|file| root.join(file).is_file())
+                .map(|file| file.to_string())
+                .or_else(|| {
+                    ci_files
+                        .iter()
+                        .find(|(_, content)| reports_to(provider, content))
+                        .map(|(file, _)| file.clone())
+                })?;
+            Some(CoverageSource { provider, file })
+        })
+        .collect()
+}
+
+/// Providers to badge: the configured `provider`, else every detected one.
+pub fn select_coverage_providers(
+    detected: &[CoverageSource],
+    config: &CoverageConfig,
+) -> Vec<CoverageProvider> {
+    match config.provider {
+        Some(provider) => vec![provider],
+        None => detected.iter().map(|source| source.provider).collect(),
+    }
+}
+
+/// Codecov server from the `codecov.url` setting of the Codecov configuration,
+/// which self-hosted installations set.
+pub fn codecov_url(root: &Path) -> Option<String> {
+    let file = CoverageProvider::Codecov
+        .files()
+        .iter()
+        .find(|file| root.join(file).is_file())?;
+    let content = std::fs::read_to_string(root.join(file)).ok()?;
+    let value: serde_yaml_ng::Value = serde_yaml_ng::from_str(&content).ok()?;
+    value
+        .get("codecov")?
+        .get("url")?
+        .as_str()
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+}
+
+/// Settings read from `sonar-project.properties`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SonarProject {
+    pub project_key: Option<String>,
+    pub host_url: Option<String>,
+}
+
+pub fn sonar_project(root: &Path) -> Option<SonarProject> {
+    let content = CoverageProvider::SonarCloud
+        .files()
+        .iter()
+        .find_map(|file| std::fs::read_to_string(root.join(file)).ok())?;
+    let mut project = SonarProject::default();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(['=', ':']) else {
+            continue;
+        };
+        let value = Some(value.trim().to_string()).filter(|value| !value.is_empty());
+        match key.trim() {
+            "sonar.projectKey" => project.project_key = value,
+            "sonar.host.url" => project.host_url = value,
+            _ => {}
+        }
+    }
+    Some(project)
+}
+
+/// Actions workflows and the GitLab pipeline, as `(path, content)`.
+fn ci_files(root: &Path) -> Vec<(String, String)> {
+    let mut files = Vec::new();
+    for dir in [
+        ".github/workflows",
+        ".forgejo/workflows",
+        ".gitea/workflows",
+    ] {
+        let Ok(entries) = std::fs::read_dir(root.join(dir)) else {
+            continue;
+        };
+        let mut names = entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| name.ends_with(".yml") || name.ends_with(".yaml"))
+            .collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            let file = format!("{dir}/{name}");
+            if let Ok(content) = std::fs::read_to_string(root.join(&file)) {
+                files.push((file, content));
+            }
+        }
+    }
+    if let Some(ci) = detect_gitlab_ci(root)
+        && let Ok(content) = std::fs::read_to_string(root.join(&ci.file))
+    {
+        files.push((ci.file, content));
+    }
+    files
+}
+
+fn reports_to(provider: CoverageProvider, content: &str) -> bool {
+    referenced_actions(content).iter().any(|action| {
+        provider
+            .actions()
+            .iter()
+            .any(|known| is_action(action, known))
+    }) || content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .any(|line| {
+            provider
+                .markers()
+                .iter()
+                .any(|marker| line.contains(marker))
+        })
+        || shell_commands(content)
+            .iter()
+            .any(|words| provider.commands().contains(&words[0].as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CoverageProvider, CoverageSource, codecov_url, detect_coverage_providers, sonar_project,
+    };
+    use std::fs;
+
+    fn providers(root: &std::path::Path) -> Vec<(CoverageProvider, String)> {
+        detect_coverage_providers(root)
+            .into_iter()
+            .map(|CoverageSource { provider, file }| (provider, file))
+            .collect()
+    }
+
+    #[test]
+    fn detects_providers_from_config_files_actions_and_uploaders() {
+        let temp = tempfile::tempdir().unwrap();
+        let workflows = temp.path().join(".github/workflows");
+        fs::create_dir_all(&workflows).unwrap();
+        fs::write(
+            workflows.join("ci.yml"),
+            "jobs:\n  test:\n    steps:\n      - name: Upload to codecov later\n      - uses: coverallsapp/github-action@v2\n      - run: bash <(curl -Ls https://coverage.codacy.com/get.sh) report\n",
+        )
+        .unwrap();
+        assert_eq!(
+            providers(temp.path()),
+            vec![
+                (
+                    CoverageProvider::Coveralls,
+                    ".github/workflows/ci.yml".to_string()
+                ),
+                (
+                    CoverageProvider::Codacy,
+                    ".github/workflows/ci.yml".to_string()
+                ),
+            ]
+        );
+
+        fs::write(
+            temp.path().join(".gitlab-ci.yml"),
+            "test:\n  script:\n    - cargo test\n    - ./codecov -t \"$TOKEN\"\n",
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("sonar-project.properties"),
+            "sonar.organization=acme\nsonar.projectKey=acme_app\n",
+        )
+        .unwrap();
+        assert_eq!(
+            providers(temp.path()),
+            vec![
+                (CoverageProvider::Codecov, ".gitlab-ci.yml".to_string()),
+                (
+                    CoverageProvider::Coveralls,
+                    ".github/workflows/ci.yml".to_string()
+                ),
+                (
+                    CoverageProvider::Codacy,
+                    ".github/workflows/ci.yml".to_string()
+                ),
+                (
+                    CoverageProvider::SonarCloud,
+                    "sonar-project.properties".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            sonar_project(temp.path()).unwrap().project_key.as_deref(),
+            Some("acme_app")
+        );
+
+        // A SonarQube server is not SonarCloud.
+        fs::write(
+            temp.path().join("sonar-project.properties"),
+            "sonar.host.url=https://sonar.corp.example\n",
+        )
+        .unwrap();
+        assert!(
+            !providers(temp.path())
+                .iter()
+                .any(|(provider, _)| *provider == CoverageProvider::SonarCloud)
+        );
+    }
+
+    #[test]
+    fn reads_self_hosted_codecov_url() {
+        let temp = tempfile::tempdir().unwrap();
+        assert_eq!(codecov_url(temp.path()), None);
+        fs::write(
+            temp.path().join(".codecov.yml"),
+            "codecov:\n  url: https://codecov.corp.example/\ncoverage:\n  precision: 2\n",
+        )
+        .unwrap();
+        assert_eq!(
+            codecov_url(temp.path()).as_deref(),
+            Some("https://codecov.corp.example")
+        );
+        assert_eq!(
+            providers(temp.path()),
+            vec![(CoverageProvider::Codecov, ".codecov.yml".to_string())]
+        );
+    }
+}
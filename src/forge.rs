@@ -0,0 +1,251 @@
+use serde::Deserialize;
+
+/// Code-hosting service that repository badges (license, release, CI) point at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    GitHub,
+    GitLab,
+    /// Forgejo and Gitea, including Codeberg.
+    #[serde(alias = "gitea", alias = "codeberg")]
+    Forgejo,
+}
+
+impl ForgeKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::GitHub => "github",
+            Self::GitLab => "gitlab",
+            Self::Forgejo => "forgejo",
+        }
+    }
+
+    /// Forge served from `host`. `gitlab.com` and hosts named `gitlab.*` are
+    /// GitLab, `codeberg.org` and hosts named `forgejo.*` or `gitea.*` are
+    /// Forgejo; unrecognised hosts return `None`.
+    pub fn from_host(host: &str) -> Option<Self> {
+        let host = host.to_ascii_lowercase();
+        match host.as_str() {
+            "github.com" | "www.github.com" => Some(Self::GitHub),
+            "gitlab.com" | "www.gitlab.com" => Some(Self::GitLab),
+            "codeberg.org" => Some(Self::Forgejo),
+            _ if host.starts_with("gitlab.") => Some(Self::GitLab),
+            _ if host.starts_with("forgejo.") || host.starts_with("gitea.") => Some(Self::Forgejo),
+            _ => None,
+        }
+    }
+}
+
+/// A repository on a forge. On GitLab `owner` is the full namespace, which may
+/// contain nested groups such as `group/subgroup`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoRef {
+    pub kind: ForgeKind,
+    pub host: String,
+    /// Web root of the forge, `https://<host>` unless configured otherwise.
+    pub base_url: String,
+    pub owner: String,
+    pub repo: String,
+    /// shields.io-compatible instance configured for this forge, e.g. one that
+    /// can read a GitHub Enterprise API. `None` means public shields.io.
+    pub shields_url: Option<String>,
+    /// Whether a `[forge.hosts]` entry names this host. Only such hosts, and
+    /// github.com, are sent the GitHub API token.
+    pub host_configured: bool,
+}
+
+impl RepoRef {
+    /// `owner/repo`, the project path used in forge URLs.
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.owner, self.repo)
+    }
+
+    pub fn web_url(&self) -> String {
+        format!("{}/{}", self.base_url, self.path())
+    }
+
+    /// Whether the repository lives on the forge's public SaaS host.
+    pub fn is_public_host(&self) -> bool {
+        matches!(
+            self.host.as_str(),
+            "github.com" | "www.github.com" | "gitlab.com" | "www.gitlab.com" | "codeberg.org"
+        )
+    }
+
+    /// Replaces the web root, for self-hosted forges served under a different
+    /// host or a sub-path.
+    pub fn with_base_url(mut self, url: Option<&str>) -> Self {
+        if let Some(url) = url {
+            self.base_url = url.trim_end_matches('/').to_string();
+        }
+        self
+    }
+
+    pub fn with_shields_url(mut self, url: Option<&str>) -> Self {
+        if let Some(url) = url {
+            self.shields_url = Some(url.trim_end_matches('/').to_string());
+        }
+        self
+    }
+
+    /// Root of the shields instance used for this repository's badges.
+    pub fn shields(&self) -> &str {
+        self.shields_url
+            .as_deref()
+            .unwrap_or("https://img.shields.io")
+    }
+
+    /// Whether shields can read this repository. Public shields.io only knows
+    /// github.com, while GitLab and Forgejo endpoints take the instance URL as a
+    /// query parameter.
+    pub fn shields_reachable(&self) -> bool {
+        self.shields_url.is_some() || self.kind != ForgeKind::GitHub || self.is_public_host()
+    }
+}
+
+/// Parses a git remote or manifest repository URL: `https://`, `git+https://`,
+/// `git://`, `ssh://` and scp-like `git@host:owner/repo.git` forms, plus the npm
+/// shorthands `owner/repo`, `github:owner/repo` and `gitlab:group/repo`.
+pub fn parse_repo_url(url: &str) -> Option<RepoRef> {
+    parse_repo_url_with(url, ForgeKind::from_host)
+}
+
+/// Like [`parse_repo_url`], with `kind_for_host` deciding which forge serves a
+/// host; repositories on hosts it returns `None` for are rejected.
+pub fn parse_repo_url_with(
+    url: &str,
+    kind_for_host: impl Fn(&str) -> Option<ForgeKind>,
+) -> Option<RepoRef> {
+    let (host, path) = split_repo_url(url)?;
+    let kind = kind_for_host(&host)?;
+    let path = path.split(['?', '#']).next().unwrap_or("");
+    let mut segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .take_while(|segment| *segment != "-")
+        .collect::<Vec<_>>();
+    if kind != ForgeKind::GitLab {
+        segments.truncate(2);
+    }
+    let repo = segments.pop()?;
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    if segments.is_empty() || repo.is_empty() {
+        return None;
+    }
+    Some(RepoRef {
+        kind,
+        base_url: format!("https://{host}"),
+        host,
+        owner: segments.join("/"),
+        repo: repo.to_string(),
+        shields_url: None,
+        host_configured: false,
+    })
+}
+
+/// Lower-cased host of a repository URL, whether or not it is a known forge.
+pub fn repo_url_host(url: &str) -> Option<String> {
+    split_repo_url(url).map(|(host, _)| host)
+}
+
+fn split_repo_url(url: &str) -> Option<(String, &str)> {
+    let url = url.trim();
+    let url = url.strip_prefix("git+").unwrap_or(url);
+    let (host, path) = if let Some(path) = url.strip_prefix("github:") {
+        ("github.com", path)
+    } else if let Some(path) = url.strip_prefix("gitlab:") {
+        ("gitlab.com", path)
+    } else if let Some((_, rest)) = url.split_once("://") {
+        let (authority, path) = rest.split_once('/')?;
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        (host.split(':').next().unwrap_or(host), path)
+    } else if let Some((authority, path)) = url.split_once(':') {
+        (authority.rsplit('@').next().unwrap_or(authority), path)
+    } else if url.split('/').count() == 2 {
+        ("github.com", url)
+    } else {
+        return None;
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some((host.to_ascii_lowercase(), path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ForgeKind, parse_repo_url, parse_repo_url_with, repo_url_host};
+
+    #[test]
+    fn parses_github_remote_forms() {
+        for url in [
+            "https://github.com/f4ah6o/bdg-rs",
+            "https://github.com/f4ah6o/bdg-rs.git",
+            "git+https://github.com/f4ah6o/bdg-rs.git",
+            "git@github.com:f4ah6o/bdg-rs.git",
+            "ssh://git@github.com:22/f4ah6o/bdg-rs.git",
+            "https://github.com/f4ah6o/bdg-rs/tree/main",
+            "github:f4ah6o/bdg-rs",
+            "f4ah6o/bdg-rs",
+        ] {
+            let repo = parse_repo_url(url).unwrap_or_else(|| panic!("{url}"));
+            assert_eq!(repo.kind, ForgeKind::GitHub, "{url}");
+            assert_eq!(repo.host, "github.com", "{url}");
+            assert_eq!(repo.path(), "f4ah6o/bdg-rs", "{url}");
+        }
+    }
+
+    #[test]
+    fn parses_gitlab_nested_groups_and_self_hosted_hosts() {
+        let repo = parse_repo_url("git@gitlab.com:group/sub/project.git").expect("repo");
+        assert_eq!(repo.kind, ForgeKind::GitLab);
+        assert_eq!(repo.owner, "group/sub");
+        assert_eq!(repo.repo, "project");
+        assert!(repo.is_public_host());
+
+        let repo = parse_repo_url("https://gitlab.example.com/team/app/-/tree/main").expect("repo");
+        assert_eq!(repo.host, "gitlab.example.com");
+        assert_eq!(repo.path(), "team/app");
+        assert!(!repo.is_public_host());
+    }
+
+    #[test]
+    fn rejects_unknown_hosts_and_local_paths() {
+        assert_eq!(parse_repo_url("https://example.com/o/r"), None);
+        assert_eq!(parse_repo_url("/srv/git/project.git"), None);
+        assert_eq!(parse_repo_url("https://github.com/only-owner"), None);
+    }
+
+    #[test]
+    fn recognises_forgejo_hosts_and_configured_self_hosted_forges() {
+        let repo = parse_repo_url("git@codeberg.org:forgejo/forgejo.git").expect("repo");
+        assert_eq!(repo.kind, ForgeKind::Forgejo);
+        assert_eq!(repo.web_url(), "https://codeberg.org/forgejo/forgejo");
+
+        let repo = parse_repo_url_with("ssh://git@git.example.com:2222/team/app.git", |_| {
+            Some(ForgeKind::Forgejo)
+        })
+        .expect("repo")
+        .with_base_url(Some("https://git.example.com/forge/"));
+        assert_eq!(repo.kind, ForgeKind::Forgejo);
+        assert_eq!(repo.web_url(), "https://git.example.com/forge/team/app");
+    }
+
+    #[test]
+    fn keeps_enterprise_hosts() {
+        assert_eq!(
+            repo_url_host("git@GHE.corp.example:team/app.git").as_deref(),
+            Some("ghe.corp.example")
+        );
+        let repo = parse_repo_url_with("git@ghe.corp.example:team/app.git", |_| {
+            Some(ForgeKind::GitHub)
+        })
+        .expect("repo");
+        assert_eq!(repo.web_url(), "https://ghe.corp.example/team/app");
+        assert!(!repo.shields_reachable());
+        assert!(
+            repo.with_shields_url(Some("https://shields.corp.example/"))
+                .shields_reachable()
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use std::path::{Path, PathBuf};
+
+/// A repository located by reading `.git` directly, so no `git` binary is
+/// needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRepository {
+    pub work_tree: PathBuf,
+    /// Per-worktree directory holding `HEAD`.
+    pub git_dir: PathBuf,
+    /// Directory shared by all worktrees, holding `config` and `refs/`.
+    pub common_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRemote {
+    pub name: String,
+    pub url: String,
+}
+
+impl GitRepository {
+    /// Finds the innermost repository enclosing `start`. A `.git` file holding
+    /// `gitdir: <path>` (linked worktrees and submodules) is followed, and a
+    /// `commondir` file in the resolved directory points at the shared one.
+    pub fn discover(start: &Path) -> Option<Self> {
+        for dir in start.ancestors() {
+            let dot_git = dir.join(".git");
+            let git_dir = if dot_git.is_dir() {
+                dot_git
+            } else if dot_git.is_file() {
+                let content = std::fs::read_to_string(&dot_git).ok()?;
+                let target = content
+                    .lines()
+                    .find_map(|line| line.strip_prefix("gitdir:"))?
+                    .trim();
+                dir.join(target)
+            } else {
+                continue;
+            };
+            if !git_dir.join("HEAD").is_file() {
+                continue;
+            }
+            let common_dir = std::fs::read_to_string(git_dir.join("commondir"))
+                .ok()
+                .map(|common| git_dir.join(common.trim()))
+                .unwrap_or_else(|| git_dir.clone());
+            return Some(Self {
+                work_tree: dir.to_path_buf(),
+                git_dir,
+                common_dir,
+            });
+        }
+        None
+    }
+
+    /// Remotes declared in the repository config, in file order, with
+    /// `url.<base>.insteadOf` rewrites applied.
+    pub fn remotes(&self) -> Vec<GitRemote> {
+        let Ok(content) = std::fs::read_to_string(self.common_dir.join("config")) else {
+            return Vec::new();
+        };
+        parse_remotes(&content)
+    }
+
+    /// Branch checked out in this worktree, `None` when `HEAD` is detached.
+    pub fn head_branch(&self) -> Option<String> {
+        let head = std::fs::read_to_string(self.git_dir.join("HEAD")).ok()?;
+        head.trim()
+            .strip_prefix("ref: refs/heads/")
+            .map(str::to_string)
+    }
+
+    /// Whether `branch` exists locally or as a remote-tracking branch, loose or
+    /// packed.
+    pub fn has_branch(&self, branch: &str) -> bool {
+        let refs = self.common_dir.join("refs");
+        if refs.join("heads").join(branch).is_file() {
+            return true;
+        }
+        let tracked = std::fs::read_dir(refs.join("remotes"))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(|remote| remote.path().join(branch).is_file());
+        if tracked {
+            return true;
+        }
+        let Ok(packed) = std::fs::read_to_string(self.common_dir.join("packed-refs")) else {
+            return false;
+        };
+        packed
+            .lines()
+            .filter_map(|line| line.split_once(' ').map(|(_, name)| name))
+            .any(|name| {
+                name.strip_prefix("refs/heads/") == Some(branch)
+                    || name
+                        .strip_prefix("refs/remotes/")
+                        .and_then(|rest| rest.split_once('/'))
+                        .is_some_and(|(_, name)| name == branch)
+            })
+    }
+
+    /// Default branch of `remote`, read from `refs/remotes/<remote>/HEAD`.
+    pub fn remote_default_branch(&self, remote: &str) -> Option<String> {
+        let path = self
+            .common_dir
+            .join("refs")
+            .join("remotes")
+            .join(remote)
+            .join("HEAD");
+        let head = std::fs::read_to_string(path).ok()?;
+        let prefix = format!("ref: refs/remotes/{remote}/");
+        head.trim()
+            .strip_prefix(&prefix)
+            .filter(|branch| !branch.is_empty())
+            .map(str::to_string)
+    }
+}
+
+/// The first remote named in `preference`, else `origin`, else the first
+/// declared remote.
+pub fn select_remote<'a>(remotes: &'a [GitRemote], preference: &[String]) -> Option<&'a GitRemote> {
+    preference
+        .iter()
+        .map(String::as_str)
+        .chain(["origin"])
+        .find_map(|name| remotes.iter().find(|remote| remote.name == name))
+        .or_else(|| remotes.first())
+}
+
+fn parse_remotes(content: &str) -> Vec<GitRemote> {
+    let mut remotes: Vec<GitRemote> = Vec::new();
+    let mut rewrites: Vec<(String, String)> = Vec::new();
+    let mut section = Section::Other;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[') {
+            section = parse_section(header.split(']').next().unwrap_or(""));
+            continue;
+        }
+        let (key, value) = line.split_once('=').unwrap_or((line, ""));
+        let key = key.trim().to_ascii_lowercase();
+        let value = unquote(value.trim());
+        match (&section, key.as_str()) {
+            (Section::Remote(name), "url")
+                if !remotes.iter().any(|remote| &remote.name == name) =>
+            {
+                remotes.push(GitRemote {
+                    name: name.clone(),
+                    url: value,
+                });
+            }
+            (Section::Url(base), "insteadof") => rewrites.push((value, base.clone())),
+            _ => {}
+        }
+    }
+    for remote in &mut remotes {
+        if let Some((prefix, base)) = rewrites
+            .iter()
+            .filter(|(prefix, _)| remote.url.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+        {
+            remote.url = format!("{}{}", base, &remote.url[prefix.len()..]);
+        }
+    }
+    remotes
+}
+
+enum Section {
+    Remote(String),
+    Url(String),
+    Other,
+}
+
+/// `remote "origin"` and the legacy `remote.origin` spelling.
+fn parse_section(header: &str) -> Section {
+    let header = header.trim();
+    let (name, subsection) = match header.split_once(char::is_whitespace) {
+        Some((name, rest)) => (name, unquote(rest.trim())),
+        None => match header.split_once('.') {
+            Some((name, rest)) => (name, rest.to_string()),
+            None => (header, String::new()),
+        },
+    };
+    match name.to_ascii_lowercase().as_str() {
+        "remote" if !subsection.is_empty() => Section::Remote(subsection),
+        "url" if !subsection.is_empty() => Section::Url(subsection),
+        _ => Section::Other,
+    }
+}
+
+fn unquote(value: &str) -> String {
+    let value = value
+        .split_once(" #")
+        .or_else(|| value.split_once(" ;"))
+        .map_or(value, |(value, _)| value)
+        .trim();
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+        .replace("\\\"", "\"")
+        .replace("\\\\", "\\")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GitRemote, GitRepository, select_remote};
+    use std::fs;
+
+    fn write(path: &std::path::Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn reads_remotes_heads_and_remote_default_branch() {
+        let temp = tempfile::tempdir().unwrap();
+        let git = temp.path().join(".git");
+        write(&git.join("HEAD"), "ref: refs/heads/feature/x\n");
+        write(
+            &git.join("config"),
+            r#"[core]
+	bare = false
+[remote "origin"]
+	url = git@github.com:me/fork.git
+	fetch = +refs/heads/*:refs/remotes/origin/*
+[remote "upstream"]
+	url = "gh:owner/app.git" ; canonical
+[url "https://github.com/"]
+	insteadOf = gh:
+"#,
+        );
+        write(
+            &git.join("refs/remotes/upstream/HEAD"),
+            "ref: refs/remotes/upstream/release/2\n",
+        );
+
+        let nested = temp.path().join("crates/app");
+        fs::create_dir_all(&nested).unwrap();
+        let repo = GitRepository::discover(&nested).expect("repository");
+        assert_eq!(repo.work_tree, temp.path());
+        assert_eq!(repo.head_branch().as_deref(), Some("feature/x"));
+        assert_eq!(
+            repo.remote_default_branch("upstream").as_deref(),
+            Some("release/2")
+        );
+        assert_eq!(repo.remote_default_branch("origin"), None);
+
+        let remotes = repo.remotes();
+        assert_eq!(
+            remotes,
+            vec![
+                GitRemote {
+                    name: "origin".to_string(),
+                    url: "git@github.com:me/fork.git".to_string(),
+                },
+                GitRemote {
+                    name: "upstream".to_string(),
+                    url: "https://github.com/owner/app.git".to_string(),
+                },
+            ]
+        );
+        let preference = ["upstream".to_string(), "origin".to_string()];
+        assert_eq!(
+            select_remote(&remotes, &preference).map(|remote| remote.name.as_str()),
+            Some("upstream")
+        );
+        assert_eq!(
+            select_remote(&remotes, &[]).map(|remote| remote.name.as_str()),
+            Some("origin")
+        );
+        assert_eq!(
+            select_remote(&remotes[1..], &[]).map(|remote| remote.name.as_str()),
+            Some("upstream")
+        );
+    }
+
+    #[test]
+    fn follows_gitdir_files_for_worktrees_and_submodules() {
+        let temp = tempfile::tempdir().unwrap();
+        let main_git = temp.path().join("main/.git");
+        write(&main_git.join("HEAD"), "ref: refs/heads/main\n");
+        write(
+            &main_git.join("config"),
+            "[remote \"origin\"]\n\turl = https://gitlab.com/group/app.git\n",
+        );
+
+        let worktree_git = main_git.join("worktrees/wt");
+        write(&worktree_git.join("HEAD"), "ref: refs/heads/topic\n");
+        write(&worktree_git.join("commondir"), "../..\n");
+        write(
+            &temp.path().join("wt/.git"),
+            &format!("gitdir: {}\n", worktree_git.display()),
+        );
+        let worktree = GitRepository::discover(&temp.path().join("wt")).expect("worktree");
+        assert_eq!(worktree.head_branch().as_deref(), Some("topic"));
+        assert_eq!(
+            worktree.remotes()[0].url,
+            "https://gitlab.com/group/app.git"
+        );
+
+        let module_git = main_git.join("modules/sub");
+        write(&module_git.join("HEAD"), "0123456789abcdef\n");
+        write(
+            &module_git.join("config"),
+            "[remote \"origin\"]\n\turl = https://codeberg.org/team/sub.git\n",
+        );
+        write(
+            &temp.path().join("main/sub/.git"),
+            "gitdir: ../.git/modules/sub\n",
+        );
+        let submodule = GitRepository::discover(&temp.path().join("main/sub")).expect("submodule");
+        assert_eq!(submodule.work_tree, temp.path().join("main/sub"));
+        assert_eq!(submodule.head_branch(), None);
+        assert_eq!(
+            submodule.remotes()[0].url,
+            "https://codeberg.org/team/sub.git"
+        );
+    }
+}
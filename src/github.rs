@@ -0,0 +1,338 @@
+use crate::badges::encode_query_value;
+use crate::forge::{ForgeKind, RepoRef};
+use crate::providers::USER_AGENT;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Overrides the REST API root, e.g. to point at a mock server in tests.
+pub const API_URL_ENV: &str = "BDG_GITHUB_API_URL";
+
+/// Minimal GitHub REST client. Authenticates with `GITHUB_TOKEN` or
+/// `GH_TOKEN` when set; public repositories work without a token within the
+/// anonymous rate limit.
+pub struct GitHubClient {
+    agent: ureq::Agent,
+    api_url: String,
+    token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitHubError {
+    /// 401: the token is missing where required, invalid, or expired.
+    Unauthorized,
+    /// 403 without an exhausted rate limit: the token lacks access.
+    Forbidden,
+    /// 403 or 429 with the rate limit exhausted. `reset` is the epoch second
+    /// the limit resets at, when GitHub says.
+    RateLimited {
+        reset: Option<u64>,
+    },
+    /// 404: the repository or workflow does not exist, or is private and not
+    /// visible to the token.
+    NotFound,
+    Http(u16),
+    Network(String),
+    InvalidResponse(String),
+}
+
+impl GitHubError {
+    /// Stable identifier reported in `list --json`.
+    pub const fn reason(&self) -> &'static str {
+        match self {
+            Self::Unauthorized => "unauthorized",
+            Self::Forbidden => "forbidden",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::NotFound => "not_found",
+            Self::Http(_) => "http_error",
+            Self::Network(_) => "network_error",
+            Self::InvalidResponse(_) => "invalid_response",
+        }
+    }
+}
+
+impl std::fmt::Display for GitHubError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unauthorized => write!(f, "GitHub rejected the credentials"),
+            Self::Forbidden => write!(f, "GitHub denied access"),
+            Self::RateLimited { reset: Some(reset) } => {
+                write!(f, "GitHub rate limit exceeded until {reset}")
+            }
+            Self::RateLimited { reset: None } => write!(f, "GitHub rate limit exceeded"),
+            Self::NotFound => write!(f, "not found on GitHub"),
+            Self::Http(status) => write!(f, "GitHub returned HTTP {status}"),
+            Self::Network(message) => write!(f, "GitHub request failed: {message}"),
+            Self::InvalidResponse(message) => write!(f, "unexpected GitHub response: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for GitHubError {}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowRun {
+    pub id: u64,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    pub html_url: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkflowRunsPage {
+    workflow_runs: Vec<WorkflowRun>,
+}
+
+impl GitHubClient {
+    pub fn new(api_url: &str, token: Option<String>) -> Self {
+        let config = ureq::Agent::config_builder()
+            .timeout_connect(Some(Duration::from_secs(3)))
+            .timeout_global(Some(Duration::from_secs(8)))
+            .http_status_as_error(false)
+            .build();
+        Self {
+            agent: ureq::Agent::new_with_config(config),
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token: token.filter(|token| !token.is_empty()),
+        }
+    }
+
+    /// Client for `repo`'s host: `api.github.com`, or `<base>/api/v3` on
+    /// GitHub Enterprise Server. `BDG_GITHUB_API_URL` overrides both. `None`
+    /// when the repository is not on GitHub. `GITHUB_TOKEN`/`GH_TOKEN` is only
+    /// sent to github.com and to hosts with their own `[forge.hosts]` entry.
+    pub fn for_repo(repo: &RepoRef) -> Option<Self> {
+        if repo.kind != ForgeKind::GitHub {
+            return None;
+        }
+        let api_url = std::env::var(API_URL_ENV)
+            .ok()
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| {
+                if repo.is_public_host() {
+                    "https://api.github.com".to_string()
+                } else {
+                    format!("{}/api/v3", repo.base_url)
+                }
+            });
+        let token = (repo.is_public_host() || repo.host_configured)
+            .then(|| {
+                std::env::var("GITHUB_TOKEN")
+                    .ok()
+                    .filter(|token| !token.is_empty())
+                    .or_else(|| std::env::var("GH_TOKEN").ok())
+            })
+            .flatten();
+        Some(Self::new(&api_url, token))
+    }
+
+    /// Most recent run of `workflow_file`, optionally limited to `branch`.
+    /// `Ok(None)` when the workflow has never run.
+    pub fn latest_workflow_run(
+        &self,
+        repo: &RepoRef,
+        workflow_file: &str,
+        branch: Option<&str>,
+    ) -> Result<Option<WorkflowRun>, GitHubError> {
+        let mut path = format!(
+            "/repos/{}/{}/actions/workflows/{}/runs?per_page=1",
+            repo.owner, repo.repo, workflow_file
+        );
+        if let Some(branch) = branch {
+            path.push_str(&format!("&branch={}", encode_query_value(branch)));
+        }
+        let page: WorkflowRunsPage = self.get_json(&path)?;
+        Ok(page.workflow_runs.into_iter().next())
+    }
+
+    fn get_json<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, GitHubError> {
+        let mut request = self
+            .agent
+            .get(&format!("{}{}", self.api_url, path))
+            .header("User-Agent", USER_AGENT)
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(token) = &self.token {
+            request = request.header("Authorization", &format!("Bearer {token}"));
+        }
+        let mut response = request
+            .call()
+            .map_err(|error| GitHubError::Network(error.to_string()))?;
+        let status = response.status().as_u16();
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        // Primary limits exhaust `x-ratelimit-remaining`; secondary limits
+        // send `retry-after` instead.
+        let limited = header("x-ratelimit-remaining").as_deref() == Some("0")
+            || header("retry-after").is_some();
+        let reset = header("x-ratelimit-reset").and_then(|reset| reset.parse().ok());
+        match status {
+            200..=299 => {}
+            401 => return Err(GitHubError::Unauthorized),
+            429 => return Err(GitHubError::RateLimited { reset }),
+            403 if limited => return Err(GitHubError::RateLimited { reset }),
+            403 => return Err(GitHubError::Forbidden),
+            404 => return Err(GitHubError::NotFound),
+            _ => return Err(GitHubError::Http(status)),
+        }
+        let text = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|error| GitHubError::Network(error.to_string()))?;
+        serde_json::from_str(&text).map_err(|error| GitHubError::InvalidResponse(error.to_string()))
+    }
+}
+
+/// Latest run of a workflow as reported in `list`. `reason` explains a
+/// missing run: `no_repository` outside GitHub, `no_runs`, or a
+/// [`GitHubError::reason`].
+#[derive(Debug, Clone, Default)]
+pub struct RunStatus {
+    pub ok: bool,
+    pub reason: Option<String>,
+    /// Human-readable detail for `reason`.
+    pub message: Option<String>,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    pub run_id: Option<u64>,
+    pub html_url: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl RunStatus {
+    fn failed(reason: &str, message: impl Into<String>) -> Self {
+        Self {
+            reason: Some(reason.to_string()),
+            message: Some(message.into()),
+            ..Self::default()
+        }
+    }
+}
+
+pub fn latest_run_status(
+    repo: Option<&RepoRef>,
+    workflow_file: &str,
+    branch: Option<&str>,
+) -> RunStatus {
+    let Some((repo, client)) = repo.and_then(|repo| Some((repo, GitHubClient::for_repo(repo)?)))
+    else {
+        return RunStatus::failed("no_repository", "the repository is not on GitHub");
+    };
+    match client.latest_workflow_run(repo, workflow_file, branch) {
+        Ok(Some(run)) => RunStatus {
+            ok: true,
+            reason: None,
+            message: None,
+            status: run.status,
+            conclusion: run.conclusion,
+            run_id: Some(run.id),
+            html_url: run.html_url,
+            updated_at: run.updated_at,
+        },
+        Ok(None) => RunStatus::failed("no_runs", "the workflow has no runs"),
+        Err(error) => RunStatus::failed(error.reason(), error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GitHubClient, GitHubError};
+    use crate::forge::parse_repo_url;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// Serves `responses` in order and reports each request head.
+    fn serve(responses: Vec<String>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = String::new();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                sender.send(head).unwrap();
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, receiver)
+    }
+
+    fn response(status: &str, headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{headers}\r\n{body}",
+            body.len()
+        )
+    }
+
+    #[test]
+    fn reads_latest_workflow_run_with_token() {
+        let (url, requests) = serve(vec![response(
+            "200 OK",
+            "",
+            r#"{"total_count":1,"workflow_runs":[{"id":42,"status":"completed","conclusion":"success","html_url":"https://github.com/o/r/actions/runs/42","updated_at":"2026-01-02T03:04:05Z"}]}"#,
+        )]);
+        let repo = parse_repo_url("https://github.com/o/r").unwrap();
+        let client = GitHubClient::new(&url, Some("secret".to_string()));
+        let run = client
+            .latest_workflow_run(&repo, "ci.yml", Some("release/1"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(run.id, 42);
+        assert_eq!(run.conclusion.as_deref(), Some("success"));
+
+        let head = requests.recv().unwrap();
+        assert!(head.starts_with(
+            "GET /repos/o/r/actions/workflows/ci.yml/runs?per_page=1&branch=release%2F1 "
+        ));
+        assert!(
+            head.to_ascii_lowercase()
+                .contains("authorization: bearer secret")
+        );
+    }
+
+    #[test]
+    fn classifies_error_responses() {
+        let (url, _requests) = serve(vec![
+            response("200 OK", "", r#"{"total_count":0,"workflow_runs":[]}"#),
+            response("401 Unauthorized", "", "{}"),
+            response(
+                "403 Forbidden",
+                "x-ratelimit-remaining: 0\r\nx-ratelimit-reset: 1700000000\r\n",
+                "{}",
+            ),
+            response("403 Forbidden", "x-ratelimit-remaining: 10\r\n", "{}"),
+            response("404 Not Found", "", "{}"),
+            response("502 Bad Gateway", "", "{}"),
+            response("200 OK", "", "not json"),
+        ]);
+        let repo = parse_repo_url("https://github.com/o/r").unwrap();
+        let client = GitHubClient::new(&url, None);
+        let latest = || client.latest_workflow_run(&repo, "ci.yml", None);
+        assert!(latest().unwrap().is_none());
+        assert_eq!(latest().unwrap_err(), GitHubError::Unauthorized);
+        assert_eq!(
+            latest().unwrap_err(),
+            GitHubError::RateLimited {
+                reset: Some(1_700_000_000)
+            }
+        );
+        assert_eq!(latest().unwrap_err(), GitHubError::Forbidden);
+        assert_eq!(latest().unwrap_err(), GitHubError::NotFound);
+        assert_eq!(latest().unwrap_err(), GitHubError::Http(502));
+        assert_eq!(latest().unwrap_err().reason(), "invalid_response");
+    }
+}
@@ -1,14 +1,30 @@
-use crate::config::Config;
+use crate::badge_service::BadgeHosts;
+use crate::badges::badge_for_workflow;
+use crate::config::{CiConfig, Config};
 use crate::core::ProjectContext;
-use crate::manifest::{read_moon_mod, read_package_json, read_resolved_cargo_package};
+use crate::coverage::detect_coverage_providers;
+use crate::forge::{ForgeKind, RepoRef};
+use crate::github::latest_run_status;
+use crate::manifest::{
+    read_go_mod, read_jsr_manifest, read_moon_mod, read_npm_workspace, read_package_json,
+    read_resolved_cargo_package, read_resolved_python_package, split_jsr_package,
+};
 use crate::project::{
     NpmPackage, local_npm_packages, repository_to_string, select_representative_npm_package,
 };
-use crate::providers::{RegistryMetadata, fetch_crates_metadata};
-use crate::readme::readme_newline_info;
+use crate::providers::{
+    RegistryMetadata, fetch_crates_metadata, fetch_jsr_metadata, fetch_mooncakes_metadata,
+    fetch_pypi_metadata,
+};
+use crate::readme::{LiteralBlocks, ReadmeFormat, ReadmeSelection, readme_newline_info};
 use crate::readme_badges::ParsedBadge;
+use crate::security::detect_security;
 use crate::version::VersionOptions;
-use crate::workflows::{WorkflowInfo, detect_workflows, gh_latest_status_json_in};
+use crate::workflows::{
+    WorkflowInfo, detect_ci_services, detect_container_images, detect_dockerfiles,
+    detect_forgejo_workflows, detect_gitlab_ci, detect_woodpecker, detect_workflows,
+    is_selected_ci_workflow, workflow_badge_scope,
+};
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -18,15 +34,18 @@ pub(crate) struct ListJson {
     repo: Option<RepoJson>,
     config: Option<ConfigJson>,
     readme: ReadmeJson,
+    ecosystems: Vec<EcosystemJson>,
     manifests: HashMap<String, serde_json::Value>,
     registries: HashMap<String, serde_json::Value>,
     ci: CiJson,
+    security: SecurityJson,
     readme_block: ReadmeBlockJson,
     warnings: Vec<ListWarningJson>,
 }
 
 #[derive(Debug, Serialize)]
 struct ConfigJson {
+    readme: Option<String>,
     version: ConfigVersionJson,
     badges: ConfigBadgesJson,
     catalog: ConfigCatalogJson,
@@ -52,15 +71,32 @@ struct ConfigCatalogJson {
 #[derive(Debug, Serialize)]
 struct RepoJson {
     git_root: String,
+    remote_name: Option<String>,
     remote: Option<String>,
+    host: Option<String>,
+    forge: Option<String>,
     owner: Option<String>,
     name: Option<String>,
     default_branch: Option<String>,
+    branch: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct EcosystemJson {
+    name: String,
+    manifest: Option<String>,
+    primary: bool,
 }
 
 #[derive(Debug, Serialize)]
 struct ReadmeJson {
     path: String,
+    /// Why this file was chosen: `cli`, `config`, `manifest` or `default`.
+    source: String,
+    /// Manifest declaring the README when `source` is `manifest`.
+    declared_by: Option<String>,
+    /// Markup of the file: `markdown`, `rst` or `asciidoc`.
+    format: String,
     newline: String,
     trailing_newline: bool,
     markers: MarkerJson,
@@ -80,14 +116,68 @@ struct MarkerJson {
 struct CiJson {
     workflows_dir: String,
     workflows: Vec<WorkflowJson>,
+    dockerfiles: Vec<String>,
+    containers: Vec<ContainerJson>,
+    gitlab: Option<GitLabCiJson>,
+    forgejo_workflows: Vec<String>,
+    woodpecker: Option<String>,
+    services: Vec<CiServiceJson>,
+    coverage: Vec<CoverageJson>,
+}
+
+#[derive(Debug, Serialize)]
+struct CiServiceJson {
+    service: String,
+    file: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SecurityJson {
+    scorecard_workflow: Option<String>,
+    audit_workflows: Vec<String>,
+    cargo_deny: Option<String>,
+    policy: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CoverageJson {
+    provider: String,
+    file: String,
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabCiJson {
+    file: String,
+    coverage: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ContainerJson {
+    registry: String,
+    image: String,
+    workflow: String,
 }
 
 #[derive(Debug, Serialize)]
 struct WorkflowJson {
     file: String,
     name: String,
+    /// `on:` events, `null` when the workflow could not be parsed.
+    triggers: Option<Vec<WorkflowTriggerJson>>,
+    jobs: Vec<String>,
+    /// Whether `sync` badges this workflow as CI.
+    selected: bool,
     badge: WorkflowBadgeJson,
-    latest_status: GhStatusJson,
+    latest_status: RunStatusJson,
+}
+
+#[derive(Debug, Serialize)]
+struct WorkflowTriggerJson {
+    event: String,
+    branches: Vec<String>,
+    branches_ignore: Vec<String>,
+    tags: Vec<String>,
+    tags_ignore: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -98,10 +188,12 @@ struct WorkflowBadgeJson {
 }
 
 #[derive(Debug, Serialize)]
-struct GhStatusJson {
+struct RunStatusJson {
     source: String,
     ok: bool,
     reason: Option<String>,
+    message: Option<String>,
+    status: Option<String>,
     conclusion: Option<String>,
     run_id: Option<u64>,
     html_url: Option<String>,
@@ -135,25 +227,44 @@ struct ListWarningJson {
 
 pub(crate) fn build_list_json(
     context: &ProjectContext,
-    readme_path: &std::path::Path,
+    readme: &ReadmeSelection,
     content: &str,
     badges: &[String],
     options: &VersionOptions,
     config: Option<&Config>,
 ) -> anyhow::Result<ListJson> {
+    let forge = config
+        .map(|config| config.forge.clone())
+        .unwrap_or_default();
+    let repo_ref = context.git.as_ref().and_then(|git| git.repo_ref(&forge));
     let repo = context.git.as_ref().map(|git| RepoJson {
         git_root: git.root.to_string_lossy().to_string(),
+        remote_name: git.remote_name.clone(),
         remote: git.remote.clone(),
+        host: git.host.clone(),
+        forge: repo_ref
+            .as_ref()
+            .map(|repo| repo.kind)
+            .or(git.forge)
+            .map(|forge| forge.as_str().to_string()),
         owner: git.owner.clone(),
         name: git.repo.clone(),
         default_branch: git.default_branch.clone(),
+        branch: git.branch.clone(),
     });
 
     let (newline, trailing) = readme_newline_info(content);
     let marker = crate::readme::marker_state(content);
     let marker_count = marker.begin_count;
+    let format = ReadmeFormat::of(&readme.path, content);
     let readme_json = ReadmeJson {
-        path: readme_path.to_string_lossy().to_string(),
+        path: readme.path.to_string_lossy().to_string(),
+        source: readme.source.as_str().to_string(),
+        declared_by: readme
+            .manifest
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string()),
+        format: format.as_str().to_string(),
         newline,
         trailing_newline: trailing,
         markers: MarkerJson {
@@ -166,13 +277,32 @@ pub(crate) fn build_list_json(
         },
     };
 
+    let ecosystems = context
+        .ecosystems
+        .iter()
+        .enumerate()
+        .map(|(idx, ecosystem)| EcosystemJson {
+            name: ecosystem.as_str().to_string(),
+            manifest: context
+                .manifest_path(*ecosystem)
+                .map(|path| path.to_string_lossy().to_string()),
+            primary: idx == 0,
+        })
+        .collect();
     let npm_packages = local_npm_packages(context);
     let manifests = collect_manifests(context, options, &npm_packages)?;
     let registries = collect_registries(context, options, &npm_packages)?;
-    let ci = build_ci_json(context)?;
-    let readme_block = build_readme_block(badges);
+    let ci_config = config.map(|config| config.ci.clone()).unwrap_or_default();
+    let ci = build_ci_json(context, repo_ref.as_ref(), &ci_config)?;
+    let security = build_security_json(context, repo_ref.as_ref());
+    let hosts = config.map(BadgeHosts::from_config).unwrap_or_default();
+    let readme_block = build_readme_block(badges, format, &hosts);
 
     let config_json = config.map(|cfg| ConfigJson {
+        readme: cfg
+            .readme
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string()),
         version: ConfigVersionJson {
             allow_yy_calver: cfg.version.allow_yy_calver,
             year_min: cfg.version.year_min,
@@ -190,9 +320,11 @@ pub(crate) fn build_list_json(
         repo,
         config: config_json,
         readme: readme_json,
+        ecosystems,
         manifests,
         registries,
         ci,
+        security,
         readme_block,
         warnings: Vec::new(),
     })
@@ -215,6 +347,7 @@ fn collect_manifests(
             .as_ref()
             .and_then(|path| read_package_json(path).ok().map(|pkg| (path, pkg)))
             .and_then(|(path, pkg)| {
+                let node_engine = pkg.node_engine().map(str::to_string);
                 Some(NpmPackage {
                     path: path.clone(),
                     name: pkg.name?,
@@ -222,6 +355,7 @@ fn collect_manifests(
                     license: pkg.license,
                     repository: pkg.repository,
                     description: pkg.description,
+                    node_engine,
                     private: pkg.private.unwrap_or(false),
                     registry: RegistryMetadata::empty(),
                     published: false,
@@ -246,6 +380,7 @@ fn collect_manifests(
                 "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
                 "license": pkg.license,
                 "repository": repo,
+                "node_engine": pkg.node_engine,
                 "private": pkg.private,
                 "published": pkg.published,
             }),
@@ -269,6 +404,7 @@ fn collect_manifests(
                     "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
                     "license": pkg.license,
                     "repository": repository_to_string(pkg.repository.clone()),
+                    "node_engine": pkg.node_engine,
                     "private": pkg.private,
                     "published": pkg.published,
                 })
@@ -279,6 +415,15 @@ fn collect_manifests(
             serde_json::Value::Array(packages),
         );
     }
+    if let Some(workspace) = read_npm_workspace(&context.root) {
+        manifests.insert(
+            "node_workspace".to_string(),
+            serde_json::json!({
+                "source": workspace.source,
+                "patterns": workspace.patterns,
+            }),
+        );
+    }
     if let Some(path) = &context.manifests.cargo_toml
         && let Some(package) = read_resolved_cargo_package(path)?
     {
@@ -298,6 +443,62 @@ fn collect_manifests(
                 "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
                 "license": package.license,
                 "repository": package.repository,
+                "rust_version": package.rust_version,
+                "edition": package.edition,
+            }),
+        );
+    }
+    if let Some(path) = &context.manifests.pyproject_toml
+        && let Some(package) = read_resolved_python_package(path)?
+    {
+        let version_info = package
+            .version
+            .as_deref()
+            .map(|v| crate::version::classify_version(v, options));
+        manifests.insert(
+            "python".to_string(),
+            serde_json::json!({
+                "path": path.to_string_lossy(),
+                "name": package.name,
+                "version": package.version,
+                "version_format": version_info.as_ref().map(|v| v.version_format.clone()),
+                "calver_scheme": version_info.as_ref().and_then(|v| v.calver_scheme.clone()),
+                "calver_parts": version_info.as_ref().and_then(|v| v.calver_parts.clone()),
+                "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
+                "license": package.license,
+                "repository": package.repository,
+                "requires_python": package.requires_python,
+            }),
+        );
+    }
+    if let Some(path) = &context.manifests.go_mod {
+        let module = read_go_mod(path)?;
+        manifests.insert(
+            "go".to_string(),
+            serde_json::json!({
+                "path": path.to_string_lossy(),
+                "module": module.module,
+                "go": module.go,
+            }),
+        );
+    }
+    if let Some(path) = &context.manifests.jsr_manifest {
+        let manifest = read_jsr_manifest(path)?;
+        let version_info = manifest
+            .version
+            .as_deref()
+            .map(|v| crate::version::classify_version(v, options));
+        manifests.insert(
+            "deno".to_string(),
+            serde_json::json!({
+                "path": path.to_string_lossy(),
+                "name": manifest.name,
+                "version": manifest.version,
+                "version_format": version_info.as_ref().map(|v| v.version_format.clone()),
+                "calver_scheme": version_info.as_ref().and_then(|v| v.calver_scheme.clone()),
+                "calver_parts": version_info.as_ref().and_then(|v| v.calver_parts.clone()),
+                "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
+                "license": manifest.license,
             }),
         );
     }
@@ -318,6 +519,10 @@ fn collect_manifests(
                 "calver_parts": version_info.as_ref().and_then(|v| v.calver_parts.clone()),
                 "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
                 "readme": module.readme,
+                "license": module.license,
+                "repository": module.repository,
+                "description": module.description,
+                "keywords": module.keywords,
             }),
         );
     }
@@ -436,60 +641,239 @@ fn collect_registries(
             }
         }
     }
-    if let Some(path) = &context.manifests.moon_mod {
-        let module = read_moon_mod(path)?;
-        registries.insert(
-            "mooncakes".to_string(),
-            serde_json::json!({
-                "ok": false,
-                "module": module.name,
-                "reason": "disabled",
-            }),
-        );
+    if let Some(path) = &context.manifests.pyproject_toml
+        && let Some(package) = read_resolved_python_package(path)?
+        && let Some(name) = package.name.as_deref()
+    {
+        match fetch_pypi_metadata(name) {
+            Ok(meta) => {
+                let version_info = meta
+                    .version
+                    .as_deref()
+                    .map(|v| crate::version::classify_version(v, options));
+                registries.insert(
+                    "pypi".to_string(),
+                    serde_json::json!({
+                        "ok": true,
+                        "package": name,
+                        "latest": meta.version,
+                        "version_format": version_info.as_ref().map(|v| v.version_format.clone()),
+                        "calver_scheme": version_info.as_ref().and_then(|v| v.calver_scheme.clone()),
+                        "calver_parts": version_info.as_ref().and_then(|v| v.calver_parts.clone()),
+                        "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
+                        "license": meta.license,
+                        "homepage": meta.homepage,
+                        "repository": meta.repository,
+                    }),
+                );
+            }
+            Err(_) => {
+                registries.insert(
+                    "pypi".to_string(),
+                    serde_json::json!({
+                        "ok": false,
+                        "package": name,
+                        "reason": "network",
+                    }),
+                );
+            }
+        }
+    }
+    if let Some(path) = &context.manifests.jsr_manifest
+        && let Some(package) = read_jsr_manifest(path)?.name
+        && let Some((scope, name)) = split_jsr_package(&package)
+    {
+        match fetch_jsr_metadata(scope, name) {
+            Ok(meta) => {
+                let version_info = meta
+                    .version
+                    .as_deref()
+                    .map(|v| crate::version::classify_version(v, options));
+                registries.insert(
+                    "jsr".to_string(),
+                    serde_json::json!({
+                        "ok": true,
+                        "package": package,
+                        "latest": meta.version,
+                        "version_format": version_info.as_ref().map(|v| v.version_format.clone()),
+                        "calver_scheme": version_info.as_ref().and_then(|v| v.calver_scheme.clone()),
+                        "calver_parts": version_info.as_ref().and_then(|v| v.calver_parts.clone()),
+                        "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
+                        "repository": meta.repository,
+                    }),
+                );
+            }
+            Err(_) => {
+                registries.insert(
+                    "jsr".to_string(),
+                    serde_json::json!({
+                        "ok": false,
+                        "package": package,
+                        "reason": "network",
+                    }),
+                );
+            }
+        }
+    }
+    if let Some(path) = &context.manifests.moon_mod
+        && let Some(name) = read_moon_mod(path)?.name
+    {
+        let result = if name.contains('/') {
+            fetch_mooncakes_metadata(&name).map_err(|_| "network")
+        } else {
+            Err("unscoped")
+        };
+        match result {
+            Ok(meta) => {
+                let version_info = meta
+                    .version
+                    .as_deref()
+                    .map(|v| crate::version::classify_version(v, options));
+                registries.insert(
+                    "mooncakes".to_string(),
+                    serde_json::json!({
+                        "ok": true,
+                        "module": name,
+                        "latest": meta.version,
+                        "version_format": version_info.as_ref().map(|v| v.version_format.clone()),
+                        "calver_scheme": version_info.as_ref().and_then(|v| v.calver_scheme.clone()),
+                        "calver_parts": version_info.as_ref().and_then(|v| v.calver_parts.clone()),
+                        "modifier": version_info.as_ref().and_then(|v| v.modifier.clone()),
+                        "license": meta.license,
+                        "repository": meta.repository,
+                    }),
+                );
+            }
+            Err(reason) => {
+                registries.insert(
+                    "mooncakes".to_string(),
+                    serde_json::json!({
+                        "ok": false,
+                        "module": name,
+                        "reason": reason,
+                    }),
+                );
+            }
+        }
     }
     Ok(registries)
 }
 
-fn build_ci_json(context: &ProjectContext) -> anyhow::Result<CiJson> {
+fn build_security_json(context: &ProjectContext, repo_ref: Option<&RepoRef>) -> SecurityJson {
+    let workflows = match repo_ref.map(|repo| repo.kind) {
+        Some(ForgeKind::Forgejo) => detect_forgejo_workflows(&context.root),
+        _ => detect_workflows(&context.root),
+    };
+    let security = detect_security(&context.root, &workflows);
+    SecurityJson {
+        scorecard_workflow: security.scorecard_workflow,
+        audit_workflows: security.audit_workflows,
+        cargo_deny: security.cargo_deny,
+        policy: security.policy,
+    }
+}
+
+fn build_ci_json(
+    context: &ProjectContext,
+    repo_ref: Option<&RepoRef>,
+    ci_config: &CiConfig,
+) -> anyhow::Result<CiJson> {
     let workflows = detect_workflows(&context.root);
     let workflows_json = workflows
         .iter()
-        .map(|wf| workflow_to_json(context, wf))
+        .map(|wf| workflow_to_json(context, repo_ref, wf, ci_config))
         .collect::<Vec<_>>();
+    let (owner, repo) = context
+        .git
+        .as_ref()
+        .map(|git| (git.owner.as_deref(), git.repo.as_deref()))
+        .unwrap_or((None, None));
+    let containers = detect_container_images(&context.root, owner, repo)
+        .into_iter()
+        .map(|image| ContainerJson {
+            registry: image.registry.as_str().to_string(),
+            image: image.name,
+            workflow: image.workflow,
+        })
+        .collect();
     Ok(CiJson {
         workflows_dir: ".github/workflows".to_string(),
         workflows: workflows_json,
+        dockerfiles: detect_dockerfiles(&context.root),
+        containers,
+        gitlab: detect_gitlab_ci(&context.root).map(|ci| GitLabCiJson {
+            file: ci.file,
+            coverage: ci.coverage,
+        }),
+        forgejo_workflows: detect_forgejo_workflows(&context.root)
+            .into_iter()
+            .map(|workflow| workflow.file)
+            .collect(),
+        woodpecker: detect_woodpecker(&context.root),
+        services: detect_ci_services(&context.root)
+            .into_iter()
+            .map(|found| CiServiceJson {
+                service: found.service.as_str().to_string(),
+                file: found.file,
+            })
+            .collect(),
+        coverage: detect_coverage_providers(&context.root)
+            .into_iter()
+            .map(|found| CoverageJson {
+                provider: found.provider.as_str().to_string(),
+                file: found.file,
+            })
+            .collect(),
     })
 }
 
-fn workflow_to_json(context: &ProjectContext, workflow: &WorkflowInfo) -> WorkflowJson {
+fn workflow_to_json(
+    context: &ProjectContext,
+    repo_ref: Option<&RepoRef>,
+    workflow: &WorkflowInfo,
+    ci_config: &CiConfig,
+) -> WorkflowJson {
+    let default_branch = context
+        .git
+        .as_ref()
+        .and_then(|git| git.default_branch.as_deref());
+    let (branch, event) = workflow_badge_scope(workflow, default_branch, ci_config);
     let mut image = String::new();
     let mut link = String::new();
-    if let Some(git) = &context.git
-        && let (Some(owner), Some(repo)) = (git.owner.as_deref(), git.repo.as_deref())
-    {
-        image = format!(
-            "https://github.com/{}/{}/actions/workflows/{}/badge.svg",
-            owner, repo, workflow.file
-        );
-        link = format!(
-            "https://github.com/{}/{}/actions/workflows/{}",
-            owner, repo, workflow.file
-        );
+    if let Some(repo) = repo_ref.filter(|repo| repo.kind == ForgeKind::GitHub) {
+        let badge = badge_for_workflow(repo, &workflow.file, branch, event);
+        image = badge.image_url;
+        link = badge.link_url.unwrap_or_default();
     }
-    let status = gh_latest_status_json_in(&context.root, &workflow.file);
+    let status = latest_run_status(repo_ref, &workflow.file, branch);
     WorkflowJson {
         file: workflow.file.clone(),
         name: workflow.name.clone(),
+        triggers: workflow.triggers.as_ref().map(|triggers| {
+            triggers
+                .iter()
+                .map(|trigger| WorkflowTriggerJson {
+                    event: trigger.event.clone(),
+                    branches: trigger.branches.clone(),
+                    branches_ignore: trigger.branches_ignore.clone(),
+                    tags: trigger.tags.clone(),
+                    tags_ignore: trigger.tags_ignore.clone(),
+                })
+                .collect()
+        }),
+        jobs: workflow.jobs.clone(),
+        selected: is_selected_ci_workflow(workflow, context.ci_branch(), ci_config),
         badge: WorkflowBadgeJson {
             kind: "github_actions".to_string(),
             image,
             link,
         },
-        latest_status: GhStatusJson {
-            source: "gh".to_string(),
+        latest_status: RunStatusJson {
+            source: "github_api".to_string(),
             ok: status.ok,
             reason: status.reason,
+            message: status.message,
+            status: status.status,
             conclusion: status.conclusion,
             run_id: status.run_id,
             html_url: status.html_url,
@@ -498,7 +882,11 @@ fn workflow_to_json(context: &ProjectContext, workflow: &WorkflowInfo) -> Workfl
     }
 }
 
-fn build_readme_block(badges: &[String]) -> ReadmeBlockJson {
+fn build_readme_block(
+    badges: &[String],
+    format: ReadmeFormat,
+    hosts: &BadgeHosts,
+) -> ReadmeBlockJson {
     let raw = if badges.is_empty() {
         String::new()
     } else {
@@ -507,16 +895,12 @@ fn build_readme_block(badges: &[String]) -> ReadmeBlockJson {
         joined
     };
     let mut parsed = Vec::new();
-    let mut in_code_fence = false;
+    let mut literal = LiteralBlocks::new(format);
     for line in badges {
-        if is_code_fence(line) {
-            in_code_fence = !in_code_fence;
-            continue;
-        }
-        if in_code_fence {
+        if literal.skip(line) {
             continue;
         }
-        let badge = crate::readme_badges::parse_badge_line(line);
+        let badge = crate::readme_badges::parse_badge_line_with(line, hosts);
         parsed.push(readme_badge_from_parsed(badge));
     }
     ReadmeBlockJson {
@@ -525,10 +909,6 @@ fn build_readme_block(badges: &[String]) -> ReadmeBlockJson {
     }
 }
 
-fn is_code_fence(line: &str) -> bool {
-    line.trim_start().starts_with("```")
-}
-
 fn readme_badge_from_parsed(parsed: ParsedBadge) -> ReadmeBadgeJson {
     ReadmeBadgeJson {
         id: parsed.id,
@@ -1,4 +1,5 @@
 pub mod app;
+pub mod badge_service;
 pub mod badges;
 pub mod build_info;
 pub mod catalog;
@@ -6,6 +7,10 @@ pub mod check;
 pub mod cli;
 pub mod config;
 pub mod core;
+pub mod coverage;
+pub mod forge;
+pub mod git;
+pub mod github;
 pub mod inspect;
 pub mod manifest;
 pub mod plan;
@@ -14,6 +19,7 @@ pub mod providers;
 pub mod readme;
 pub mod readme_badges;
 pub mod readme_remove;
+pub mod security;
 pub mod tui;
 pub mod version;
 pub mod workflows;
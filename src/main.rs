@@ -47,6 +47,8 @@ fn run() -> anyhow::Result<ExitCode> {
         }
         None => process_dir,
     };
+    let readme = cli.readme.map(|readme| current_dir.join(readme));
+    let readme = readme.as_deref();
 
     let code = match cli.command {
         bdg::cli::Commands::Add {
@@ -55,23 +57,45 @@ fn run() -> anyhow::Result<ExitCode> {
             allow_yy_calver,
             dry_run,
             json,
-        } => bdg::app::cmd_add(&current_dir, yes, &only, allow_yy_calver, dry_run, json)?,
+        } => bdg::app::cmd_add(
+            &current_dir,
+            readme,
+            yes,
+            &only,
+            allow_yy_calver,
+            dry_run,
+            json,
+        )?,
         bdg::cli::Commands::Sync {
             only,
             allow_yy_calver,
             dry_run,
             check,
             json,
-        } => bdg::app::cmd_sync(&current_dir, &only, allow_yy_calver, dry_run || check, json)?,
+            workspace,
+        } => {
+            if workspace {
+                bdg::app::cmd_sync_workspace(&current_dir, readme, &only, dry_run || check, json)?
+            } else {
+                bdg::app::cmd_sync(
+                    &current_dir,
+                    readme,
+                    &only,
+                    allow_yy_calver,
+                    dry_run || check,
+                    json,
+                )?
+            }
+        }
         bdg::cli::Commands::Check { json, strict } => {
-            bdg::check::cmd_check(&current_dir, json, strict)?
+            bdg::check::cmd_check(&current_dir, readme, json, strict)?
         }
         bdg::cli::Commands::List {
             json,
             quiet,
             allow_yy_calver,
         } => {
-            bdg::app::cmd_list(&current_dir, json, quiet, allow_yy_calver)?;
+            bdg::app::cmd_list(&current_dir, readme, json, quiet, allow_yy_calver)?;
             0
         }
         bdg::cli::Commands::Remove {
@@ -85,6 +109,7 @@ fn run() -> anyhow::Result<ExitCode> {
             allow_yy_calver,
         } => bdg::app::cmd_remove(
             &current_dir,
+            readme,
             all,
             &id,
             &kind,
@@ -109,7 +134,15 @@ fn run() -> anyhow::Result<ExitCode> {
                 set,
                 dry_run,
                 json,
-            } => bdg::catalog::cmd_catalog_add(&current_dir, &ids, &source, &set, dry_run, json)?,
+            } => bdg::catalog::cmd_catalog_add(
+                &current_dir,
+                readme,
+                &ids,
+                &source,
+                &set,
+                dry_run,
+                json,
+            )?,
             bdg::cli::CatalogCommand::AddUrl {
                 image,
                 label,
@@ -118,6 +151,7 @@ fn run() -> anyhow::Result<ExitCode> {
                 json,
             } => bdg::catalog::cmd_catalog_add_url(
                 &current_dir,
+                readme,
                 &image,
                 &label,
                 link.as_deref(),
@@ -1,5 +1,6 @@
 use serde::Deserialize;
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct PackageJson {
@@ -9,6 +10,54 @@ pub struct PackageJson {
     pub description: Option<String>,
     pub license: Option<String>,
     pub repository: Option<RepositoryField>,
+    pub workspaces: Option<NpmWorkspacesField>,
+    pub engines: Option<BTreeMap<String, String>>,
+    pub readme: Option<String>,
+}
+
+/// package.json `workspaces`: a glob list, or Yarn's `{ "packages": [...] }` form.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum NpmWorkspacesField {
+    Patterns(Vec<String>),
+    Object {
+        #[serde(default)]
+        packages: Vec<String>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct LernaJson {
+    packages: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PnpmWorkspaceYaml {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NxJson {
+    #[serde(rename = "workspaceLayout")]
+    workspace_layout: Option<NxWorkspaceLayout>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NxWorkspaceLayout {
+    #[serde(rename = "appsDir")]
+    apps_dir: Option<String>,
+    #[serde(rename = "libsDir")]
+    libs_dir: Option<String>,
+}
+
+/// JavaScript workspace declaration found at a repository root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NpmWorkspace {
+    /// Config file the patterns came from, e.g. `pnpm-workspace.yaml`.
+    pub source: &'static str,
+    /// Globs as written; entries starting with `!` exclude packages.
+    pub patterns: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -23,6 +72,11 @@ pub struct MoonMod {
     pub name: Option<String>,
     pub version: Option<String>,
     pub readme: Option<String>,
+    pub license: Option<String>,
+    pub repository: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -38,11 +92,20 @@ pub struct CargoPackage {
     pub description: Option<CargoPackageField>,
     pub license: Option<CargoPackageField>,
     pub repository: Option<CargoPackageField>,
+    #[serde(rename = "rust-version")]
+    pub rust_version: Option<CargoPackageField>,
+    pub edition: Option<CargoPackageField>,
+    pub readme: Option<CargoReadmeField>,
+    pub publish: Option<CargoPublishField>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CargoWorkspace {
     pub package: Option<CargoWorkspacePackage>,
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -51,6 +114,100 @@ pub struct CargoWorkspacePackage {
     pub description: Option<String>,
     pub license: Option<String>,
     pub repository: Option<String>,
+    #[serde(rename = "rust-version")]
+    pub rust_version: Option<String>,
+    pub edition: Option<String>,
+    pub readme: Option<CargoReadmeField>,
+    pub publish: Option<CargoPublishField>,
+}
+
+/// `package.readme`: a path, `false` to disable, or `readme.workspace = true`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum CargoReadmeField {
+    Path(String),
+    Enabled(bool),
+    Workspace { workspace: bool },
+}
+
+/// `package.publish`: `false`, a registry allow-list, or `publish.workspace = true`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum CargoPublishField {
+    Enabled(bool),
+    Registries(Vec<String>),
+    Workspace { workspace: bool },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PyProject {
+    pub project: Option<PyProjectTable>,
+    pub tool: Option<PyProjectTool>,
+}
+
+/// PEP 621 `[project]` table.
+#[derive(Debug, Deserialize)]
+pub struct PyProjectTable {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub license: Option<PyProjectLicense>,
+    #[serde(rename = "requires-python")]
+    pub requires_python: Option<String>,
+    #[serde(default)]
+    pub urls: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum PyProjectLicense {
+    Expression(String),
+    Table {
+        text: Option<String>,
+        file: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PyProjectTool {
+    pub poetry: Option<PoetryTable>,
+}
+
+/// Poetry's `[tool.poetry]` table, used when `[project]` is absent or incomplete.
+#[derive(Debug, Deserialize)]
+pub struct PoetryTable {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub license: Option<String>,
+    pub repository: Option<String>,
+    pub homepage: Option<String>,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, toml::Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolvedPythonPackage {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub license: Option<String>,
+    pub repository: Option<String>,
+    pub requires_python: Option<String>,
+}
+
+/// Package fields shared by `jsr.json`, `deno.json` and `deno.jsonc`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JsrManifest {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub license: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoMod {
+    pub module: Option<String>,
+    pub go: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -67,6 +224,31 @@ pub struct ResolvedCargoPackage {
     pub description: Option<String>,
     pub license: Option<String>,
     pub repository: Option<String>,
+    /// `rust-version`, the minimum supported Rust version.
+    pub rust_version: Option<String>,
+    pub edition: Option<String>,
+    /// README resolved the way Cargo does: the declared path, or `README.md`,
+    /// `README.txt` or `README` next to the manifest. `None` when disabled or absent.
+    pub readme: Option<PathBuf>,
+    /// `Some(false)` when `publish = false` or the registry list excludes crates.io.
+    pub publish: Option<bool>,
+}
+
+impl PackageJson {
+    /// `engines.node`, the supported Node version range.
+    pub fn node_engine(&self) -> Option<&str> {
+        self.engines
+            .as_ref()
+            .and_then(|engines| engines.get("node"))
+            .map(|range| range.trim())
+            .filter(|range| !range.is_empty())
+    }
+}
+
+impl ResolvedCargoPackage {
+    pub fn is_publishable(&self) -> bool {
+        self.publish != Some(false)
+    }
 }
 
 impl CargoPackageField {
@@ -88,6 +270,97 @@ pub fn read_package_json(path: &Path) -> anyhow::Result<PackageJson> {
     Ok(package)
 }
 
+/// Reads the workspace declaration at `root`, checking `pnpm-workspace.yaml`,
+/// the package.json `workspaces` field, `lerna.json` and then the nx
+/// `workspaceLayout`, in that order. Returns `None` when none of them declare packages.
+pub fn read_npm_workspace(root: &Path) -> Option<NpmWorkspace> {
+    if let Ok(content) = std::fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        let patterns = parse_pnpm_workspace_packages(&content);
+        if !patterns.is_empty() {
+            return Some(NpmWorkspace {
+                source: "pnpm-workspace.yaml",
+                patterns,
+            });
+        }
+    }
+    if let Ok(package) = read_package_json(&root.join("package.json")) {
+        let patterns = match package.workspaces {
+            Some(NpmWorkspacesField::Patterns(patterns)) => patterns,
+            Some(NpmWorkspacesField::Object { packages }) => packages,
+            None => Vec::new(),
+        };
+        if !patterns.is_empty() {
+            return Some(NpmWorkspace {
+                source: "package.json",
+                patterns,
+            });
+        }
+    }
+    if let Ok(content) = std::fs::read_to_string(root.join("lerna.json"))
+        && let Ok(lerna) = serde_json::from_str::<LernaJson>(&content)
+    {
+        return Some(NpmWorkspace {
+            source: "lerna.json",
+            patterns: lerna
+                .packages
+                .unwrap_or_else(|| vec!["packages/*".to_string()]),
+        });
+    }
+    if let Ok(content) = std::fs::read_to_string(root.join("nx.json"))
+        && let Ok(nx) = serde_json::from_str::<NxJson>(&content)
+    {
+        // nx.json alone does not make a workspace: without a `workspaceLayout`
+        // only the default `apps` and `libs` directories that exist count.
+        let patterns = match nx.workspace_layout {
+            Some(layout) => vec![
+                layout.apps_dir.unwrap_or_else(|| "apps".to_string()),
+                layout.libs_dir.unwrap_or_else(|| "libs".to_string()),
+            ],
+            None => ["apps", "libs"]
+                .into_iter()
+                .filter(|dir| root.join(dir).is_dir())
+                .map(str::to_string)
+                .collect(),
+        };
+        if !patterns.is_empty() {
+            return Some(NpmWorkspace {
+                source: "nx.json",
+                patterns: patterns.iter().map(|dir| format!("{dir}/*")).collect(),
+            });
+        }
+    }
+    None
+}
+
+/// Reads the `packages:` list of a `pnpm-workspace.yaml`.
+pub fn parse_pnpm_workspace_packages(content: &str) -> Vec<String> {
+    serde_yaml_ng::from_str::<PnpmWorkspaceYaml>(content)
+        .map(|workspace| workspace.packages)
+        .unwrap_or_default()
+}
+
+/// package.json paths matched by the workspace globs, minus `!` exclusions.
+pub fn npm_workspace_packages(root: &Path, workspace: &NpmWorkspace) -> Vec<PathBuf> {
+    let (excludes, includes): (Vec<&String>, Vec<&String>) = workspace
+        .patterns
+        .iter()
+        .partition(|pattern| pattern.starts_with('!'));
+    let excluded = excludes
+        .iter()
+        .flat_map(|pattern| expand_path_glob(root, pattern.trim_start_matches('!')))
+        .collect::<Vec<_>>();
+    let mut packages = includes
+        .iter()
+        .flat_map(|pattern| expand_path_glob(root, pattern))
+        .filter(|dir| !excluded.iter().any(|excluded| dir.starts_with(excluded)))
+        .map(|dir| dir.join("package.json"))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    packages.sort();
+    packages.dedup();
+    packages
+}
+
 pub fn read_moon_mod(path: &Path) -> anyhow::Result<MoonMod> {
     let content = std::fs::read_to_string(path)?;
     let module: MoonMod = serde_json::from_str(&content)?;
@@ -100,21 +373,308 @@ pub fn read_cargo_toml(path: &Path) -> anyhow::Result<CargoToml> {
     Ok(manifest)
 }
 
+pub fn read_pyproject(path: &Path) -> anyhow::Result<PyProject> {
+    let content = std::fs::read_to_string(path)?;
+    let manifest: PyProject = toml::from_str(&content)?;
+    Ok(manifest)
+}
+
+pub fn read_resolved_python_package(path: &Path) -> anyhow::Result<Option<ResolvedPythonPackage>> {
+    let manifest = read_pyproject(path)?;
+    let poetry = manifest.tool.and_then(|tool| tool.poetry);
+    if manifest.project.is_none() && poetry.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(resolve_python_package(manifest.project, poetry)))
+}
+
+pub fn pyproject_has_package(path: &Path) -> bool {
+    read_pyproject(path)
+        .map(|manifest| {
+            manifest.project.is_some() || manifest.tool.is_some_and(|tool| tool.poetry.is_some())
+        })
+        .unwrap_or(false)
+}
+
+pub fn read_jsr_manifest(path: &Path) -> anyhow::Result<JsrManifest> {
+    let content = std::fs::read_to_string(path)?;
+    let content = if path.extension().is_some_and(|ext| ext == "jsonc") {
+        strip_jsonc_comments(&content)
+    } else {
+        content
+    };
+    let manifest: JsrManifest = serde_json::from_str(&content)?;
+    Ok(manifest)
+}
+
+pub fn jsr_manifest_has_package(path: &Path) -> bool {
+    read_jsr_manifest(path)
+        .map(|manifest| manifest.name.is_some_and(|name| name.starts_with('@')))
+        .unwrap_or(false)
+}
+
+/// Splits a JSR package name like `@scope/name` into its scope and name.
+pub fn split_jsr_package(package: &str) -> Option<(&str, &str)> {
+    let (scope, name) = package.strip_prefix('@')?.split_once('/')?;
+    if scope.is_empty() || name.is_empty() {
+        None
+    } else {
+        Some((scope, name))
+    }
+}
+
+fn strip_jsonc_comments(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    while let Some(ch) = chars.next() {
+        if in_string {
+            output.push(ch);
+            if ch == '\\' {
+                if let Some(next) = chars.next() {
+                    output.push(next);
+                }
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match (ch, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(ch);
+            }
+            ('/', Some('/')) => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        output.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = '\0';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            _ => output.push(ch),
+        }
+    }
+    output
+}
+
+pub fn read_go_mod(path: &Path) -> anyhow::Result<GoMod> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(parse_go_mod(&content))
+}
+
+pub fn parse_go_mod(content: &str) -> GoMod {
+    let mut module = GoMod::default();
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or("").trim();
+        let mut parts = line.split_whitespace();
+        match (parts.next(), parts.next()) {
+            (Some("module"), Some(path)) if module.module.is_none() => {
+                module.module = Some(path.trim_matches('"').to_string());
+            }
+            (Some("go"), Some(version)) if module.go.is_none() => {
+                module.go = Some(version.to_string());
+            }
+            _ => {}
+        }
+    }
+    module
+}
+
+/// Derives a repository URL from a Go module path such as
+/// `github.com/owner/repo/v2`, dropping any subdirectory or major-version suffix.
+pub fn go_module_repository(module: &str) -> Option<String> {
+    let mut parts = module.split('/');
+    let host = parts.next()?;
+    let owner = parts.next()?;
+    let repo = parts.next()?;
+    if !host.contains('.') || owner.is_empty() || repo.is_empty() {
+        return None;
+    }
+    Some(format!("https://{host}/{owner}/{repo}"))
+}
+
 pub fn read_resolved_cargo_package(path: &Path) -> anyhow::Result<Option<ResolvedCargoPackage>> {
     let manifest = read_cargo_toml(path)?;
     let Some(package) = manifest.package else {
         return Ok(None);
     };
-    let workspace_package = match manifest.workspace.and_then(|workspace| workspace.package) {
-        Some(package) => Some(package),
+    let manifest_dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let workspace = match manifest.workspace.and_then(|workspace| workspace.package) {
+        Some(package) => Some((manifest_dir.to_path_buf(), package)),
         None => find_workspace_package(path)?,
     };
     Ok(Some(resolve_cargo_package(
         package,
-        workspace_package.as_ref(),
+        manifest_dir,
+        workspace
+            .as_ref()
+            .map(|(dir, package)| (dir.as_path(), package)),
     )))
 }
 
+/// README path declared explicitly by a Cargo.toml (`package.readme`, including
+/// `readme.workspace = true`), package.json or moon.mod.json `readme` field,
+/// resolved against the declaring manifest's directory. Cargo's implicit
+/// `README.md` fallback does not count as a declaration.
+pub fn read_declared_readme(path: &Path) -> Option<PathBuf> {
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let declared = match path.file_name().and_then(|name| name.to_str()) {
+        Some("Cargo.toml") => return read_declared_cargo_readme(path).ok().flatten(),
+        Some("package.json") => read_package_json(path).ok()?.readme,
+        Some("moon.mod.json") => read_moon_mod(path).ok()?.readme,
+        _ => None,
+    };
+    declared
+        .filter(|readme| !readme.trim().is_empty())
+        .map(|readme| dir.join(readme))
+}
+
+fn read_declared_cargo_readme(path: &Path) -> anyhow::Result<Option<PathBuf>> {
+    let manifest = read_cargo_toml(path)?;
+    let Some(package) = manifest.package else {
+        return Ok(None);
+    };
+    let manifest_dir = path.parent().unwrap_or_else(|| Path::new(""));
+    match package.readme {
+        Some(CargoReadmeField::Path(readme)) => Ok(Some(manifest_dir.join(readme))),
+        Some(CargoReadmeField::Workspace { workspace: true }) => {
+            let workspace = match manifest.workspace.and_then(|workspace| workspace.package) {
+                Some(package) => Some((manifest_dir.to_path_buf(), package)),
+                None => find_workspace_package(path)?,
+            };
+            Ok(workspace.and_then(|(dir, package)| match package.readme {
+                Some(CargoReadmeField::Path(readme)) => Some(dir.join(readme)),
+                _ => None,
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Member manifests of the workspace declared in `workspace_manifest`, with
+/// `members` globs expanded and `exclude` applied. The root manifest is listed
+/// first when it also declares a `[package]`.
+pub fn cargo_workspace_members(workspace_manifest: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let manifest = read_cargo_toml(workspace_manifest)?;
+    let Some(workspace) = manifest.workspace else {
+        anyhow::bail!(
+            "{} does not declare a [workspace]",
+            workspace_manifest.display()
+        );
+    };
+    let root = workspace_manifest.parent().unwrap_or_else(|| Path::new(""));
+    let excluded = workspace
+        .exclude
+        .iter()
+        .flat_map(|pattern| expand_path_glob(root, pattern))
+        .collect::<Vec<_>>();
+    let mut members = Vec::new();
+    if manifest.package.is_some() {
+        members.push(workspace_manifest.to_path_buf());
+    }
+    let mut expanded = workspace
+        .members
+        .iter()
+        .flat_map(|pattern| expand_path_glob(root, pattern))
+        .filter(|dir| !excluded.iter().any(|excluded| dir.starts_with(excluded)))
+        .map(|dir| dir.join("Cargo.toml"))
+        .filter(|path| path.is_file() && path != workspace_manifest)
+        .collect::<Vec<_>>();
+    expanded.sort();
+    expanded.dedup();
+    members.extend(expanded);
+    Ok(members)
+}
+
+/// Expands a workspace path pattern where `*` and `?` match within one segment
+/// and `**` matches any number of directories.
+fn expand_path_glob(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut current = vec![root.to_path_buf()];
+    for segment in pattern.trim_end_matches('/').split('/') {
+        if segment.is_empty() || segment == "." {
+            continue;
+        }
+        let mut next = Vec::new();
+        for dir in &current {
+            if segment == "**" {
+                next.extend(
+                    walkdir::WalkDir::new(dir)
+                        .max_depth(4)
+                        .into_iter()
+                        .filter_entry(|entry| {
+                            !matches!(
+                                entry.file_name().to_str(),
+                                Some("node_modules" | ".git" | "target")
+                            )
+                        })
+                        .flatten()
+                        .filter(|entry| entry.file_type().is_dir())
+                        .map(|entry| entry.path().to_path_buf()),
+                );
+                continue;
+            }
+            if !segment.contains(['*', '?']) {
+                let path = dir.join(segment);
+                if path.is_dir() {
+                    next.push(path);
+                }
+                continue;
+            }
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                if let Some(name) = name.to_str()
+                    && name != "node_modules"
+                    && entry.path().is_dir()
+                    && wildcard_match(segment, name)
+                {
+                    next.push(entry.path());
+                }
+            }
+        }
+        current = next;
+    }
+    current.sort();
+    current.dedup();
+    current
+}
+
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let name = name.as_bytes();
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|byte| *byte == b'*')
+}
+
 pub fn cargo_manifest_has_package(path: &Path) -> bool {
     read_cargo_toml(path)
         .map(|manifest| manifest.package.is_some())
@@ -123,8 +683,22 @@ pub fn cargo_manifest_has_package(path: &Path) -> bool {
 
 fn resolve_cargo_package(
     package: CargoPackage,
-    workspace_package: Option<&CargoWorkspacePackage>,
+    manifest_dir: &Path,
+    workspace: Option<(&Path, &CargoWorkspacePackage)>,
 ) -> ResolvedCargoPackage {
+    let workspace_package = workspace.map(|(_, package)| package);
+    let readme = match package.readme {
+        Some(CargoReadmeField::Workspace { workspace: true }) => {
+            workspace.and_then(|(dir, package)| resolve_cargo_readme(package.readme.as_ref(), dir))
+        }
+        readme => resolve_cargo_readme(readme.as_ref(), manifest_dir),
+    };
+    let publish = match package.publish {
+        Some(CargoPublishField::Workspace { workspace: true }) => {
+            workspace_package.and_then(|package| package.publish.as_ref().and_then(publish_flag))
+        }
+        publish => publish.as_ref().and_then(publish_flag),
+    };
     ResolvedCargoPackage {
         name: resolve_field(package.name, None),
         version: resolve_field(
@@ -143,9 +717,106 @@ fn resolve_cargo_package(
             package.repository,
             workspace_package.and_then(|package| package.repository.clone()),
         ),
+        rust_version: resolve_field(
+            package.rust_version,
+            workspace_package.and_then(|package| package.rust_version.clone()),
+        ),
+        edition: resolve_field(
+            package.edition,
+            workspace_package.and_then(|package| package.edition.clone()),
+        ),
+        readme,
+        publish,
+    }
+}
+
+fn resolve_cargo_readme(field: Option<&CargoReadmeField>, dir: &Path) -> Option<PathBuf> {
+    match field {
+        Some(CargoReadmeField::Path(path)) => Some(dir.join(path)),
+        Some(CargoReadmeField::Enabled(false)) => None,
+        _ => ["README.md", "README.txt", "README"]
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file()),
     }
 }
 
+fn publish_flag(field: &CargoPublishField) -> Option<bool> {
+    match field {
+        CargoPublishField::Enabled(enabled) => Some(*enabled),
+        CargoPublishField::Registries(registries) => {
+            Some(registries.iter().any(|registry| registry == "crates-io"))
+        }
+        CargoPublishField::Workspace { .. } => None,
+    }
+}
+
+fn resolve_python_package(
+    project: Option<PyProjectTable>,
+    poetry: Option<PoetryTable>,
+) -> ResolvedPythonPackage {
+    let (name, version, description, license, repository, requires_python) = match project {
+        Some(project) => {
+            let repository = project_url(&project.urls);
+            let license = project.license.and_then(|license| match license {
+                PyProjectLicense::Expression(value) => Some(value),
+                PyProjectLicense::Table { text, .. } => text,
+            });
+            (
+                project.name,
+                project.version,
+                project.description,
+                license,
+                repository,
+                project.requires_python,
+            )
+        }
+        None => (None, None, None, None, None, None),
+    };
+    let Some(poetry) = poetry else {
+        return ResolvedPythonPackage {
+            name,
+            version,
+            description,
+            license,
+            repository,
+            requires_python,
+        };
+    };
+    let poetry_python = poetry
+        .dependencies
+        .get("python")
+        .and_then(|value| value.as_str())
+        .map(str::to_string);
+    ResolvedPythonPackage {
+        name: name.or(poetry.name),
+        version: version.or(poetry.version),
+        description: description.or(poetry.description),
+        license: license.or(poetry.license),
+        repository: repository.or(poetry.repository).or(poetry.homepage),
+        requires_python: requires_python.or(poetry_python),
+    }
+}
+
+fn project_url(urls: &BTreeMap<String, String>) -> Option<String> {
+    for key in [
+        "repository",
+        "source",
+        "source code",
+        "code",
+        "github",
+        "homepage",
+    ] {
+        if let Some((_, url)) = urls
+            .iter()
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case(key))
+        {
+            return Some(url.clone());
+        }
+    }
+    None
+}
+
 fn resolve_field(
     field: Option<CargoPackageField>,
     workspace_value: Option<String>,
@@ -157,17 +828,31 @@ fn resolve_field(
     }
 }
 
-fn find_workspace_package(path: &Path) -> anyhow::Result<Option<CargoWorkspacePackage>> {
+fn find_workspace_package(path: &Path) -> anyhow::Result<Option<(PathBuf, CargoWorkspacePackage)>> {
     let mut dir = path.parent().map(Path::to_path_buf);
     while let Some(current) = dir {
         let candidate = current.join("Cargo.toml");
         if candidate != path && candidate.exists() {
             let manifest = read_cargo_toml(&candidate)?;
             if let Some(workspace) = manifest.workspace {
-                return Ok(workspace.package);
+                return Ok(workspace.package.map(|package| (current, package)));
             }
         }
         dir = current.parent().map(Path::to_path_buf);
     }
     Ok(None)
 }
+
+/// Nearest `Cargo.toml` declaring `[workspace]`, searching from `start` up to `root`.
+pub fn find_cargo_workspace_manifest(start: &Path, root: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .take_while(|dir| dir.starts_with(root))
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|candidate| {
+            candidate.is_file()
+                && read_cargo_toml(candidate)
+                    .map(|manifest| manifest.workspace.is_some())
+                    .unwrap_or(false)
+        })
+}
@@ -26,14 +26,20 @@ impl ReadmePlan {
     }
 
     pub fn diff(&self) -> String {
-        if !self.changed() {
-            return String::new();
-        }
         let rel_path = self
             .path
             .file_name()
             .and_then(|s| s.to_str())
             .unwrap_or("README.md");
+        self.diff_with_path(rel_path)
+    }
+
+    /// Like `diff`, but labels the patch with `rel_path`, so that plans for
+    /// several READMEs (e.g. workspace members) stay distinguishable.
+    pub fn diff_with_path(&self, rel_path: &str) -> String {
+        if !self.changed() {
+            return String::new();
+        }
         let patch = diffy::create_patch(&self.original, &self.updated);
         diffy::PatchFormatter::new()
             .fmt_patch(&patch)
@@ -1,8 +1,13 @@
 use crate::core::{Ecosystem, ProjectContext};
 use crate::manifest::{
-    RepositoryField, read_moon_mod, read_package_json, read_resolved_cargo_package,
+    RepositoryField, go_module_repository, npm_workspace_packages, read_go_mod, read_jsr_manifest,
+    read_moon_mod, read_npm_workspace, read_package_json, read_resolved_cargo_package,
+    read_resolved_python_package, split_jsr_package,
+};
+use crate::providers::{
+    RegistryMetadata, fetch_crates_metadata, fetch_jsr_metadata, fetch_mooncakes_metadata,
+    fetch_npm_metadata, fetch_pypi_metadata,
 };
-use crate::providers::{RegistryMetadata, fetch_crates_metadata, fetch_npm_metadata};
 use anyhow::Context;
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -25,15 +30,34 @@ pub(crate) struct NpmPackage {
     pub(crate) license: Option<String>,
     pub(crate) repository: Option<RepositoryField>,
     pub(crate) description: Option<String>,
+    pub(crate) node_engine: Option<String>,
     pub(crate) private: bool,
     pub(crate) registry: RegistryMetadata,
     pub(crate) published: bool,
 }
 
+/// package.json files that take part in badge generation. When the repository
+/// root declares a workspace (see `read_npm_workspace`), that is the root manifest
+/// plus every matched workspace package; otherwise it is the detected package.json.
+pub(crate) fn npm_package_paths(context: &ProjectContext) -> Vec<PathBuf> {
+    let Some(workspace) = read_npm_workspace(&context.root) else {
+        return context.manifests.package_json.iter().cloned().collect();
+    };
+    let root_manifest = context.root.join("package.json");
+    let mut paths = Vec::new();
+    if root_manifest.is_file() {
+        paths.push(root_manifest);
+    }
+    paths.extend(npm_workspace_packages(&context.root, &workspace));
+    paths
+}
+
+/// Reads the packages from `npm_package_paths`. Private packages are dropped
+/// before any registry request is made.
 pub(crate) fn local_npm_packages(context: &ProjectContext) -> Vec<NpmPackage> {
     let mut seen = HashSet::new();
     let mut packages = Vec::new();
-    for path in &context.manifests.package_json_all {
+    for path in &npm_package_paths(context) {
         if !seen.insert(path.clone()) {
             continue;
         }
@@ -46,6 +70,7 @@ pub(crate) fn local_npm_packages(context: &ProjectContext) -> Vec<NpmPackage> {
         let Some(name) = pkg.name.clone() else {
             continue;
         };
+        let node_engine = pkg.node_engine().map(str::to_string);
         let registry = fetch_npm_metadata(&name).unwrap_or_else(|_| RegistryMetadata::empty());
         let published = registry.version.is_some();
         packages.push(NpmPackage {
@@ -55,6 +80,7 @@ pub(crate) fn local_npm_packages(context: &ProjectContext) -> Vec<NpmPackage> {
             license: pkg.license,
             repository: pkg.repository,
             description: pkg.description,
+            node_engine,
             private: false,
             registry,
             published,
@@ -76,16 +102,64 @@ pub(crate) fn select_representative_npm_package(
     packages.first().cloned()
 }
 
+/// Resolves metadata for every detected ecosystem and merges the results field
+/// by field. Ecosystems are visited in `ProjectContext::ecosystems` order, so
+/// the first one that provides a name, version, license, repository or
+/// description wins; the registry response comes from the first ecosystem whose
+/// registry knows the package. A single failing ecosystem is skipped, and an
+/// error is only returned when all of them fail.
 pub(crate) fn resolve_metadata(
     context: &ProjectContext,
     npm_packages: Option<&[NpmPackage]>,
 ) -> anyhow::Result<ResolvedMetadata> {
-    match context.ecosystem {
-        Some(Ecosystem::Node) => resolve_node_metadata(context, npm_packages),
-        Some(Ecosystem::MoonBit) => resolve_moonbit_metadata(context),
-        Some(Ecosystem::Rust) => resolve_rust_metadata(context),
-        None => Ok(ResolvedMetadata::default()),
+    let mut resolved = Vec::new();
+    let mut first_error = None;
+    for ecosystem in &context.ecosystems {
+        let result = match ecosystem {
+            Ecosystem::Node => resolve_node_metadata(context, npm_packages),
+            Ecosystem::MoonBit => resolve_moonbit_metadata(context),
+            Ecosystem::Rust => resolve_rust_metadata(context),
+            Ecosystem::Python => resolve_python_metadata(context),
+            Ecosystem::Go => resolve_go_metadata(context),
+            Ecosystem::Deno => resolve_deno_metadata(context),
+        };
+        match result {
+            Ok(metadata) => resolved.push(metadata),
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+    if resolved.is_empty()
+        && let Some(err) = first_error
+    {
+        return Err(err);
     }
+    Ok(merge_metadata(resolved))
+}
+
+fn merge_metadata(resolved: Vec<ResolvedMetadata>) -> ResolvedMetadata {
+    let mut merged = ResolvedMetadata::default();
+    for metadata in resolved {
+        merged.name = merged.name.or(non_empty(metadata.name));
+        merged.version = merged.version.or(non_empty(metadata.version));
+        merged.license = merged.license.or(non_empty(metadata.license));
+        merged.repository = merged.repository.or(non_empty(metadata.repository));
+        merged.description = merged.description.or(non_empty(metadata.description));
+        if merged
+            .registry
+            .as_ref()
+            .is_none_or(|registry| registry.version.is_none())
+            && metadata.registry.is_some()
+        {
+            merged.registry = metadata.registry.or(merged.registry);
+        }
+    }
+    merged
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.trim().is_empty())
 }
 
 fn resolve_node_metadata(
@@ -138,13 +212,19 @@ fn resolve_moonbit_metadata(context: &ProjectContext) -> anyhow::Result<Resolved
         .as_ref()
         .context("moon.mod.json missing")?;
     let module = read_moon_mod(manifest_path)?;
+    let registry = module
+        .name
+        .as_deref()
+        .filter(|name| name.contains('/'))
+        .and_then(|name| fetch_mooncakes_metadata(name).ok())
+        .unwrap_or_else(RegistryMetadata::empty);
     Ok(ResolvedMetadata {
         name: module.name,
-        version: module.version,
-        license: None,
-        repository: None,
-        description: None,
-        registry: None,
+        version: registry.version.clone().or(module.version),
+        license: registry.license.clone().or(module.license),
+        repository: registry.repository.clone().or(module.repository),
+        description: registry.description.clone().or(module.description),
+        registry: Some(registry),
     })
 }
 
@@ -170,26 +250,75 @@ fn resolve_rust_metadata(context: &ProjectContext) -> anyhow::Result<ResolvedMet
     })
 }
 
-pub(crate) fn infer_owner_repo(repository: &Option<String>) -> (Option<String>, Option<String>) {
-    let url = match repository {
-        Some(url) => url,
-        None => return (None, None),
-    };
-    let cleaned = url
-        .trim()
-        .trim_end_matches(".git")
-        .replace("git+", "")
-        .replace("git://", "https://");
-    let parts: Vec<&str> = cleaned.split('/').collect();
-    if parts.len() < 2 {
-        return (None, None);
-    }
-    let repo = parts.last().unwrap_or(&"").to_string();
-    let owner = parts.get(parts.len() - 2).unwrap_or(&"").to_string();
-    if owner.is_empty() || repo.is_empty() {
-        return (None, None);
-    }
-    (Some(owner), Some(repo))
+fn resolve_python_metadata(context: &ProjectContext) -> anyhow::Result<ResolvedMetadata> {
+    let manifest_path = context
+        .manifests
+        .pyproject_toml
+        .as_ref()
+        .context("pyproject.toml missing")?;
+    let package = read_resolved_python_package(manifest_path)?.unwrap_or_default();
+    let registry = package
+        .name
+        .as_deref()
+        .and_then(|name| fetch_pypi_metadata(name).ok())
+        .unwrap_or_else(RegistryMetadata::empty);
+    Ok(ResolvedMetadata {
+        name: package.name,
+        version: registry.version.clone().or(package.version),
+        license: registry.license.clone().or(package.license),
+        repository: registry.repository.clone().or(package.repository),
+        description: registry.description.clone().or(package.description),
+        registry: Some(registry),
+    })
+}
+
+fn resolve_go_metadata(context: &ProjectContext) -> anyhow::Result<ResolvedMetadata> {
+    let manifest_path = context
+        .manifests
+        .go_mod
+        .as_ref()
+        .context("go.mod missing")?;
+    let module = read_go_mod(manifest_path)?;
+    let repository = context
+        .git
+        .as_ref()
+        .and_then(|git| git.remote.clone())
+        .or_else(|| module.module.as_deref().and_then(go_module_repository));
+    Ok(ResolvedMetadata {
+        name: module.module,
+        version: None,
+        license: None,
+        repository,
+        description: None,
+        registry: None,
+    })
+}
+
+fn resolve_deno_metadata(context: &ProjectContext) -> anyhow::Result<ResolvedMetadata> {
+    let manifest_path = context
+        .manifests
+        .jsr_manifest
+        .as_ref()
+        .context("jsr.json or deno.json missing")?;
+    let manifest = read_jsr_manifest(manifest_path)?;
+    let registry = manifest
+        .name
+        .as_deref()
+        .and_then(split_jsr_package)
+        .and_then(|(scope, name)| fetch_jsr_metadata(scope, name).ok())
+        .unwrap_or_else(RegistryMetadata::empty);
+    let repository = registry
+        .repository
+        .clone()
+        .or_else(|| context.git.as_ref().and_then(|git| git.remote.clone()));
+    Ok(ResolvedMetadata {
+        name: manifest.name,
+        version: registry.version.clone().or(manifest.version),
+        license: manifest.license,
+        repository,
+        description: registry.description.clone(),
+        registry: Some(registry),
+    })
 }
 
 pub(crate) fn repository_to_string(repo: Option<RepositoryField>) -> Option<String> {
@@ -202,7 +331,7 @@ pub(crate) fn repository_to_string(repo: Option<RepositoryField>) -> Option<Stri
 
 #[cfg(test)]
 mod tests {
-    use super::{NpmPackage, select_representative_npm_package};
+    use super::{NpmPackage, ResolvedMetadata, merge_metadata, select_representative_npm_package};
     use crate::providers::RegistryMetadata;
     use std::path::PathBuf;
 
@@ -214,6 +343,7 @@ mod tests {
             license: None,
             repository: None,
             description: None,
+            node_engine: None,
             private: false,
             registry: RegistryMetadata {
                 version: Some("1.0.0".to_string()),
@@ -254,4 +384,30 @@ mod tests {
 
         assert_eq!(selected.name, "n8n");
     }
+
+    #[test]
+    fn merged_metadata_fills_missing_fields_from_later_ecosystems() {
+        let node = ResolvedMetadata {
+            name: Some("demo-wasm".to_string()),
+            version: Some("0.2.0".to_string()),
+            license: Some(" ".to_string()),
+            ..ResolvedMetadata::default()
+        };
+        let rust = ResolvedMetadata {
+            name: Some("demo".to_string()),
+            license: Some("MIT".to_string()),
+            repository: Some("https://github.com/f4ah6o/demo".to_string()),
+            ..ResolvedMetadata::default()
+        };
+
+        let merged = merge_metadata(vec![node, rust]);
+
+        assert_eq!(merged.name.as_deref(), Some("demo-wasm"));
+        assert_eq!(merged.version.as_deref(), Some("0.2.0"));
+        assert_eq!(merged.license.as_deref(), Some("MIT"));
+        assert_eq!(
+            merged.repository.as_deref(),
+            Some("https://github.com/f4ah6o/demo")
+        );
+    }
 }
@@ -1,7 +1,8 @@
 use serde::Deserialize;
 use std::time::Duration;
 
-const USER_AGENT: &str = concat!("bdg/", env!("CARGO_PKG_VERSION"));
+/// Sent with every HTTP request `bdg` makes.
+pub(crate) const USER_AGENT: &str = concat!("bdg/", env!("CARGO_PKG_VERSION"));
 
 #[derive(Debug, Clone)]
 pub struct RegistryMetadata {
@@ -38,6 +39,24 @@ pub fn fetch_crates_metadata(crate_name: &str) -> anyhow::Result<RegistryMetadat
     parse_crates_metadata(&text)
 }
 
+pub fn fetch_pypi_metadata(package: &str) -> anyhow::Result<RegistryMetadata> {
+    let url = format!("https://pypi.org/pypi/{}/json", package);
+    let text = fetch_json_text(&url)?;
+    parse_pypi_metadata(&text)
+}
+
+pub fn fetch_jsr_metadata(scope: &str, name: &str) -> anyhow::Result<RegistryMetadata> {
+    let url = format!("https://api.jsr.io/scopes/{}/packages/{}", scope, name);
+    let text = fetch_json_text(&url)?;
+    parse_jsr_metadata(&text)
+}
+
+pub fn fetch_mooncakes_metadata(module: &str) -> anyhow::Result<RegistryMetadata> {
+    let url = format!("https://mooncakes.io/api/v0/modules/{}", module);
+    let text = fetch_json_text(&url)?;
+    parse_mooncakes_metadata(&text)
+}
+
 fn parse_npm_metadata(text: &str) -> anyhow::Result<RegistryMetadata> {
     let payload: NpmPackument = serde_json::from_str(text)?;
     let version = payload.dist_tags.get("latest").cloned().or(payload.version);
@@ -63,6 +82,62 @@ fn parse_crates_metadata(text: &str) -> anyhow::Result<RegistryMetadata> {
     })
 }
 
+fn parse_pypi_metadata(text: &str) -> anyhow::Result<RegistryMetadata> {
+    let payload: PypiResponse = serde_json::from_str(text)?;
+    let info = payload.info;
+    let urls = info.project_urls.unwrap_or_default();
+    let repository = ["Repository", "Source", "Source Code", "Code", "GitHub"]
+        .iter()
+        .find_map(|key| {
+            urls.iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, url)| url.clone())
+        });
+    let homepage = info
+        .home_page
+        .filter(|value| !value.is_empty())
+        .or_else(|| {
+            urls.iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("Homepage"))
+                .map(|(_, url)| url.clone())
+        });
+    Ok(RegistryMetadata {
+        version: Some(info.version),
+        license: info.license.filter(|value| !value.is_empty()),
+        repository,
+        description: info.summary.filter(|value| !value.is_empty()),
+        downloads: None,
+        homepage,
+    })
+}
+
+fn parse_jsr_metadata(text: &str) -> anyhow::Result<RegistryMetadata> {
+    let payload: JsrPackage = serde_json::from_str(text)?;
+    let repository = payload
+        .github_repository
+        .map(|repo| format!("https://github.com/{}/{}", repo.owner, repo.name));
+    Ok(RegistryMetadata {
+        version: payload.latest_version,
+        license: None,
+        repository,
+        description: payload.description.filter(|value| !value.is_empty()),
+        downloads: None,
+        homepage: None,
+    })
+}
+
+fn parse_mooncakes_metadata(text: &str) -> anyhow::Result<RegistryMetadata> {
+    let payload: MooncakesModule = serde_json::from_str(text)?;
+    Ok(RegistryMetadata {
+        version: payload.latest_version.or(payload.version),
+        license: payload.license.filter(|value| !value.is_empty()),
+        repository: payload.repository.filter(|value| !value.is_empty()),
+        description: payload.description.filter(|value| !value.is_empty()),
+        downloads: None,
+        homepage: None,
+    })
+}
+
 fn fetch_json_text(url: &str) -> anyhow::Result<String> {
     let config = ureq::Agent::config_builder()
         .timeout_connect(Some(Duration::from_secs(3)))
@@ -109,9 +184,49 @@ struct CrateData {
     homepage: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct PypiResponse {
+    info: PypiInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct PypiInfo {
+    version: String,
+    license: Option<String>,
+    summary: Option<String>,
+    home_page: Option<String>,
+    project_urls: Option<std::collections::HashMap<String, String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsrPackage {
+    latest_version: Option<String>,
+    description: Option<String>,
+    github_repository: Option<JsrGithubRepository>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsrGithubRepository {
+    owner: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MooncakesModule {
+    latest_version: Option<String>,
+    version: Option<String>,
+    license: Option<String>,
+    repository: Option<String>,
+    description: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{USER_AGENT, parse_crates_metadata, parse_npm_metadata};
+    use super::{
+        USER_AGENT, parse_crates_metadata, parse_jsr_metadata, parse_mooncakes_metadata,
+        parse_npm_metadata, parse_pypi_metadata,
+    };
 
     #[test]
     fn user_agent_includes_crate_version() {
@@ -188,4 +303,80 @@ mod tests {
             Some("https://example.com/crate")
         );
     }
+
+    #[test]
+    fn parses_pypi_metadata() {
+        let metadata = parse_pypi_metadata(
+            r#"{
+                "info": {
+                    "version": "3.1.0",
+                    "license": "BSD-3-Clause",
+                    "summary": "demo distribution",
+                    "home_page": "",
+                    "project_urls": {
+                        "Homepage": "https://example.com/dist",
+                        "Source": "https://github.com/example/dist"
+                    }
+                }
+            }"#,
+        )
+        .expect("metadata");
+
+        assert_eq!(metadata.version.as_deref(), Some("3.1.0"));
+        assert_eq!(metadata.license.as_deref(), Some("BSD-3-Clause"));
+        assert_eq!(
+            metadata.repository.as_deref(),
+            Some("https://github.com/example/dist")
+        );
+        assert_eq!(metadata.description.as_deref(), Some("demo distribution"));
+        assert_eq!(
+            metadata.homepage.as_deref(),
+            Some("https://example.com/dist")
+        );
+    }
+
+    #[test]
+    fn parses_jsr_package_metadata() {
+        let metadata = parse_jsr_metadata(
+            r#"{
+                "scope": "std",
+                "name": "path",
+                "description": "path utilities",
+                "latestVersion": "1.0.8",
+                "githubRepository": { "owner": "denoland", "name": "std" },
+                "score": 100
+            }"#,
+        )
+        .expect("metadata");
+
+        assert_eq!(metadata.version.as_deref(), Some("1.0.8"));
+        assert_eq!(metadata.description.as_deref(), Some("path utilities"));
+        assert_eq!(
+            metadata.repository.as_deref(),
+            Some("https://github.com/denoland/std")
+        );
+    }
+
+    #[test]
+    fn parses_mooncakes_module_metadata() {
+        let metadata = parse_mooncakes_metadata(
+            r#"{
+                "name": "moonbitlang/x",
+                "version": "0.4.0",
+                "latest_version": "0.4.2",
+                "license": "Apache-2.0",
+                "repository": "https://github.com/moonbitlang/x",
+                "description": ""
+            }"#,
+        )
+        .expect("metadata");
+
+        assert_eq!(metadata.version.as_deref(), Some("0.4.2"));
+        assert_eq!(metadata.license.as_deref(), Some("Apache-2.0"));
+        assert_eq!(
+            metadata.repository.as_deref(),
+            Some("https://github.com/moonbitlang/x")
+        );
+        assert_eq!(metadata.description, None);
+    }
 }
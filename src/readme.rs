@@ -1,8 +1,203 @@
+use crate::badges::{Badge, render_asciidoc_badge, render_html_badge, render_rst_badge};
+use crate::config::{Config, RenderConfig, RenderMode};
+use crate::core::{Ecosystem, ProjectContext};
+use crate::manifest::read_declared_readme;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 pub const BDG_BEGIN: &str = "<!-- bdg:begin -->";
 pub const BDG_END: &str = "<!-- bdg:end -->";
+pub const HTML_CENTER_BEGIN: &str = "<p align=\"center\">";
+pub const HTML_CENTER_END: &str = "</p>";
+
+/// Markup of a README: decides the marker comments, how badges are written,
+/// which lines delimit literal blocks, and where a new block goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadmeFormat {
+    Markdown,
+    /// reStructuredText.
+    Rst,
+    AsciiDoc,
+}
+
+impl ReadmeFormat {
+    pub const ALL: [Self; 3] = [Self::Markdown, Self::Rst, Self::AsciiDoc];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Markdown => "markdown",
+            Self::Rst => "rst",
+            Self::AsciiDoc => "asciidoc",
+        }
+    }
+
+    /// Format named by the file extension; Markdown unless `.rst` or `.adoc`.
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("rst" | "rest") => Self::Rst,
+            Some("adoc" | "asciidoc" | "asc") => Self::AsciiDoc,
+            _ => Self::Markdown,
+        }
+    }
+
+    /// Format whose begin marker appears in `content` outside literal blocks.
+    /// reStructuredText and AsciiDoc markers win over Markdown ones, which
+    /// their documents can only contain as examples.
+    pub fn from_markers(content: &str) -> Option<Self> {
+        let (newline, _) = detect_newline(content);
+        let lines = split_lines(content, newline);
+        [Self::Rst, Self::AsciiDoc, Self::Markdown]
+            .into_iter()
+            .find(|format| !collect_marker_indices(&lines, *format).0.is_empty())
+    }
+
+    /// Format of the README at `path` holding `content`: the one its markers
+    /// use, else the one its extension names.
+    pub fn of(path: &Path, content: &str) -> Self {
+        Self::from_markers(content).unwrap_or_else(|| Self::from_path(path))
+    }
+
+    pub const fn begin_marker(self) -> &'static str {
+        match self {
+            Self::Markdown => BDG_BEGIN,
+            Self::Rst => ".. bdg:begin",
+            Self::AsciiDoc => "// bdg:begin",
+        }
+    }
+
+    pub const fn end_marker(self) -> &'static str {
+        match self {
+            Self::Markdown => BDG_END,
+            Self::Rst => ".. bdg:end",
+            Self::AsciiDoc => "// bdg:end",
+        }
+    }
+
+    /// One badge as written in this format. `mode` picks Markdown or HTML for
+    /// Markdown READMEs; the other formats have a single image syntax.
+    pub fn render_badge(self, badge: &Badge, mode: RenderMode) -> String {
+        self.render_parts(
+            &badge.label,
+            &badge.image_url,
+            badge.link_url.as_deref(),
+            mode,
+        )
+    }
+
+    pub fn render_parts(
+        self,
+        label: &str,
+        image_url: &str,
+        link_url: Option<&str>,
+        mode: RenderMode,
+    ) -> String {
+        match (self, mode) {
+            (Self::Markdown, RenderMode::Markdown) => match link_url {
+                Some(link) => format!("[![{label}]({image_url})]({link})"),
+                None => format!("![{label}]({image_url})"),
+            },
+            // Theme variants need `[style]`, which `sync` and `add` apply themselves.
+            (Self::Markdown, RenderMode::Html | RenderMode::Picture) => {
+                render_html_badge(label, image_url, link_url)
+            }
+            (Self::Rst, _) => render_rst_badge(label, image_url, link_url),
+            (Self::AsciiDoc, _) => render_asciidoc_badge(label, image_url, link_url),
+        }
+    }
+
+    /// Line before which a new marker pair goes: below the document title.
+    fn block_position(self, lines: &[String]) -> usize {
+        let mut literal = LiteralBlocks::new(self);
+        let outside = lines
+            .iter()
+            .map(|line| !literal.skip(line))
+            .collect::<Vec<_>>();
+        let found = match self {
+            Self::Markdown => (0..lines.len())
+                .find(|&idx| outside[idx] && lines[idx].starts_with("# "))
+                .map(|idx| idx + 1),
+            // A title is a line underlined with punctuation at least as long.
+            Self::Rst => (0..lines.len().saturating_sub(1))
+                .find(|&idx| {
+                    let title = lines[idx].trim_end();
+                    !title.is_empty()
+                        && !title.starts_with(char::is_whitespace)
+                        && !title.starts_with("..")
+                        && !is_rst_adornment(title)
+                        && is_rst_adornment(&lines[idx + 1])
+                        && lines[idx + 1].trim_end().chars().count() >= title.chars().count()
+                })
+                .map(|idx| idx + 2),
+            // The document header runs from the `= Title` line to the first
+            // blank line; images inside it would be read as header lines.
+            Self::AsciiDoc => (0..lines.len())
+                .find(|&idx| outside[idx] && lines[idx].starts_with("= "))
+                .map(|title| {
+                    (title..lines.len())
+                        .find(|&idx| lines[idx].trim().is_empty())
+                        .map_or(lines.len(), |blank| blank + 1)
+                }),
+        };
+        found.unwrap_or(0)
+    }
+
+    /// Whether the block needs blank lines around it to stay separate from
+    /// neighbouring paragraphs.
+    const fn separates_block(self) -> bool {
+        matches!(self, Self::Rst | Self::AsciiDoc)
+    }
+}
+
+fn is_rst_adornment(line: &str) -> bool {
+    let line = line.trim_end();
+    let mut chars = line.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    line.len() >= 3 && first.is_ascii_punctuation() && chars.all(|ch| ch == first)
+}
+
+/// Tracks literal blocks line by line, so that markers and badges shown as
+/// examples inside them are left alone.
+pub(crate) struct LiteralBlocks {
+    format: ReadmeFormat,
+    open: Option<String>,
+}
+
+impl LiteralBlocks {
+    pub(crate) fn new(format: ReadmeFormat) -> Self {
+        Self { format, open: None }
+    }
+
+    /// Whether `line` delimits or sits inside a literal block. Indented
+    /// reStructuredText literal blocks never match a marker, so only fences
+    /// are tracked there.
+    pub(crate) fn skip(&mut self, line: &str) -> bool {
+        match (self.delimiter(line), &self.open) {
+            (Some(delimiter), None) => self.open = Some(delimiter),
+            (Some(delimiter), Some(open)) if delimiter == *open => self.open = None,
+            (_, open) => return open.is_some(),
+        }
+        true
+    }
+
+    fn delimiter(&self, line: &str) -> Option<String> {
+        if line.trim_start().starts_with("```") {
+            return Some("```".to_string());
+        }
+        let line = line.trim_end();
+        let first = line.chars().next()?;
+        (self.format == ReadmeFormat::AsciiDoc
+            && line.len() >= 4
+            && matches!(first, '-' | '.' | '/' | '+')
+            && line.chars().all(|ch| ch == first))
+        .then(|| line.to_string())
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MarkerState {
@@ -20,7 +215,7 @@ impl MarkerState {
 pub fn marker_state(content: &str) -> MarkerState {
     let (newline, _) = detect_newline(content);
     let lines = split_lines(content, newline);
-    let (begin_indices, end_indices) = collect_marker_indices(&lines);
+    let (begin_indices, end_indices) = marker_indices(content, &lines);
     MarkerState {
         begin_count: begin_indices.len(),
         end_count: end_indices.len(),
@@ -32,9 +227,21 @@ pub fn marker_state(content: &str) -> MarkerState {
 
 pub fn resolve_readme(root: &Path, prefer_moonbit: bool) -> PathBuf {
     let candidates = if prefer_moonbit {
-        vec!["README.mbt.md", "README.md", "docs/README.md"]
+        vec![
+            "README.mbt.md",
+            "README.md",
+            "README.rst",
+            "README.adoc",
+            "docs/README.md",
+        ]
     } else {
-        vec!["README.md", "README.mbt.md", "docs/README.md"]
+        vec![
+            "README.md",
+            "README.mbt.md",
+            "README.rst",
+            "README.adoc",
+            "docs/README.md",
+        ]
     };
     for candidate in &candidates {
         let path = root.join(candidate);
@@ -45,6 +252,102 @@ pub fn resolve_readme(root: &Path, prefer_moonbit: bool) -> PathBuf {
     root.join(candidates[0])
 }
 
+/// Where the README chosen by `select_readme` came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadmeSource {
+    /// The global `--readme PATH` option.
+    Cli,
+    /// The `readme` key in `.bdg.toml`.
+    Config,
+    /// A `readme` field declared by a detected manifest.
+    Manifest,
+    /// The conventional file names tried by `resolve_readme`.
+    Default,
+}
+
+impl ReadmeSource {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Cli => "cli",
+            Self::Config => "config",
+            Self::Manifest => "manifest",
+            Self::Default => "default",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReadmeSelection {
+    pub path: PathBuf,
+    pub source: ReadmeSource,
+    /// Manifest that declared the README when `source` is `Manifest`.
+    pub manifest: Option<PathBuf>,
+}
+
+/// Chooses the README to manage: `--readme`, then the `.bdg.toml` `readme` key,
+/// then the first existing file declared by a manifest (Cargo.toml
+/// `package.readme`, package.json `readme`, moon.mod.json `readme`) in ecosystem
+/// order, and finally `resolve_readme`. Manifests are only consulted when
+/// `current_dir` is inside their package, so a workspace root does not pick up a
+/// member's README.
+pub fn select_readme(
+    current_dir: &Path,
+    context: &ProjectContext,
+    config: &Config,
+    readme_override: Option<&Path>,
+) -> ReadmeSelection {
+    if let Some(path) = readme_override {
+        return ReadmeSelection {
+            path: path.to_path_buf(),
+            source: ReadmeSource::Cli,
+            manifest: None,
+        };
+    }
+    if let Some(path) = &config.readme {
+        return ReadmeSelection {
+            path: path.clone(),
+            source: ReadmeSource::Config,
+            manifest: None,
+        };
+    }
+    for ecosystem in &context.ecosystems {
+        if !matches!(
+            ecosystem,
+            Ecosystem::Rust | Ecosystem::Node | Ecosystem::MoonBit
+        ) {
+            continue;
+        }
+        let Some(manifest) = context
+            .manifest_path(*ecosystem)
+            .filter(|manifest| manifest_encloses(manifest, current_dir))
+        else {
+            continue;
+        };
+        if let Some(path) = read_declared_readme(manifest).filter(|path| path.is_file()) {
+            return ReadmeSelection {
+                path,
+                source: ReadmeSource::Manifest,
+                manifest: Some(manifest.to_path_buf()),
+            };
+        }
+    }
+    ReadmeSelection {
+        path: resolve_readme(&context.root, context.has_moonbit()),
+        source: ReadmeSource::Default,
+        manifest: None,
+    }
+}
+
+fn manifest_encloses(manifest: &Path, dir: &Path) -> bool {
+    let Some(package_dir) = manifest.parent() else {
+        return false;
+    };
+    match (package_dir.canonicalize(), dir.canonicalize()) {
+        (Ok(package_dir), Ok(dir)) => dir.starts_with(package_dir),
+        _ => dir.starts_with(package_dir),
+    }
+}
+
 pub fn ensure_marker_block(readme_path: &Path) -> anyhow::Result<String> {
     let content = match fs::read_to_string(readme_path) {
         Ok(content) => content,
@@ -53,43 +356,39 @@ pub fn ensure_marker_block(readme_path: &Path) -> anyhow::Result<String> {
     };
     let (newline, has_trailing_newline) = detect_newline(&content);
     let lines = split_lines(&content, newline);
-    let (begin_indices, end_indices) = collect_marker_indices(&lines);
+    let (begin_indices, end_indices) = marker_indices(&content, &lines);
     if begin_indices.len() == 1 && end_indices.len() == 1 {
         return Ok(content);
     }
+    let format = ReadmeFormat::from_path(readme_path);
     let mut lines = lines
         .into_iter()
         .map(|line| line.to_string())
         .collect::<Vec<_>>();
-    let mut inserted = false;
-    let mut in_code_fence = false;
-    for idx in 0..lines.len() {
-        if is_code_fence(&lines[idx]) {
-            in_code_fence = !in_code_fence;
-            continue;
-        }
-        if in_code_fence {
-            continue;
-        }
-        if lines[idx].starts_with("# ") {
-            let insert_at = idx + 1;
-            lines.insert(insert_at, BDG_BEGIN.to_string());
-            lines.insert(insert_at + 1, BDG_END.to_string());
-            inserted = true;
-            break;
-        }
+    let position = format.block_position(&lines);
+    let mut block = Vec::new();
+    if format.separates_block() && position > 0 && !lines[position - 1].trim().is_empty() {
+        block.push(String::new());
     }
-    if !inserted {
-        lines.insert(0, BDG_END.to_string());
-        lines.insert(0, BDG_BEGIN.to_string());
+    block.push(format.begin_marker().to_string());
+    block.push(format.end_marker().to_string());
+    if format.separates_block()
+        && lines
+            .get(position)
+            .is_some_and(|line| !line.trim().is_empty())
+    {
+        block.push(String::new());
     }
+    lines.splice(position..position, block);
     Ok(join_lines(lines, newline, has_trailing_newline))
 }
 
+/// Replaces the block with `badges`. A badge spanning several lines, like a
+/// reStructuredText image directive, separates them with `\n`.
 pub fn rewrite_marker_block(content: &str, badges: &[String]) -> anyhow::Result<String> {
     let (newline, has_trailing_newline) = detect_newline(content);
     let lines = split_lines(content, newline);
-    let (begin_indices, end_indices) = collect_marker_indices(&lines);
+    let (begin_indices, end_indices) = marker_indices(content, &lines);
     if begin_indices.len() != 1 || end_indices.len() != 1 {
         anyhow::bail!("marker block missing or duplicated");
     }
@@ -102,7 +401,7 @@ pub fn rewrite_marker_block(content: &str, badges: &[String]) -> anyhow::Result<
     let mut output: Vec<String> = Vec::new();
     output.extend(lines[..=begin].iter().map(|line| (*line).to_string()));
     for badge in badges {
-        output.push(badge.to_string());
+        output.extend(badge.split('\n').map(str::to_string));
     }
     output.extend(lines[end..].iter().map(|line| (*line).to_string()));
     Ok(join_lines(output, newline, has_trailing_newline))
@@ -115,10 +414,13 @@ pub fn write_readme_atomic(readme_path: &Path, content: &str) -> anyhow::Result<
     Ok(())
 }
 
+/// Badges of the managed block, one entry per badge. Blank lines and layout
+/// containers are dropped, and a reStructuredText directive is joined with its
+/// option lines by `\n`.
 pub fn extract_managed_block(content: &str) -> Vec<String> {
     let (newline, _) = detect_newline(content);
     let lines = split_lines(content, newline);
-    let (begin_indices, end_indices) = collect_marker_indices(&lines);
+    let (begin_indices, end_indices) = marker_indices(content, &lines);
     if begin_indices.len() != 1 || end_indices.len() != 1 {
         return Vec::new();
     }
@@ -127,22 +429,75 @@ pub fn extract_managed_block(content: &str) -> Vec<String> {
     if begin >= end {
         return Vec::new();
     }
-    lines[begin + 1..end]
-        .iter()
-        .filter_map(|line| {
-            if line.trim().is_empty() {
-                None
-            } else {
-                Some((*line).to_string())
-            }
-        })
+    group_badge_lines(&lines[begin + 1..end])
+        .into_iter()
+        .filter(|entry| !entry.trim().is_empty() && !is_block_container(entry))
         .collect()
 }
 
+/// Joins each reStructuredText directive (`.. image::`) with the indented
+/// option lines that follow it; every other line stands alone.
+pub fn group_badge_lines<S: AsRef<str>>(lines: &[S]) -> Vec<String> {
+    let mut entries: Vec<String> = Vec::new();
+    let mut in_directive = false;
+    for line in lines {
+        let line = line.as_ref();
+        if in_directive && line.starts_with(char::is_whitespace) && !line.trim().is_empty() {
+            if let Some(entry) = entries.last_mut() {
+                entry.push('\n');
+                entry.push_str(line);
+            }
+            continue;
+        }
+        in_directive = line.starts_with(".. ");
+        entries.push(line.to_string());
+    }
+    entries
+}
+
+/// Opening or closing tag of an HTML element that lays out the badges, such
+/// as `<p align="center">` or `</div>`, on a line of its own.
+pub fn is_block_container(line: &str) -> bool {
+    let trimmed = line.trim();
+    let Some(tag) = trimmed
+        .strip_prefix('<')
+        .and_then(|rest| rest.strip_suffix('>'))
+    else {
+        return false;
+    };
+    let name = tag
+        .trim_start_matches('/')
+        .split(|ch: char| ch.is_whitespace())
+        .next()
+        .unwrap_or("");
+    matches!(name.to_ascii_lowercase().as_str(), "p" | "div") && !tag.contains('<')
+}
+
+/// Badge lines for the managed block, wrapped in a centered container when
+/// `[render]` asks for centered HTML in a Markdown README.
+pub fn badge_block_lines(
+    badges: Vec<String>,
+    format: ReadmeFormat,
+    render: &RenderConfig,
+) -> Vec<String> {
+    if format != ReadmeFormat::Markdown
+        || !render.mode.is_html()
+        || !render.center
+        || badges.is_empty()
+    {
+        return badges;
+    }
+    let mut lines = Vec::with_capacity(badges.len() + 2);
+    lines.push(HTML_CENTER_BEGIN.to_string());
+    lines.extend(badges);
+    lines.push(HTML_CENTER_END.to_string());
+    lines
+}
+
 pub fn extract_marker_block_lines(content: &str) -> anyhow::Result<Vec<String>> {
     let (newline, _) = detect_newline(content);
     let lines = split_lines(content, newline);
-    let (begin_indices, end_indices) = collect_marker_indices(&lines);
+    let (begin_indices, end_indices) = marker_indices(content, &lines);
     if begin_indices.len() != 1 || end_indices.len() != 1 {
         anyhow::bail!("marker block missing or duplicated");
     }
@@ -157,10 +512,12 @@ pub fn extract_marker_block_lines(content: &str) -> anyhow::Result<Vec<String>>
         .collect())
 }
 
+/// Replaces the block with `lines` as they are; entries joined by `\n` are
+/// split back into lines.
 pub fn rewrite_marker_block_lines(content: &str, lines: &[String]) -> anyhow::Result<String> {
     let (newline, has_trailing_newline) = detect_newline(content);
     let content_lines = split_lines(content, newline);
-    let (begin_indices, end_indices) = collect_marker_indices(&content_lines);
+    let (begin_indices, end_indices) = marker_indices(content, &content_lines);
     if begin_indices.len() != 1 || end_indices.len() != 1 {
         anyhow::bail!("marker block missing or duplicated");
     }
@@ -176,7 +533,11 @@ pub fn rewrite_marker_block_lines(content: &str, lines: &[String]) -> anyhow::Re
             .iter()
             .map(|line| (*line).to_string()),
     );
-    output.extend(lines.iter().cloned());
+    output.extend(
+        lines
+            .iter()
+            .flat_map(|line| line.split('\n').map(str::to_string)),
+    );
     output.extend(content_lines[end..].iter().map(|line| (*line).to_string()));
     Ok(join_lines(output, newline, has_trailing_newline))
 }
@@ -184,7 +545,7 @@ pub fn rewrite_marker_block_lines(content: &str, lines: &[String]) -> anyhow::Re
 pub fn remove_marker_block(content: &str) -> anyhow::Result<String> {
     let (newline, has_trailing_newline) = detect_newline(content);
     let content_lines = split_lines(content, newline);
-    let (begin_indices, end_indices) = collect_marker_indices(&content_lines);
+    let (begin_indices, end_indices) = marker_indices(content, &content_lines);
     if begin_indices.len() != 1 || end_indices.len() != 1 {
         anyhow::bail!("marker block missing or duplicated");
     }
@@ -247,32 +608,30 @@ pub fn readme_newline_info(content: &str) -> (String, bool) {
 pub fn marker_count(content: &str) -> usize {
     let (newline, _) = detect_newline(content);
     let lines = split_lines(content, newline);
-    let (begin_indices, _) = collect_marker_indices(&lines);
+    let (begin_indices, _) = marker_indices(content, &lines);
     begin_indices.len()
 }
 
-fn collect_marker_indices(lines: &[&str]) -> (Vec<usize>, Vec<usize>) {
+/// Marker lines of the format whose markers `content` uses.
+fn marker_indices(content: &str, lines: &[&str]) -> (Vec<usize>, Vec<usize>) {
+    let format = ReadmeFormat::from_markers(content).unwrap_or(ReadmeFormat::Markdown);
+    collect_marker_indices(lines, format)
+}
+
+fn collect_marker_indices(lines: &[&str], format: ReadmeFormat) -> (Vec<usize>, Vec<usize>) {
     let mut begin_indices = Vec::new();
     let mut end_indices = Vec::new();
-    let mut in_code_fence = false;
+    let mut literal = LiteralBlocks::new(format);
     for (idx, line) in lines.iter().enumerate() {
-        if is_code_fence(line) {
-            in_code_fence = !in_code_fence;
-            continue;
-        }
-        if in_code_fence {
+        if literal.skip(line) {
             continue;
         }
-        if *line == BDG_BEGIN {
+        if *line == format.begin_marker() {
             begin_indices.push(idx);
         }
-        if *line == BDG_END {
+        if *line == format.end_marker() {
             end_indices.push(idx);
         }
     }
     (begin_indices, end_indices)
 }
-
-fn is_code_fence(line: &str) -> bool {
-    line.trim_start().starts_with("```")
-}
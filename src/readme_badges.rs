@@ -1,3 +1,5 @@
+use crate::badge_service::BadgeHosts;
+use crate::forge::ForgeKind;
 use serde::Serialize;
 
 #[derive(Debug, Serialize, Clone)]
@@ -13,13 +15,13 @@ pub struct ParsedBadge {
 }
 
 pub fn parse_badge_line(line: &str) -> ParsedBadge {
-    if let Some((label, image, link)) = parse_linked_image(line) {
-        return build_badge(line, label, image, Some(link));
-    }
-    if let Some((label, image)) = parse_image(line) {
-        return build_badge(line, label, image, None);
-    }
-    ParsedBadge {
+    parse_badge_line_with(line, &BadgeHosts::default())
+}
+
+/// Like [`parse_badge_line`], also recognizing badges rendered by the shields
+/// and badgen instances in `hosts`.
+pub fn parse_badge_line_with(line: &str, hosts: &BadgeHosts) -> ParsedBadge {
+    parse_badge_line_optional_with(line, hosts).unwrap_or_else(|| ParsedBadge {
         id: format!("unknown:{}", hash_line(line)),
         kind: "unknown".to_string(),
         label: String::new(),
@@ -28,21 +30,38 @@ pub fn parse_badge_line(line: &str) -> ParsedBadge {
         source: "readme".to_string(),
         meta: None,
         raw: line.to_string(),
-    }
+    })
 }
 
 pub fn parse_badge_line_optional(line: &str) -> Option<ParsedBadge> {
+    parse_badge_line_optional_with(line, &BadgeHosts::default())
+}
+
+pub fn parse_badge_line_optional_with(line: &str, hosts: &BadgeHosts) -> Option<ParsedBadge> {
+    if let Some((label, image, link)) = parse_html_badge(line) {
+        return Some(build_badge(line, label, image, link, hosts));
+    }
     if let Some((label, image, link)) = parse_linked_image(line) {
-        return Some(build_badge(line, label, image, Some(link)));
+        return Some(build_badge(line, label, image, Some(link), hosts));
     }
     if let Some((label, image)) = parse_image(line) {
-        return Some(build_badge(line, label, image, None));
+        return Some(build_badge(line, label, image, None, hosts));
+    }
+    if let Some((label, image, link)) = parse_rst_image(line).or_else(|| parse_asciidoc_image(line))
+    {
+        return Some(build_badge(line, label, image, link, hosts));
     }
     None
 }
 
-fn build_badge(raw: &str, label: String, image: String, link: Option<String>) -> ParsedBadge {
-    let (kind, id, meta) = infer_kind(&image, raw);
+fn build_badge(
+    raw: &str,
+    label: String,
+    image: String,
+    link: Option<String>,
+    hosts: &BadgeHosts,
+) -> ParsedBadge {
+    let (kind, id, meta) = infer_kind(&hosts.canonical(image.trim()), raw);
     ParsedBadge {
         id,
         kind,
@@ -94,6 +113,239 @@ fn parse_image(line: &str) -> Option<(String, String)> {
     Some((label, image.trim().to_string()))
 }
 
+/// `<a href="…"><img alt="…" src="…"></a>` or a bare `<img>`, as written by the
+/// HTML render mode. Attributes may come in any order and use either quote.
+/// A `<picture>` in place of the `<img>` is read through its `<img>` fallback,
+/// so theme variants share the badge's id.
+fn parse_html_badge(line: &str) -> Option<(String, String, Option<String>)> {
+    let trimmed = line.trim();
+    let (link, img) = if let Some(rest) = strip_tag_prefix(trimmed, "a") {
+        let end = rest.find('>')?;
+        let link = html_attr(&rest[..end], "href")?;
+        let inner = rest[end + 1..].trim();
+        let inner = inner
+            .strip_suffix("</a>")
+            .or_else(|| inner.strip_suffix("</A>"))?;
+        (Some(link), inner.trim())
+    } else {
+        (None, trimmed)
+    };
+    let img = picture_fallback(img)?;
+    let attrs = strip_tag_prefix(img, "img")?;
+    let attrs = attrs.strip_suffix('>')?.trim_end_matches('/');
+    if attrs.contains('>') {
+        return None;
+    }
+    let image = html_attr(attrs, "src").filter(|src| !src.is_empty())?;
+    let label = html_attr(attrs, "alt").unwrap_or_default();
+    Some((label, image, link))
+}
+
+/// A reStructuredText `.. image::` directive, or the `.. |name| image::`
+/// substitution form, with its `:target:` and `:alt:` options on the
+/// following lines of `entry`.
+fn parse_rst_image(entry: &str) -> Option<(String, String, Option<String>)> {
+    let mut lines = entry.lines();
+    let directive = lines.next()?.trim().strip_prefix("..")?.trim_start();
+    let (name, directive) = match directive.strip_prefix('|') {
+        Some(rest) => {
+            let (name, rest) = rest.split_once('|')?;
+            (Some(name.trim()), rest.trim_start())
+        }
+        None => (None, directive),
+    };
+    let image = directive.strip_prefix("image::")?.trim();
+    if image.is_empty() {
+        return None;
+    }
+    let mut label = name.unwrap_or_default().to_string();
+    let mut link = None;
+    for option in lines {
+        let option = option.trim();
+        if let Some(target) = option.strip_prefix(":target:") {
+            link = Some(target.trim().to_string());
+        } else if let Some(alt) = option.strip_prefix(":alt:") {
+            label = alt.trim().to_string();
+        }
+    }
+    Some((label, image.to_string(), link))
+}
+
+/// An AsciiDoc `image:url[alt,link=…]` macro, inline or block (`image::`).
+fn parse_asciidoc_image(line: &str) -> Option<(String, String, Option<String>)> {
+    let trimmed = line.trim();
+    let rest = trimmed.strip_prefix("image:")?;
+    let rest = rest.strip_prefix(':').unwrap_or(rest);
+    let (image, attrs) = rest.split_once('[')?;
+    let attrs = attrs.strip_suffix(']')?;
+    if image.is_empty() || image.contains(char::is_whitespace) {
+        return None;
+    }
+    let mut label = String::new();
+    let mut link = None;
+    for (idx, attr) in split_asciidoc_attrs(attrs).into_iter().enumerate() {
+        let named = (!attr.starts_with('"'))
+            .then(|| attr.split_once('='))
+            .flatten()
+            .filter(|(name, _)| {
+                !name.is_empty() && name.chars().all(|ch| ch.is_alphanumeric() || ch == '-')
+            });
+        match named {
+            Some(("link", value)) => link = Some(unquote_asciidoc(value)),
+            Some(("alt", value)) => label = unquote_asciidoc(value),
+            Some(_) => {}
+            None if idx == 0 => label = unquote_asciidoc(&attr),
+            None => {}
+        }
+    }
+    Some((label, image.to_string(), link))
+}
+
+/// Splits an AsciiDoc attribute list on commas outside double quotes.
+fn split_asciidoc_attrs(attrs: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = attrs.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                current.push(ch);
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            '"' => {
+                quoted = !quoted;
+                current.push(ch);
+            }
+            ',' if !quoted => parts.push(std::mem::take(&mut current).trim().to_string()),
+            _ => current.push(ch),
+        }
+    }
+    parts.push(current.trim().to_string());
+    parts
+}
+
+fn unquote_asciidoc(value: &str) -> String {
+    let value = value.trim();
+    let value = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value);
+    value.replace("\\]", "]").replace("\\\"", "\"")
+}
+
+/// The `<img>` closing a `<picture>` whose other children are `<source>`
+/// elements, or `element` itself when it is not a picture.
+fn picture_fallback(element: &str) -> Option<&str> {
+    let Some(rest) = strip_tag_prefix(element, "picture") else {
+        return Some(element);
+    };
+    let body = rest[rest.find('>')? + 1..].trim_end();
+    let close = body.len().checked_sub("</picture>".len())?;
+    if !body.get(close..)?.eq_ignore_ascii_case("</picture>") {
+        return None;
+    }
+    let body = body[..close].trim();
+    let (sources, img) = body.split_at(body.to_ascii_lowercase().rfind("<img")?);
+    sources
+        .split_inclusive('>')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .all(|tag| strip_tag_prefix(tag, "source").is_some_and(|attrs| attrs.ends_with('>')))
+        .then_some(img.trim())
+}
+
+/// Attributes following `<name`, when `tag` opens a `name` element.
+fn strip_tag_prefix<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let rest = tag.strip_prefix('<')?;
+    let (head, rest) = rest.split_at_checked(name.len())?;
+    (head.eq_ignore_ascii_case(name)
+        && rest.starts_with(|ch: char| ch == '>' || ch.is_whitespace()))
+    .then_some(rest)
+}
+
+/// Unescaped value of attribute `name` in the attribute list `attrs`.
+fn html_attr(attrs: &str, name: &str) -> Option<String> {
+    let mut rest = attrs;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            return None;
+        }
+        let key_end = rest
+            .find(|ch: char| ch == '=' || ch.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = &rest[..key_end];
+        rest = rest[key_end..].trim_start();
+        let Some(value_part) = rest.strip_prefix('=') else {
+            continue;
+        };
+        let value_part = value_part.trim_start();
+        let (value, remainder) = match value_part.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let end = value_part[1..].find(quote)? + 1;
+                (&value_part[1..end], &value_part[end + 1..])
+            }
+            _ => {
+                let end = value_part
+                    .find(char::is_whitespace)
+                    .unwrap_or(value_part.len());
+                (&value_part[..end], &value_part[end..])
+            }
+        };
+        if key.eq_ignore_ascii_case(name) {
+            return Some(unescape_html(value));
+        }
+        rest = remainder;
+    }
+}
+
+fn unescape_html(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Parsed kinds that render a CI status, so `remove --kind ci` can target all of
+/// them at once.
+pub fn is_ci_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "github_actions"
+            | "forgejo_actions"
+            | "gitlab_pipeline"
+            | "woodpecker"
+            | "circleci"
+            | "travis"
+            | "azure_pipelines"
+            | "buildkite"
+            | "jenkins"
+    )
+}
+
+/// Parsed kinds that render test coverage, so `remove --kind coverage` covers
+/// every provider.
+pub fn is_coverage_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "coverage" | "gitlab_coverage" | "coveralls" | "codacy" | "sonarcloud"
+    )
+}
+
+/// Parsed kinds of the opt-in security badges, so `remove --kind security`
+/// can target all of them at once.
+pub fn is_security_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "ossf_scorecard" | "best_practices" | "security_policy"
+    )
+}
+
 fn infer_kind(image: &str, raw: &str) -> (String, String, Option<serde_json::Value>) {
     let image_trimmed = image.trim();
     if !is_http_url(image_trimmed) {
@@ -109,19 +361,63 @@ fn infer_kind(image: &str, raw: &str) -> (String, String, Option<serde_json::Val
             .nth(1)
             .and_then(|rest| rest.split('/').next())
             .map(|s| s.to_string());
+        let forgejo =
+            url_host(image_trimmed).and_then(ForgeKind::from_host) == Some(ForgeKind::Forgejo);
+        let kind = if forgejo {
+            "forgejo_actions"
+        } else {
+            "github_actions"
+        };
         if let Some(file) = workflow_file {
-            return (
-                "github_actions".to_string(),
-                format!("ci:{}", file),
-                Some(serde_json::json!({ "workflow_file": file })),
-            );
+            let id = if forgejo {
+                format!("ci:forgejo:{}", file)
+            } else {
+                format!("ci:{}", file)
+            };
+            // Branch and event scope the badge without changing which
+            // workflow it tracks, so they live in meta rather than the id.
+            let mut meta = serde_json::json!({ "workflow_file": file });
+            for key in ["branch", "event"] {
+                if let Some(value) = query_param(image_trimmed, key) {
+                    meta[key] = serde_json::Value::String(value);
+                }
+            }
+            return (kind.to_string(), id, Some(meta));
         }
         return (
-            "github_actions".to_string(),
+            kind.to_string(),
             format!("unknown:{}", hash_line(raw)),
             None,
         );
     }
+    if let Some((project, branch, badge)) = extract_gitlab_branch_badge(image_trimmed) {
+        let (kind, id) = match badge.as_str() {
+            "pipeline" => ("gitlab_pipeline", format!("ci:gitlab:{}", branch)),
+            _ => ("gitlab_coverage", "coverage:gitlab".to_string()),
+        };
+        return (
+            kind.to_string(),
+            id,
+            Some(serde_json::json!({ "project": project, "branch": branch })),
+        );
+    }
+    if let Some((server, repo)) = extract_woodpecker_badge(image_trimmed) {
+        return (
+            "woodpecker".to_string(),
+            "ci:woodpecker".to_string(),
+            Some(serde_json::json!({ "server": server, "repo": repo })),
+        );
+    }
+    if let Some((kind, meta)) = extract_ci_service_badge(image_trimmed) {
+        return (kind.to_string(), format!("ci:{}", kind), Some(meta));
+    }
+    if let Some(project) = extract_gitlab_release_project(image_trimmed) {
+        return (
+            "gitlab_release".to_string(),
+            "release:gitlab".to_string(),
+            Some(serde_json::json!({ "project": project })),
+        );
+    }
     if let Some(pkg) = extract_after_prefix(image_trimmed, "img.shields.io/npm/v/") {
         return (
             "npm_version".to_string(),
@@ -160,6 +456,63 @@ fn infer_kind(image: &str, raw: &str) -> (String, String, Option<serde_json::Val
             Some(serde_json::json!({ "crate": crate_name })),
         );
     }
+    if let Some(pkg) = extract_after_prefix(image_trimmed, "img.shields.io/pypi/v/") {
+        return (
+            "pypi_version".to_string(),
+            format!("pypi:{}", pkg),
+            Some(serde_json::json!({ "package": pkg })),
+        );
+    }
+    if let Some(pkg) = extract_after_prefix(image_trimmed, "img.shields.io/pypi/dm/")
+        .or_else(|| extract_after_prefix(image_trimmed, "img.shields.io/pypi/dw/"))
+        .or_else(|| extract_after_prefix(image_trimmed, "img.shields.io/pypi/dd/"))
+    {
+        return (
+            "pypi_downloads".to_string(),
+            format!("pypi_downloads:{}", pkg),
+            Some(serde_json::json!({ "package": pkg })),
+        );
+    }
+    if let Some(pkg) = extract_after_prefix(image_trimmed, "img.shields.io/pypi/pyversions/") {
+        return (
+            "pypi_pyversions".to_string(),
+            format!("pypi_pyversions:{}", pkg),
+            Some(serde_json::json!({ "package": pkg })),
+        );
+    }
+    if let Some(image) = extract_after_prefix(image_trimmed, "img.shields.io/docker/v/") {
+        return (
+            "docker_version".to_string(),
+            format!("docker:{}", image),
+            Some(serde_json::json!({ "image": image, "registry": "docker_hub" })),
+        );
+    }
+    if let Some(image) = extract_after_prefix(image_trimmed, "img.shields.io/docker/image-size/") {
+        return (
+            "docker_image_size".to_string(),
+            format!("docker_size:{}", image),
+            Some(serde_json::json!({ "image": image, "registry": "docker_hub" })),
+        );
+    }
+    if let Some(image) = extract_after_prefix(image_trimmed, "img.shields.io/docker/pulls/") {
+        return (
+            "docker_pulls".to_string(),
+            format!("docker_pulls:{}", image),
+            Some(serde_json::json!({ "image": image, "registry": "docker_hub" })),
+        );
+    }
+    if let Some((image, size)) = extract_ghcr_badge(image_trimmed) {
+        let (kind, id) = if size {
+            ("ghcr_image_size", format!("ghcr_size:{}", image))
+        } else {
+            ("ghcr_version", format!("ghcr:{}", image))
+        };
+        return (
+            kind.to_string(),
+            id,
+            Some(serde_json::json!({ "image": image, "registry": "ghcr" })),
+        );
+    }
     if let Some(crate_name) = extract_docs_rs_crate(image_trimmed) {
         return (
             "docs".to_string(),
@@ -167,9 +520,78 @@ fn infer_kind(image: &str, raw: &str) -> (String, String, Option<serde_json::Val
             Some(serde_json::json!({ "crate": crate_name, "provider": "docs.rs" })),
         );
     }
+    if let Some((package, score)) = extract_jsr_badge(image_trimmed) {
+        let (kind, id) = if score {
+            ("jsr_score", format!("jsr_score:{}", package))
+        } else {
+            ("jsr_version", format!("jsr:{}", package))
+        };
+        return (
+            kind.to_string(),
+            id,
+            Some(serde_json::json!({ "package": package })),
+        );
+    }
+    if let Some(module) = extract_go_reference_module(image_trimmed) {
+        return (
+            "docs".to_string(),
+            format!("docs:gopkg:{}", module),
+            Some(serde_json::json!({ "module": module, "provider": "pkg.go.dev" })),
+        );
+    }
+    if let Some(module) = extract_after_prefix(image_trimmed, "goreportcard.com/badge/") {
+        return (
+            "go_report_card".to_string(),
+            format!("goreportcard:{}", module),
+            Some(serde_json::json!({ "module": module })),
+        );
+    }
+    if let Some(version) = extract_static_badge_message(image_trimmed, "go") {
+        return (
+            "go_version".to_string(),
+            "go_version:static".to_string(),
+            Some(serde_json::json!({ "go": version })),
+        );
+    }
+    if let Some(version) = extract_static_badge_message(image_trimmed, "moonbit") {
+        return (
+            "moonbit_version".to_string(),
+            "moonbit:static".to_string(),
+            Some(serde_json::json!({ "version": version })),
+        );
+    }
+    if let Some(version) = extract_static_badge_message(image_trimmed, "rustc") {
+        let version = version.strip_suffix('+').unwrap_or(&version).to_string();
+        return (
+            "rust_version".to_string(),
+            "rust_version:static".to_string(),
+            Some(serde_json::json!({ "rust_version": version })),
+        );
+    }
+    if let Some(edition) = extract_static_badge_message(image_trimmed, "edition") {
+        return (
+            "rust_edition".to_string(),
+            "rust_edition:static".to_string(),
+            Some(serde_json::json!({ "edition": edition })),
+        );
+    }
+    if let Some(range) = extract_static_badge_message(image_trimmed, "node") {
+        return (
+            "node_version".to_string(),
+            "node_version:static".to_string(),
+            Some(serde_json::json!({ "node": range })),
+        );
+    }
     if image_trimmed.contains("img.shields.io/github/license/") {
         return ("license".to_string(), "license:github".to_string(), None);
     }
+    if let Some(project) = extract_after_prefix(image_trimmed, "img.shields.io/gitlab/license/") {
+        return (
+            "license".to_string(),
+            "license:gitlab".to_string(),
+            Some(serde_json::json!({ "project": project })),
+        );
+    }
     if let Some(license) = extract_static_license_badge(image_trimmed) {
         return (
             "license".to_string(),
@@ -245,6 +667,16 @@ fn infer_kind(image: &str, raw: &str) -> (String, String, Option<serde_json::Val
             Some(serde_json::json!({ "owner": owner, "repo": repo })),
         );
     }
+    if let Some((kind, id, meta)) = extract_security_badge(image_trimmed) {
+        return (kind.to_string(), id.to_string(), Some(meta));
+    }
+    if let Some((kind, meta)) = extract_coverage_badge(image_trimmed) {
+        let id = match kind {
+            "coverage" => "coverage:codecov".to_string(),
+            _ => format!("coverage:{kind}"),
+        };
+        return (kind.to_string(), id, Some(meta));
+    }
     if let Some((label, message)) = extract_custom_badge(image_trimmed)
         && label.eq_ignore_ascii_case("docs")
     {
@@ -256,7 +688,10 @@ fn infer_kind(image: &str, raw: &str) -> (String, String, Option<serde_json::Val
     }
     (
         "external".to_string(),
-        format!("external:{}", hash_line(image_trimmed)),
+        format!(
+            "external:{}",
+            hash_line(&without_style_params(image_trimmed))
+        ),
         Some(serde_json::json!({ "provider": "external" })),
     )
 }
@@ -281,6 +716,21 @@ fn extract_static_license_badge(image: &str) -> Option<String> {
     }
 }
 
+/// Message of a static `img.shields.io/badge/<label>-<message>-<color>` badge.
+fn extract_static_badge_message(image: &str, label: &str) -> Option<String> {
+    let prefix = format!("img.shields.io/badge/{label}-");
+    let pos = image.find(&prefix)?;
+    let remainder = &image[pos + prefix.len()..];
+    let before_query = remainder.split('?').next().unwrap_or("");
+    let without_suffix = before_query.strip_suffix(".svg").unwrap_or(before_query);
+    let (encoded, _) = without_suffix.rsplit_once('-')?;
+    if encoded.is_empty() {
+        None
+    } else {
+        Some(decode_static_badge_segment(encoded))
+    }
+}
+
 fn decode_static_badge_segment(value: &str) -> String {
     let mut decoded = String::new();
     let bytes = value.as_bytes();
@@ -319,18 +769,326 @@ fn extract_after_prefix(image: &str, prefix: &str) -> Option<String> {
     }
 }
 
+/// Owner and repository of a GitHub or GitLab Codecov shield. GitLab owners keep
+/// their nested group path.
 fn extract_codecov_repo(image: &str) -> Option<(String, String)> {
-    let prefix = "img.shields.io/codecov/c/github/";
-    let pos = image.find(prefix)?;
-    let remainder = &image[pos + prefix.len()..];
-    let before_query = remainder.split('?').next().unwrap_or("");
-    let mut parts = before_query.split('/');
-    let owner = parts.next()?.to_string();
-    let repo = parts.next()?.trim_end_matches(".svg").to_string();
-    if owner.is_empty() || repo.is_empty() {
+    ["github", "gitlab"].iter().find_map(|service| {
+        let path = extract_after_prefix(image, &format!("img.shields.io/codecov/c/{service}/"))?;
+        let (owner, repo) = match *service {
+            "github" => {
+                let mut parts = path.split('/');
+                (parts.next()?.to_string(), parts.next()?.to_string())
+            }
+            _ => {
+                let (owner, repo) = path.rsplit_once('/')?;
+                (owner.to_string(), repo.to_string())
+            }
+        };
+        if owner.is_empty() || repo.is_empty() {
+            None
+        } else {
+            Some((owner, repo))
+        }
+    })
+}
+
+/// Kind, id and meta of OpenSSF Scorecard, OpenSSF Best Practices and
+/// security policy badges.
+fn extract_security_badge(image: &str) -> Option<(&'static str, &'static str, serde_json::Value)> {
+    let host = url_host(image)?.to_ascii_lowercase();
+    let path = image
+        .split_once("://")?
+        .1
+        .split(['?', '#'])
+        .next()?
+        .split_once('/')
+        .map_or("", |(_, path)| path);
+    match host.as_str() {
+        "api.scorecard.dev" | "api.securityscorecards.dev" => {
+            let project = path.strip_prefix("projects/")?.strip_suffix("/badge")?;
+            Some((
+                "ossf_scorecard",
+                "security:scorecard",
+                serde_json::json!({ "project": project }),
+            ))
+        }
+        "www.bestpractices.dev" | "bestpractices.dev" | "bestpractices.coreinfrastructure.org" => {
+            let rest = path.strip_prefix("projects/")?;
+            let project = rest.strip_suffix("/badge").unwrap_or(rest);
+            let project = project.parse::<u64>().ok()?;
+            Some((
+                "best_practices",
+                "security:best_practices",
+                serde_json::json!({ "project": project }),
+            ))
+        }
+        _ => {
+            let message = extract_static_badge_message(image, "security")?;
+            (message == "policy")
+                .then(|| ("security_policy", "security:policy", serde_json::json!({})))
+        }
+    }
+}
+
+/// Kind and meta of Coveralls, Codacy and SonarCloud coverage shields and
+/// native badges, and of native Codecov badges from codecov.io or a
+/// self-hosted server.
+fn extract_coverage_badge(image: &str) -> Option<(&'static str, serde_json::Value)> {
+    let host = url_host(image)?.to_ascii_lowercase();
+    let path = image
+        .split_once("://")?
+        .1
+        .split(['?', '#'])
+        .next()?
+        .split_once('/')
+        .map_or("", |(_, path)| path);
+    let path = path.strip_suffix(".svg").unwrap_or(path);
+    let mut meta = serde_json::Map::new();
+    let kind = if let Some(rest) = path
+        .strip_prefix("coveralls/")
+        .filter(|_| host == "img.shields.io")
+        .or_else(|| {
+            path.strip_prefix("repos/")
+                .filter(|_| host == "coveralls.io")
+        }) {
+        let rest = rest.strip_suffix("/badge").unwrap_or(rest);
+        let mut segments = rest.split('/');
+        let service = segments.next()?;
+        let owner = segments.next()?;
+        let repo = segments.next()?;
+        meta.insert("service".into(), service.into());
+        meta.insert("repo".into(), format!("{owner}/{repo}").into());
+        "coveralls"
+    } else if let Some(rest) = path
+        .strip_prefix("codacy/coverage/")
+        .filter(|_| host == "img.shields.io")
+        .or_else(|| {
+            path.strip_prefix("project/badge/Coverage/")
+                .filter(|_| host == "app.codacy.com")
+        })
+    {
+        let project_id = rest.split('/').next().filter(|id| !id.is_empty())?;
+        meta.insert("project_id".into(), project_id.into());
+        "codacy"
+    } else if host == "img.shields.io" && path.starts_with("sonar/coverage/") {
+        let key = path.strip_prefix("sonar/coverage/")?;
+        meta.insert("project_key".into(), percent_decode(key).into());
+        if let Some(server) = query_param(image, "server") {
+            meta.insert("server".into(), server.into());
+        }
+        "sonarcloud"
+    } else if host == "sonarcloud.io" && path == "api/project_badges/measure" {
+        if query_param(image, "metric").as_deref() != Some("coverage") {
+            return None;
+        }
+        meta.insert("project_key".into(), query_param(image, "project")?.into());
+        "sonarcloud"
+    } else if let Some(project) = path.strip_suffix("/graph/badge") {
+        let (prefix, project) =
+            ["gh/", "ghe/", "gl/", "gle/", "bb/"]
+                .iter()
+                .find_map(|service| {
+                    let start = project.find(service)?;
+                    (start == 0 || project[..start].ends_with('/'))
+                        .then(|| (&project[..start], &project[start..]))
+                })?;
+        let (service, repo) = project.split_once('/')?;
+        if !repo.contains('/') {
+            return None;
+        }
+        let server = image.split_once("://")?.0;
+        meta.insert(
+            "server".into(),
+            format!("{server}://{host}/{prefix}")
+                .trim_end_matches('/')
+                .into(),
+        );
+        meta.insert("service".into(), service.into());
+        meta.insert("repo".into(), repo.into());
+        "coverage"
+    } else {
+        return None;
+    };
+    Some((kind, serde_json::Value::Object(meta)))
+}
+
+/// Project path, branch and badge name of GitLab's native
+/// `<project>/badges/<branch>/{pipeline,coverage}.svg` badges.
+fn extract_gitlab_branch_badge(image: &str) -> Option<(String, String, String)> {
+    let before_query = image.split('?').next().unwrap_or("");
+    let (badge, rest) = ["pipeline", "coverage"].iter().find_map(|badge| {
+        before_query
+            .strip_suffix(&format!("/{badge}.svg"))
+            .map(|rest| (badge.to_string(), rest))
+    })?;
+    let (project_url, branch) = rest.split_once("/badges/")?;
+    let project = project_url.split_once("://")?.1.split_once('/')?.1;
+    let project = project.strip_suffix("/-").unwrap_or(project);
+    if project.is_empty() || branch.is_empty() {
         None
     } else {
-        Some((owner, repo))
+        Some((project.to_string(), branch.to_string(), badge))
+    }
+}
+
+/// Server and repository of a Woodpecker `/api/badges/<repo>/status.svg`
+/// badge, where `<repo>` is `owner/name` or a numeric repository id.
+fn extract_woodpecker_badge(image: &str) -> Option<(String, String)> {
+    let before_query = image.split('?').next().unwrap_or("");
+    let (server, rest) = before_query.split_once("/api/badges/")?;
+    let repo = rest.strip_suffix("/status.svg")?;
+    if repo.is_empty() {
+        None
+    } else {
+        Some((server.to_string(), repo.to_string()))
+    }
+}
+
+/// Kind and meta of CircleCI, Travis CI, Azure Pipelines, Buildkite and
+/// Jenkins status badges. Each carries the branch it reports on, if any.
+fn extract_ci_service_badge(image: &str) -> Option<(&'static str, serde_json::Value)> {
+    let host = url_host(image)?.to_ascii_lowercase();
+    let path = image
+        .split_once("://")?
+        .1
+        .split(['?', '#'])
+        .next()?
+        .split_once('/')
+        .map_or("", |(_, path)| path);
+    let mut meta = serde_json::Map::new();
+    let kind = match host.as_str() {
+        "dl.circleci.com" | "circleci.com" => {
+            // `status-badge/img/gh/o/r/tree/main.svg` or legacy `gh/o/r.svg`.
+            let path = path.strip_prefix("status-badge/img/").unwrap_or(path);
+            let path = path.strip_suffix(".svg")?;
+            let (project, branch) = match path.split_once("/tree/") {
+                Some((project, branch)) => (project, Some(percent_decode(branch))),
+                None => (path, None),
+            };
+            let mut segments = project.splitn(2, '/');
+            let vcs = segments.next()?;
+            let project = segments.next().filter(|project| project.contains('/'))?;
+            meta.insert("vcs".into(), vcs.into());
+            meta.insert("project".into(), project.into());
+            if let Some(branch) = branch {
+                meta.insert("branch".into(), branch.into());
+            }
+            "circleci"
+        }
+        "app.travis-ci.com" | "travis-ci.com" | "travis-ci.org" => {
+            let repo = path.strip_suffix(".svg")?;
+            if repo.split('/').count() != 2 {
+                return None;
+            }
+            meta.insert("repo".into(), repo.into());
+            "travis"
+        }
+        "dev.azure.com" => {
+            let (project, pipeline) = path.split_once("/_apis/build/status/")?;
+            let (organization, project) = project.split_once('/')?;
+            meta.insert("organization".into(), percent_decode(organization).into());
+            meta.insert("project".into(), percent_decode(project).into());
+            meta.insert("pipeline".into(), percent_decode(pipeline).into());
+            if let Some(branch) = query_param(image, "branchName") {
+                meta.insert("branch".into(), branch.into());
+            }
+            "azure_pipelines"
+        }
+        "badge.buildkite.com" => {
+            let key = path.strip_suffix(".svg")?;
+            meta.insert("key".into(), key.into());
+            "buildkite"
+        }
+        _ => {
+            let (server, _) = image.split_once("/buildStatus/icon")?;
+            let job = query_param(image, "job")?;
+            meta.insert("server".into(), server.into());
+            meta.insert("job".into(), job.into());
+            "jenkins"
+        }
+    };
+    if kind != "azure_pipelines"
+        && let Some(branch) = query_param(image, "branch")
+    {
+        meta.insert("branch".into(), branch.into());
+    }
+    Some((kind, serde_json::Value::Object(meta)))
+}
+
+/// `url` without the shields.io presentation parameters set by `[style]`, so
+/// restyling a badge keeps its id.
+fn without_style_params(url: &str) -> String {
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_string();
+    };
+    let (query, fragment) = match query.split_once('#') {
+        Some((query, fragment)) => (query, Some(fragment)),
+        None => (query, None),
+    };
+    let kept = query
+        .split('&')
+        .filter(|pair| {
+            let name = pair.split_once('=').map_or(*pair, |(name, _)| name);
+            !STYLE_PARAMS.contains(&name)
+        })
+        .collect::<Vec<_>>();
+    let mut stripped = base.to_string();
+    if !kept.is_empty() {
+        stripped.push('?');
+        stripped.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        stripped.push('#');
+        stripped.push_str(fragment);
+    }
+    stripped
+}
+
+const STYLE_PARAMS: &[&str] = &["style", "logo", "logoColor", "color", "labelColor"];
+
+/// Percent-decoded value of query parameter `key`.
+fn query_param(url: &str, key: &str) -> Option<String> {
+    let query = url.split_once('?')?.1.split('#').next()?;
+    query.split('&').find_map(|pair| {
+        let (name, value) = pair.split_once('=')?;
+        (name == key && !value.is_empty()).then(|| percent_decode(value))
+    })
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if bytes[idx] == b'%'
+            && let Some(byte) = bytes
+                .get(idx + 1..idx + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            decoded.push(byte);
+            idx += 3;
+        } else {
+            decoded.push(bytes[idx]);
+            idx += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn url_host(url: &str) -> Option<&str> {
+    let rest = url.split_once("://")?.1;
+    rest.split(['/', '?', '#']).next()
+}
+
+fn extract_gitlab_release_project(image: &str) -> Option<String> {
+    let before_query = image.split('?').next().unwrap_or("");
+    let project_url = before_query.strip_suffix("/-/badges/release.svg")?;
+    let project = project_url.split_once("://")?.1.split_once('/')?.1;
+    if project.is_empty() {
+        None
+    } else {
+        Some(project.to_string())
     }
 }
 
@@ -355,6 +1113,55 @@ fn extract_docs_rs_crate(image: &str) -> Option<String> {
     }
 }
 
+fn extract_jsr_badge(image: &str) -> Option<(String, bool)> {
+    let prefix = "jsr.io/badges/";
+    let pos = image.find(prefix)?;
+    let remainder = &image[pos + prefix.len()..];
+    let before_query = remainder.split('?').next().unwrap_or("");
+    let mut parts = before_query.split('/');
+    let scope = parts.next()?;
+    let name = parts.next()?;
+    if !scope.starts_with('@') || scope.len() < 2 || name.is_empty() {
+        return None;
+    }
+    let score = match parts.next() {
+        None => false,
+        Some("score") => true,
+        Some(_) => return None,
+    };
+    Some((format!("{}/{}", scope, name), score))
+}
+
+fn extract_ghcr_badge(image: &str) -> Option<(String, bool)> {
+    let prefix = "ghcr-badge.egpl.dev/";
+    let pos = image.find(prefix)?;
+    let remainder = &image[pos + prefix.len()..];
+    let before_query = remainder.split('?').next().unwrap_or("");
+    let (path, size) = if let Some(path) = before_query.strip_suffix("/latest_tag") {
+        (path, false)
+    } else {
+        (before_query.strip_suffix("/size")?, true)
+    };
+    if path.split('/').count() < 2 || path.split('/').any(str::is_empty) {
+        None
+    } else {
+        Some((path.to_string(), size))
+    }
+}
+
+fn extract_go_reference_module(image: &str) -> Option<String> {
+    let prefix = "pkg.go.dev/badge/";
+    let pos = image.find(prefix)?;
+    let remainder = &image[pos + prefix.len()..];
+    let before_query = remainder.split('?').next().unwrap_or("");
+    let module = before_query.trim_end_matches(".svg");
+    if module.is_empty() {
+        None
+    } else {
+        Some(module.to_string())
+    }
+}
+
 fn extract_owner_repo_after_prefix(image: &str, prefix: &str) -> Option<(String, String)> {
     let pos = image.find(prefix)?;
     let remainder = &image[pos + prefix.len()..];
@@ -1,5 +1,8 @@
-use crate::readme::extract_marker_block_lines;
-use crate::readme_badges::parse_badge_line_optional;
+use crate::badge_service::BadgeHosts;
+use crate::readme::{LiteralBlocks, ReadmeFormat, extract_marker_block_lines, group_badge_lines};
+use crate::readme_badges::{
+    is_ci_kind, is_coverage_kind, is_security_kind, parse_badge_line_optional_with,
+};
 
 #[derive(Debug)]
 pub struct RemovalOutcome {
@@ -17,7 +20,19 @@ pub fn remove_block_lines_by_id_kind(
     kinds: &[String],
     strict: bool,
 ) -> anyhow::Result<RemovalOutcome> {
-    let lines = extract_marker_block_lines(content)?;
+    remove_block_lines_by_id_kind_with(content, ids, kinds, strict, &BadgeHosts::default())
+}
+
+/// Like [`remove_block_lines_by_id_kind`], matching badges rendered by the
+/// shields and badgen instances in `hosts` too.
+pub fn remove_block_lines_by_id_kind_with(
+    content: &str,
+    ids: &[String],
+    kinds: &[String],
+    strict: bool,
+    hosts: &BadgeHosts,
+) -> anyhow::Result<RemovalOutcome> {
+    let lines = group_badge_lines(&extract_marker_block_lines(content)?);
     let id_set = ids
         .iter()
         .map(|s| s.trim().to_string())
@@ -32,18 +47,14 @@ pub fn remove_block_lines_by_id_kind(
     let mut removed_ids = Vec::new();
     let mut removed_kinds = std::collections::HashMap::new();
 
-    let mut in_code_fence = false;
+    let format = ReadmeFormat::from_markers(content).unwrap_or(ReadmeFormat::Markdown);
+    let mut literal = LiteralBlocks::new(format);
     for line in lines {
-        if is_code_fence(&line) {
-            in_code_fence = !in_code_fence;
-            remaining.push(line);
-            continue;
-        }
-        if in_code_fence {
+        if literal.skip(&line) {
             remaining.push(line);
             continue;
         }
-        let parsed = parse_badge_line_optional(&line);
+        let parsed = parse_badge_line_optional_with(&line, hosts);
         let id_candidate = parsed
             .as_ref()
             .map(|badge| badge.id.clone())
@@ -53,7 +64,11 @@ pub fn remove_block_lines_by_id_kind(
             .map(|badge| badge.kind.clone())
             .unwrap_or_else(|| "unknown".to_string());
         let remove_by_id = !id_set.is_empty() && id_set.contains(&id_candidate);
-        let remove_by_kind = !kind_set.is_empty() && kind_set.contains(&kind_candidate);
+        let remove_by_kind = !kind_set.is_empty()
+            && (kind_set.contains(&kind_candidate)
+                || (kind_set.contains("ci") && is_ci_kind(&kind_candidate))
+                || (kind_set.contains("coverage") && is_coverage_kind(&kind_candidate))
+                || (kind_set.contains("security") && is_security_kind(&kind_candidate)));
         if remove_by_id || remove_by_kind {
             if remove_by_id {
                 id_hits += 1;
@@ -90,10 +105,6 @@ pub fn remove_block_lines_by_id_kind(
     })
 }
 
-fn is_code_fence(line: &str) -> bool {
-    line.trim_start().starts_with("```")
-}
-
 fn hash_line(line: &str) -> String {
     use std::hash::{Hash, Hasher};
     let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -0,0 +1,127 @@
+use crate::workflows::WorkflowInfo;
+use std::path::Path;
+
+/// Actions that check dependencies against advisory databases.
+const AUDIT_ACTIONS: &[&str] = &[
+    "rustsec/audit-check",
+    "actions-rs/audit-check",
+    "actions-rust-lang/audit",
+    "embarkstudios/cargo-deny-action",
+    "pypa/gh-action-pip-audit",
+    "golang/govulncheck-action",
+];
+
+/// Leading words of audit commands.
+const AUDIT_COMMANDS: &[&[&str]] = &[
+    &["cargo", "audit"],
+    &["cargo", "deny"],
+    &["npm", "audit"],
+    &["pnpm", "audit"],
+    &["yarn", "audit"],
+    &["pip-audit"],
+    &["govulncheck"],
+];
+
+/// Locations GitHub, GitLab and Forgejo read a security policy from.
+const POLICY_FILES: &[&str] = &["SECURITY.md", ".github/SECURITY.md", "docs/SECURITY.md"];
+
+/// Security signals found in the repository.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecurityInfo {
+    /// Workflow running the OpenSSF Scorecard action.
+    pub scorecard_workflow: Option<String>,
+    /// Workflows auditing dependencies for known vulnerabilities.
+    pub audit_workflows: Vec<String>,
+    /// cargo-deny configuration.
+    pub cargo_deny: Option<String>,
+    /// Security policy document.
+    pub policy: Option<String>,
+}
+
+pub fn detect_security(root: &Path, workflows: &[WorkflowInfo]) -> SecurityInfo {
+    SecurityInfo {
+        scorecard_workflow: workflows
+            .iter()
+            .find(|workflow| workflow.is_scorecard())
+            .map(|workflow| workflow.file.clone()),
+        audit_workflows: workflows
+            .iter()
+            .filter(|workflow| is_audit_workflow(workflow))
+            .map(|workflow| workflow.file.clone())
+            .collect(),
+        cargo_deny: root
+            .join("deny.toml")
+            .is_file()
+            .then(|| "deny.toml".to_string()),
+        policy: POLICY_FILES
+            .iter()
+            .find(|file| root.join(file).is_file())
+            .map(|file| file.to_string()),
+    }
+}
+
+/// Whether the workflow runs cargo-audit, cargo-deny, npm audit, pip-audit or
+/// govulncheck, either through their actions or on the command line.
+pub fn is_audit_workflow(workflow: &WorkflowInfo) -> bool {
+    workflow.uses_any(AUDIT_ACTIONS)
+        || workflow.commands.iter().any(|words| {
+            AUDIT_COMMANDS.iter().any(|command| {
+                words.len() >= command.len()
+                    && words
+                        .iter()
+                        .zip(command.iter())
+                        .all(|(word, part)| word == part)
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecurityInfo, detect_security};
+    use crate::workflows::parse_workflow;
+    use std::fs;
+
+    #[test]
+    fn detects_scorecard_audit_workflows_and_policy() {
+        let temp = tempfile::tempdir().unwrap();
+        let workflows = vec![
+            parse_workflow(
+                "ci.yml".to_string(),
+                "ci",
+                "on: push\njobs:\n  test:\n    steps:\n      - run: cargo test\n",
+            ),
+            parse_workflow(
+                "scorecard.yml".to_string(),
+                "scorecard",
+                "on: push\njobs:\n  analysis:\n    steps:\n      - uses: ossf/scorecard-action@v2.4.0\n",
+            ),
+            parse_workflow(
+                "audit.yml".to_string(),
+                "audit",
+                "on:\n  schedule:\n    - cron: '0 0 * * *'\njobs:\n  audit:\n    steps:\n      - uses: rustsec/audit-check@v2\n",
+            ),
+            parse_workflow(
+                "deny.yml".to_string(),
+                "deny",
+                "on: push\njobs:\n  deny:\n    steps:\n      - run: |\n          cargo install cargo-deny\n          cargo deny check advisories\n",
+            ),
+        ];
+        assert_eq!(
+            detect_security(temp.path(), &workflows[..1]),
+            SecurityInfo::default()
+        );
+
+        fs::write(temp.path().join("deny.toml"), "[advisories]\n").unwrap();
+        fs::create_dir_all(temp.path().join(".github")).unwrap();
+        fs::write(temp.path().join(".github/SECURITY.md"), "# Security\n").unwrap();
+        assert_eq!(
+            detect_security(temp.path(), &workflows),
+            SecurityInfo {
+                scorecard_workflow: Some("scorecard.yml".to_string()),
+                audit_workflows: vec!["audit.yml".to_string(), "deny.yml".to_string()],
+                cargo_deny: Some("deny.toml".to_string()),
+                policy: Some(".github/SECURITY.md".to_string()),
+            }
+        );
+    }
+}
@@ -1,13 +1,201 @@
+use crate::config::CiConfig;
+use serde_yaml_ng::Value;
+use std::collections::HashMap;
 use std::path::Path;
 
+/// The OpenSSF Scorecard action, whose workflows get a `security` badge
+/// instead of a CI one.
+const SCORECARD_ACTIONS: &[&str] = &["ossf/scorecard-action"];
+
+/// The action that pushes container images; workflows using it are searched
+/// for image names.
+const BUILD_PUSH_ACTION: &str = "docker/build-push-action";
+
+/// Actions whose `tags:` and `images:` inputs name the pushed images.
+const IMAGE_ACTIONS: &[&str] = &[BUILD_PUSH_ACTION, "docker/metadata-action"];
+
 #[derive(Debug, Clone)]
 pub struct WorkflowInfo {
+    /// The workflow `name:`, else the file stem.
     pub name: String,
     pub file: String,
+    /// Whether the file declares `name:`, making `name` usable as a badge
+    /// label.
+    pub named: bool,
+    /// `on:` events with their branch filters. `None` when the file is not a
+    /// readable workflow, in which case nothing is known about when it runs.
+    pub triggers: Option<Vec<WorkflowTrigger>>,
+    /// Job display names, falling back to job ids.
+    pub jobs: Vec<String>,
+    /// Actions the steps use, as returned by [`referenced_actions`].
+    pub actions: Vec<String>,
+    /// Commands the steps run, as returned by [`shell_commands`].
+    pub commands: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowTrigger {
+    pub event: String,
+    pub branches: Vec<String>,
+    pub branches_ignore: Vec<String>,
+    pub tags: Vec<String>,
+    pub tags_ignore: Vec<String>,
+}
+
+impl WorkflowTrigger {
+    /// Whether the event fires for `branch` under its `branches`/
+    /// `branches-ignore` filters. A `push` filtered only by tags never runs
+    /// for branches.
+    pub fn matches_branch(&self, branch: &str) -> bool {
+        if self.branches.is_empty()
+            && self.branches_ignore.is_empty()
+            && !(self.tags.is_empty() && self.tags_ignore.is_empty())
+        {
+            return false;
+        }
+        if !self.branches.is_empty() {
+            // Later `!pattern` entries exclude branches earlier ones included.
+            let mut included = false;
+            for pattern in &self.branches {
+                match pattern.strip_prefix('!') {
+                    Some(pattern) if glob_match(pattern, branch) => included = false,
+                    None if glob_match(pattern, branch) => included = true,
+                    _ => {}
+                }
+            }
+            return included;
+        }
+        !self
+            .branches_ignore
+            .iter()
+            .any(|pattern| glob_match(pattern, branch))
+    }
+}
+
+impl WorkflowInfo {
+    /// Whether this runs OpenSSF Scorecard analysis.
+    pub fn is_scorecard(&self) -> bool {
+        self.uses_any(SCORECARD_ACTIONS)
+    }
+
+    /// Whether a `uses:` step references one of `actions` or a sub-action.
+    pub fn uses_any(&self, actions: &[&str]) -> bool {
+        self.actions
+            .iter()
+            .any(|action| actions.iter().any(|known| is_action(action, known)))
+    }
+
+    /// Whether this looks like the project's CI: a workflow run by `push` or
+    /// `pull_request` on the default branch. Release, scheduled and
+    /// `workflow_dispatch`-only workflows are not, nor is OpenSSF Scorecard
+    /// analysis, which has its own `security` badge. Unreadable workflows
+    /// count as CI, since nothing rules them out.
+    pub fn is_ci(&self, default_branch: &str) -> bool {
+        if self.is_scorecard() {
+            return false;
+        }
+        let Some(triggers) = &self.triggers else {
+            return true;
+        };
+        triggers.iter().any(|trigger| {
+            matches!(trigger.event.as_str(), "push" | "pull_request")
+                && trigger.matches_branch(default_branch)
+        })
+    }
+
+    /// Whether `pattern` from `[ci]` config names this workflow: its file
+    /// name, file stem, or `name:`.
+    fn is_named(&self, pattern: &str) -> bool {
+        self.file == pattern
+            || self.file.rsplit_once('.').map(|(stem, _)| stem) == Some(pattern)
+            || (self.named && self.name == pattern)
+    }
+}
+
+/// Workflows that get CI badges: those [`WorkflowInfo::is_ci`] accepts plus
+/// `[ci] include`, minus `[ci] exclude`.
+pub fn select_ci_workflows(
+    workflows: Vec<WorkflowInfo>,
+    default_branch: &str,
+    config: &CiConfig,
+) -> Vec<WorkflowInfo> {
+    workflows
+        .into_iter()
+        .filter(|workflow| is_selected_ci_workflow(workflow, default_branch, config))
+        .collect()
+}
+
+/// Branch and event a workflow's badge reports on: the `[ci.workflows]` entry
+/// naming it, else `default_branch` and every event.
+pub fn workflow_badge_scope<'a>(
+    workflow: &WorkflowInfo,
+    default_branch: Option<&'a str>,
+    config: &'a CiConfig,
+) -> (Option<&'a str>, Option<&'a str>) {
+    let settings = config
+        .workflows
+        .iter()
+        .find(|(pattern, _)| workflow.is_named(pattern))
+        .map(|(_, settings)| settings);
+    let branch = settings
+        .and_then(|settings| settings.branch.as_deref())
+        .map_or(default_branch, |branch| {
+            Some(branch).filter(|branch| !branch.is_empty())
+        });
+    let event = settings.and_then(|settings| settings.event.as_deref());
+    (branch, event)
+}
+
+pub fn is_selected_ci_workflow(
+    workflow: &WorkflowInfo,
+    default_branch: &str,
+    config: &CiConfig,
+) -> bool {
+    if config
+        .exclude
+        .iter()
+        .any(|pattern| workflow.is_named(pattern))
+    {
+        return false;
+    }
+    workflow.is_ci(default_branch)
+        || config
+            .include
+            .iter()
+            .any(|pattern| workflow.is_named(pattern))
 }
 
 pub fn detect_workflows(root: &Path) -> Vec<WorkflowInfo> {
-    let workflows_dir = root.join(".github").join("workflows");
+    workflows_in(&root.join(".github").join("workflows"))
+}
+
+/// Forgejo Actions workflows. Like Forgejo itself, this reads
+/// `.forgejo/workflows`, then `.gitea/workflows`, and falls back to
+/// `.github/workflows` when neither exists.
+pub fn detect_forgejo_workflows(root: &Path) -> Vec<WorkflowInfo> {
+    [".forgejo", ".gitea", ".github"]
+        .iter()
+        .map(|dir| root.join(dir).join("workflows"))
+        .find(|dir| dir.is_dir())
+        .map(|dir| workflows_in(&dir))
+        .unwrap_or_default()
+}
+
+/// Woodpecker CI configuration: `.woodpecker.yml`/`.woodpecker.yaml`, or the
+/// `.woodpecker/` directory of pipeline files.
+pub fn detect_woodpecker(root: &Path) -> Option<String> {
+    [".woodpecker.yml", ".woodpecker.yaml"]
+        .iter()
+        .find(|file| root.join(file).is_file())
+        .map(|file| file.to_string())
+        .or_else(|| {
+            root.join(".woodpecker")
+                .is_dir()
+                .then(|| ".woodpecker/".to_string())
+        })
+}
+
+fn workflows_in(workflows_dir: &Path) -> Vec<WorkflowInfo> {
     let mut workflows = Vec::new();
     if !workflows_dir.exists() {
         return workflows;
@@ -26,195 +214,729 @@ pub fn detect_workflows(root: &Path) -> Vec<WorkflowInfo> {
             continue;
         }
         if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-            workflows.push(WorkflowInfo {
-                name: stem.to_string(),
-                file: format!("{}.{}", stem, ext),
-            });
+            let content = std::fs::read_to_string(&path).unwrap_or_default();
+            workflows.push(parse_workflow(format!("{}.{}", stem, ext), stem, &content));
         }
     }
     workflows.sort_by(|a, b| a.file.cmp(&b.file));
     workflows
 }
 
-pub fn detects_codecov(root: &Path) -> bool {
-    if [
-        ".codecov.yml",
-        ".codecov.yaml",
-        "codecov.yml",
-        "codecov.yaml",
-    ]
-    .iter()
-    .any(|candidate| root.join(candidate).is_file())
-    {
-        return true;
+/// Reads `name:`, `on:` and `jobs:` from a GitHub or Forgejo Actions workflow.
+/// `on:` may be a single event, a list of events, or a map of events to their
+/// filters.
+pub fn parse_workflow(file: String, stem: &str, content: &str) -> WorkflowInfo {
+    let document = serde_yaml_ng::from_str::<Value>(content)
+        .ok()
+        .filter(Value::is_mapping);
+    let name = document
+        .as_ref()
+        .and_then(|document| document.get("name"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+    let triggers = document.as_ref().map(|document| {
+        // YAML 1.1 readers turn a bare `on` key into `true`; accept both.
+        let on = document
+            .get("on")
+            .or_else(|| document.get(Value::Bool(true)));
+        match on {
+            Some(Value::String(event)) => vec![trigger(event, None)],
+            Some(Value::Sequence(events)) => events
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|event| trigger(event, None))
+                .collect(),
+            Some(Value::Mapping(events)) => events
+                .iter()
+                .filter_map(|(event, filters)| Some(trigger(event.as_str()?, Some(filters))))
+                .collect(),
+            _ => Vec::new(),
+        }
+    });
+    let jobs = document
+        .as_ref()
+        .and_then(|document| document.get("jobs"))
+        .and_then(Value::as_mapping)
+        .map(|jobs| {
+            jobs.iter()
+                .filter_map(|(id, job)| {
+                    job.get("name")
+                        .and_then(Value::as_str)
+                        .or_else(|| id.as_str())
+                        .map(str::to_string)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    WorkflowInfo {
+        name: name.unwrap_or(stem).to_string(),
+        file,
+        named: name.is_some(),
+        triggers,
+        jobs,
+        actions: referenced_actions(content),
+        commands: shell_commands(content),
+    }
+}
+
+/// Actions referenced by `uses:` steps, lowercased and without the `@ref`.
+pub fn referenced_actions(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| step_line(line)?.strip_prefix("uses:"))
+        .map(|action| {
+            action
+                .trim()
+                .trim_matches(['"', '\''])
+                .split('@')
+                .next()
+                .unwrap_or("")
+                .to_ascii_lowercase()
+        })
+        .filter(|action| !action.is_empty())
+        .collect()
+}
+
+/// Whether `action` is `known` or one of its sub-actions, such as
+/// `github/codeql-action/analyze`.
+pub fn is_action(action: &str, known: &str) -> bool {
+    action
+        .strip_prefix(known)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Shell commands on `run:` and `script:` lines and in multi-line scripts,
+/// one per pipeline or list segment, split into words. Wrappers such as `npx`
+/// and `sudo` are skipped and the executable is reduced to its file name, so
+/// `./codecov -t x` yields `["codecov", "-t", "x"]`.
+pub fn shell_commands(content: &str) -> Vec<Vec<String>> {
+    let mut commands = Vec::new();
+    for line in content.lines() {
+        let Some(line) = step_line(line) else {
+            continue;
+        };
+        if line.starts_with("uses:") {
+            continue;
+        }
+        let line = ["run:", "script:"]
+            .iter()
+            .find_map(|key| line.strip_prefix(key))
+            .unwrap_or(line);
+        for segment in line.split(['|', ';', '&', '(', ')', '`']) {
+            let mut words = segment
+                .split_whitespace()
+                .map(|word| word.trim_matches(['"', '\'']))
+                .skip_while(|word| matches!(*word, "-" | "npx" | "sudo" | "uvx" | "pipx" | "run"))
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+            let Some(first) = words.first_mut() else {
+                continue;
+            };
+            if let Some((_, name)) = first.rsplit_once('/') {
+                *first = name.to_string();
+            }
+            commands.push(words);
+        }
+    }
+    commands
+}
+
+/// A line with its indentation and list marker removed, `None` for comments.
+fn step_line(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let line = line.strip_prefix("- ").unwrap_or(line).trim_start();
+    (!line.starts_with('#')).then_some(line)
+}
+
+fn trigger(event: &str, filters: Option<&Value>) -> WorkflowTrigger {
+    let list = |key: &str| -> Vec<String> {
+        match filters.and_then(|filters| filters.get(key)) {
+            Some(Value::String(pattern)) => vec![pattern.clone()],
+            Some(Value::Sequence(patterns)) => patterns
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect(),
+            _ => Vec::new(),
+        }
+    };
+    WorkflowTrigger {
+        event: event.to_string(),
+        branches: list("branches"),
+        branches_ignore: list("branches-ignore"),
+        tags: list("tags"),
+        tags_ignore: list("tags-ignore"),
+    }
+}
+
+/// GitHub Actions branch filter globs: `*` stops at `/`, `**` does not, `?`
+/// matches one character.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let text = text.as_bytes();
+    fn matches(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern {
+            [] => text.is_empty(),
+            [b'*', b'*', rest @ ..] => (0..=text.len()).any(|skip| matches(rest, &text[skip..])),
+            [b'*', rest @ ..] => (0..=text.len())
+                .take_while(|&skip| skip == 0 || text[skip - 1] != b'/')
+                .any(|skip| matches(rest, &text[skip..])),
+            [b'?', rest @ ..] => !text.is_empty() && matches(rest, &text[1..]),
+            [first, rest @ ..] => text.first() == Some(first) && matches(rest, &text[1..]),
+        }
+    }
+    matches(pattern, text)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitLabCi {
+    pub file: String,
+    /// Whether a job reports coverage through the `coverage:` keyword, which is
+    /// what feeds GitLab's coverage badge.
+    pub coverage: bool,
+}
+
+pub fn detect_gitlab_ci(root: &Path) -> Option<GitLabCi> {
+    [".gitlab-ci.yml", ".gitlab-ci.yaml"]
+        .iter()
+        .find_map(|file| {
+            let content = std::fs::read_to_string(root.join(file)).ok()?;
+            let coverage = content.lines().any(|line| {
+                let trimmed = line.trim_start();
+                trimmed.len() < line.len() && trimmed.starts_with("coverage:")
+            });
+            Some(GitLabCi {
+                file: file.to_string(),
+                coverage,
+            })
+        })
+}
+
+/// Hosted CI services recognised by their configuration file, alongside
+/// Actions, GitLab CI and Woodpecker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiService {
+    CircleCi,
+    Travis,
+    AzurePipelines,
+    Buildkite,
+    Jenkins,
+}
+
+impl CiService {
+    pub const ALL: [Self; 5] = [
+        Self::CircleCi,
+        Self::Travis,
+        Self::AzurePipelines,
+        Self::Buildkite,
+        Self::Jenkins,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::CircleCi => "circleci",
+            Self::Travis => "travis",
+            Self::AzurePipelines => "azure_pipelines",
+            Self::Buildkite => "buildkite",
+            Self::Jenkins => "jenkins",
+        }
+    }
+
+    /// Configuration files and directories, in lookup order. Directories end
+    /// with `/`.
+    const fn files(self) -> &'static [&'static str] {
+        match self {
+            Self::CircleCi => &[".circleci/config.yml", ".circleci/config.yaml"],
+            Self::Travis => &[".travis.yml", ".travis.yaml"],
+            Self::AzurePipelines => &[
+                "azure-pipelines.yml",
+                "azure-pipelines.yaml",
+                ".azure-pipelines.yml",
+                ".azure-pipelines/",
+            ],
+            Self::Buildkite => &[
+                ".buildkite/pipeline.yml",
+                ".buildkite/pipeline.yaml",
+                ".buildkite/pipeline.json",
+                "buildkite.yml",
+                "buildkite.yaml",
+            ],
+            Self::Jenkins => &["Jenkinsfile"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiServiceFile {
+    pub service: CiService,
+    pub file: String,
+}
+
+pub fn detect_ci_services(root: &Path) -> Vec<CiServiceFile> {
+    CiService::ALL
+        .into_iter()
+        .filter_map(|service| {
+            let file = service
+                .files()
+                .iter()
+                .find(|file| match file.strip_suffix('/') {
+                    Some(dir) => root.join(dir).is_dir(),
+                    None => root.join(file).is_file(),
+                })?;
+            Some(CiServiceFile {
+                service,
+                file: file.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerRegistry {
+    Ghcr,
+    DockerHub,
+}
+
+impl ContainerRegistry {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ghcr => "ghcr",
+            Self::DockerHub => "docker_hub",
+        }
+    }
+
+    const fn hosts(self) -> &'static [&'static str] {
+        match self {
+            Self::Ghcr => &["ghcr.io/"],
+            Self::DockerHub => &["docker.io/", "index.docker.io/", "registry-1.docker.io/"],
+        }
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerImage {
+    pub registry: ContainerRegistry,
+    /// Repository path without the registry host, e.g. `owner/app`.
+    pub name: String,
+    pub workflow: String,
+}
 
+/// Dockerfiles at the repository root or one directory below it.
+pub fn detect_dockerfiles(root: &Path) -> Vec<String> {
+    let mut found = Vec::new();
+    let walker = walkdir::WalkDir::new(root)
+        .max_depth(2)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !matches!(
+                    entry.file_name().to_str(),
+                    Some(".git" | "target" | "node_modules" | "vendor")
+                )
+        });
+    for entry in walker.flatten() {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Some(name) = entry.file_name().to_str() else {
+            continue;
+        };
+        if !is_dockerfile_name(name) {
+            continue;
+        }
+        if let Ok(relative) = entry.path().strip_prefix(root) {
+            found.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+    }
+    found.sort();
+    found
+}
+
+fn is_dockerfile_name(name: &str) -> bool {
+    matches!(name, "Dockerfile" | "Containerfile")
+        || name.starts_with("Dockerfile.")
+        || name.ends_with(".Dockerfile")
+}
+
+/// Images pushed by `docker/build-push-action` workflows. Image references are
+/// read from the `tags:` and `images:` inputs of `docker/build-push-action` and
+/// `docker/metadata-action` steps, after expanding `env.*` and the common
+/// `github.*` expressions; references that stay templated are skipped.
+pub fn detect_container_images(
+    root: &Path,
+    owner: Option<&str>,
+    repo: Option<&str>,
+) -> Vec<ContainerImage> {
+    let mut images: Vec<ContainerImage> = Vec::new();
+    if detect_dockerfiles(root).is_empty() {
+        return images;
+    }
     let workflows_dir = root.join(".github").join("workflows");
-    let Ok(entries) = std::fs::read_dir(workflows_dir) else {
-        return false;
+    for workflow in detect_workflows(root) {
+        if !workflow.uses_any(&[BUILD_PUSH_ACTION]) {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(workflows_dir.join(&workflow.file)) else {
+            continue;
+        };
+        for image in parse_container_images(&content, owner, repo) {
+            if images
+                .iter()
+                .any(|known| known.registry == image.0 && known.name == image.1)
+            {
+                continue;
+            }
+            images.push(ContainerImage {
+                registry: image.0,
+                name: image.1,
+                workflow: workflow.file.clone(),
+            });
+        }
+    }
+    images
+}
+
+fn parse_container_images(
+    content: &str,
+    owner: Option<&str>,
+    repo: Option<&str>,
+) -> Vec<(ContainerRegistry, String)> {
+    let mut images: Vec<(ContainerRegistry, String)> = Vec::new();
+    let Ok(document) = serde_yaml_ng::from_str::<Value>(content) else {
+        return images;
     };
-    entries.flatten().any(|entry| {
-        let path = entry.path();
-        let ext = path.extension().and_then(|value| value.to_str());
-        if !matches!(ext, Some("yml" | "yaml")) {
-            return false;
+    let Some(jobs) = document.get("jobs").and_then(Value::as_mapping) else {
+        return images;
+    };
+    for job in jobs.values() {
+        let steps = job.get("steps").and_then(Value::as_sequence);
+        for step in steps.into_iter().flatten() {
+            let Some(action) = step.get("uses").and_then(Value::as_str) else {
+                continue;
+            };
+            let action = action.split('@').next().unwrap_or("").to_ascii_lowercase();
+            if !IMAGE_ACTIONS.contains(&action.as_str()) {
+                continue;
+            }
+            // Step env overrides job env, which overrides workflow env.
+            let mut env = step_env(&document);
+            env.extend(step_env(job));
+            env.extend(step_env(step));
+            let with = step.get("with");
+            for key in ["tags", "images"] {
+                let Some(value) = with.and_then(|with| with.get(key)).and_then(Value::as_str)
+                else {
+                    continue;
+                };
+                for reference in image_references(value) {
+                    let reference = expand_expressions(reference, &env, owner, repo);
+                    if let Some(image) = container_image(&reference)
+                        && !images.contains(&image)
+                    {
+                        images.push(image);
+                    }
+                }
+            }
         }
-        std::fs::read_to_string(path)
-            .map(|content| content.to_ascii_lowercase().contains("codecov"))
-            .unwrap_or(false)
+    }
+    images
+}
+
+/// Image references in a `tags:` or `images:` input: one per line or comma,
+/// with metadata-action's `name=<image>,enable=...` form reduced to the image.
+fn image_references(value: &str) -> impl Iterator<Item = &str> {
+    value.lines().flat_map(|line| {
+        line.split(',').filter_map(|item| {
+            let item = item.trim();
+            match item.split_once('=') {
+                Some(("name", image)) => Some(image.trim()),
+                Some(_) => None,
+                None => (!item.is_empty()).then_some(item),
+            }
+        })
     })
 }
 
-pub fn gh_latest_status(workflow: &str) -> Option<(String, String)> {
-    let view_output = std::process::Command::new("gh")
-        .arg("workflow")
-        .arg("view")
-        .arg(workflow)
-        .output()
-        .ok()?;
-    if !view_output.status.success() {
+/// Registry and repository path of an expanded image reference. GHCR and
+/// Docker Hub hosts are recognized, and references without a host are Docker
+/// Hub images; other registries are skipped.
+fn container_image(reference: &str) -> Option<(ContainerRegistry, String)> {
+    let reference = reference.trim().trim_matches(['"', '\'']);
+    if reference.contains("${{") {
         return None;
     }
-    let run_output = std::process::Command::new("gh")
-        .arg("run")
-        .arg("list")
-        .arg("--limit")
-        .arg("1")
-        .arg("--workflow")
-        .arg(workflow)
-        .output()
-        .ok()?;
-    if !run_output.status.success() {
+    for registry in [ContainerRegistry::Ghcr, ContainerRegistry::DockerHub] {
+        for host in registry.hosts() {
+            if let Some(rest) = reference.strip_prefix(host) {
+                return image_path(rest).map(|name| (registry, name));
+            }
+        }
+    }
+    bare_docker_hub_image(reference).map(|name| (ContainerRegistry::DockerHub, name))
+}
+
+/// `user/app:tag` style references without a registry host resolve to Docker Hub.
+fn bare_docker_hub_image(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+    if value.contains('=') || value.contains("${{") {
         return None;
     }
-    let text = String::from_utf8_lossy(&run_output.stdout);
-    let mut parts = text.lines().next().unwrap_or("").split_whitespace();
-    let status = parts.next().unwrap_or("").to_string();
-    let timestamp = parts.next().unwrap_or("").to_string();
-    if status.is_empty() {
-        None
-    } else {
-        Some((status, timestamp))
+    let first = value.split('/').next()?;
+    if first.contains('.') || first.contains(':') || first == "localhost" {
+        return None;
     }
+    let reference = value.split(['@', ':']).next().unwrap_or(value);
+    if reference.contains(char::is_whitespace) {
+        return None;
+    }
+    image_path(reference).filter(|name| name.matches('/').count() == 1)
 }
 
-#[derive(Debug, Clone)]
-pub struct GhRunInfo {
-    pub ok: bool,
-    pub reason: Option<String>,
-    pub conclusion: Option<String>,
-    pub run_id: Option<u64>,
-    pub html_url: Option<String>,
-    pub updated_at: Option<String>,
-}
-
-pub fn gh_latest_status_json(workflow: &str) -> GhRunInfo {
-    let current_dir = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
-    gh_latest_status_json_in(&current_dir, workflow)
-}
-
-pub fn gh_latest_status_json_in(root: &Path, workflow: &str) -> GhRunInfo {
-    let gh_check = std::process::Command::new("gh")
-        .arg("--version")
-        .current_dir(root)
-        .output();
-    if gh_check.is_err() {
-        return GhRunInfo {
-            ok: false,
-            reason: Some("gh_unavailable".to_string()),
-            conclusion: None,
-            run_id: None,
-            html_url: None,
-            updated_at: None,
-        };
+fn image_path(text: &str) -> Option<String> {
+    let end = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '/' | '.' | '_' | '-')))
+        .unwrap_or(text.len());
+    let path = text[..end].trim_end_matches(['/', '.']);
+    let rest = &text[end..];
+    if rest.starts_with("${{") || rest.starts_with('$') {
+        return None;
+    }
+    let segments = path.split('/').collect::<Vec<_>>();
+    if segments.len() < 2 || segments.iter().any(|segment| segment.is_empty()) {
+        return None;
     }
-    let output = std::process::Command::new("gh")
-        .arg("run")
-        .arg("list")
-        .arg("--workflow")
-        .arg(workflow)
-        .arg("--limit")
-        .arg("1")
-        .arg("--json")
-        .arg("conclusion,updatedAt,url,databaseId")
-        .current_dir(root)
-        .output();
-    let output = match output {
-        Ok(output) => output,
-        Err(_) => {
-            return GhRunInfo {
-                ok: false,
-                reason: Some("gh_unavailable".to_string()),
-                conclusion: None,
-                run_id: None,
-                html_url: None,
-                updated_at: None,
+    Some(path.to_ascii_lowercase())
+}
+
+/// String values of a workflow, job or step `env:` mapping.
+fn step_env(value: &Value) -> HashMap<String, String> {
+    let Some(env) = value.get("env").and_then(Value::as_mapping) else {
+        return HashMap::new();
+    };
+    env.iter()
+        .filter_map(|(key, value)| {
+            let value = match value {
+                Value::String(value) => value.clone(),
+                Value::Number(value) => value.to_string(),
+                Value::Bool(value) => value.to_string(),
+                _ => return None,
+            };
+            Some((key.as_str()?.to_string(), value))
+        })
+        .collect()
+}
+
+fn expand_expressions(
+    text: &str,
+    env: &HashMap<String, String>,
+    owner: Option<&str>,
+    repo: Option<&str>,
+) -> String {
+    let mut current = text.to_string();
+    // Env values may themselves reference other env values or github context.
+    for _ in 0..4 {
+        let mut output = String::new();
+        let mut rest = current.as_str();
+        let mut changed = false;
+        while let Some(start) = rest.find("${{") {
+            let Some(len) = rest[start..].find("}}") else {
+                break;
             };
+            let expression = rest[start + 3..start + len].trim();
+            let replacement = match expression {
+                "github.repository" => owner.zip(repo).map(|(o, r)| format!("{}/{}", o, r)),
+                "github.repository_owner" => owner.map(str::to_string),
+                "github.event.repository.name" => repo.map(str::to_string),
+                _ => expression
+                    .strip_prefix("env.")
+                    .and_then(|key| env.get(key))
+                    .cloned(),
+            };
+            output.push_str(&rest[..start]);
+            match replacement {
+                Some(value) => {
+                    output.push_str(&value);
+                    changed = true;
+                }
+                None => output.push_str(&rest[start..start + len + 2]),
+            }
+            rest = &rest[start + len + 2..];
+        }
+        output.push_str(rest);
+        current = output;
+        if !changed {
+            break;
         }
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CiService, detect_ci_services, detect_gitlab_ci, glob_match, parse_workflow,
+        select_ci_workflows, workflow_badge_scope,
     };
-    if !output.status.success() {
-        return GhRunInfo {
-            ok: false,
-            reason: Some("auth_required".to_string()),
-            conclusion: None,
-            run_id: None,
-            html_url: None,
-            updated_at: None,
-        };
+    use crate::config::CiConfig;
+
+    #[test]
+    fn parses_workflow_names_triggers_and_jobs() {
+        let workflow = parse_workflow(
+            "rust.yml".to_string(),
+            "rust",
+            r#"
+name: Rust
+on:
+  push:
+    branches: [main, "release/**"]
+  pull_request:
+  workflow_dispatch:
+jobs:
+  test:
+    name: Test suite
+    runs-on: ubuntu-latest
+  lint:
+    runs-on: ubuntu-latest
+"#,
+        );
+        assert_eq!(workflow.name, "Rust");
+        assert!(workflow.named);
+        assert_eq!(workflow.jobs, vec!["Test suite", "lint"]);
+        let triggers = workflow.triggers.as_ref().unwrap();
+        assert_eq!(
+            triggers
+                .iter()
+                .map(|trigger| trigger.event.as_str())
+                .collect::<Vec<_>>(),
+            vec!["push", "pull_request", "workflow_dispatch"]
+        );
+        assert_eq!(triggers[0].branches, vec!["main", "release/**"]);
+        assert!(workflow.is_ci("main"));
+
+        let scalar = parse_workflow("a.yml".to_string(), "a", "on: push\n");
+        assert!(!scalar.named);
+        assert_eq!(scalar.name, "a");
+        assert!(scalar.is_ci("trunk"));
+        let broken = parse_workflow("b.yml".to_string(), "b", "on: [push\n");
+        assert!(broken.triggers.is_none());
+        assert!(broken.is_ci("main"));
     }
-    let text = String::from_utf8_lossy(&output.stdout).to_string();
-    let runs: Vec<GhRunPayload> = serde_json::from_str(&text).unwrap_or_default();
-    if runs.is_empty() {
-        return GhRunInfo {
-            ok: false,
-            reason: Some("no_runs".to_string()),
-            conclusion: None,
-            run_id: None,
-            html_url: None,
-            updated_at: None,
+
+    #[test]
+    fn selects_push_and_pull_request_workflows_on_the_default_branch() {
+        let workflow = |file: &str, content: &str| {
+            parse_workflow(file.to_string(), file.split('.').next().unwrap(), content)
+        };
+        let workflows = vec![
+            workflow("ci.yml", "on: [push, pull_request]\n"),
+            workflow("release.yml", "on:\n  push:\n    tags: ['v*']\n"),
+            workflow("manual.yml", "name: Manual\non: workflow_dispatch\n"),
+            workflow("nightly.yml", "on:\n  schedule:\n    - cron: '0 0 * * *'\n"),
+            workflow("docs.yml", "on:\n  push:\n    branches: [gh-pages]\n"),
+            workflow("dev.yml", "on:\n  push:\n    branches: ['*', '!main']\n"),
+        ];
+        let files = |config: &CiConfig| {
+            select_ci_workflows(workflows.clone(), "main", config)
+                .into_iter()
+                .map(|workflow| workflow.file)
+                .collect::<Vec<_>>()
         };
+        assert_eq!(files(&CiConfig::default()), vec!["ci.yml"]);
+        let config = CiConfig {
+            include: vec!["Manual".to_string(), "nightly".to_string()],
+            exclude: vec!["ci.yml".to_string()],
+            ..CiConfig::default()
+        };
+        assert_eq!(files(&config), vec!["manual.yml", "nightly.yml"]);
     }
-    let run = &runs[0];
-    GhRunInfo {
-        ok: true,
-        reason: None,
-        conclusion: run.conclusion.clone(),
-        run_id: run.database_id,
-        html_url: run.url.clone(),
-        updated_at: run.updated_at.clone(),
+
+    #[test]
+    fn pins_workflow_badges_to_configured_or_default_branch() {
+        let config: CiConfig = toml::from_str(
+            r#"
+[workflows."ci.yml"]
+event = "push"
+[workflows.Docs]
+branch = "gh-pages"
+[workflows.nightly]
+branch = ""
+"#,
+        )
+        .unwrap();
+        let scope = |file: &str, content: &str| {
+            let workflow =
+                parse_workflow(file.to_string(), file.split('.').next().unwrap(), content);
+            let (branch, event) = workflow_badge_scope(&workflow, Some("main"), &config);
+            (branch.map(str::to_string), event.map(str::to_string))
+        };
+        assert_eq!(
+            scope("ci.yml", "on: push\n"),
+            (Some("main".to_string()), Some("push".to_string()))
+        );
+        assert_eq!(
+            scope("docs.yml", "name: Docs\non: push\n"),
+            (Some("gh-pages".to_string()), None)
+        );
+        assert_eq!(scope("nightly.yml", "on: push\n"), (None, None));
+        assert_eq!(
+            scope("lint.yml", "on: push\n"),
+            (Some("main".to_string()), None)
+        );
     }
-}
 
-#[derive(Debug, Clone, serde::Deserialize)]
-struct GhRunPayload {
-    conclusion: Option<String>,
-    #[serde(rename = "databaseId")]
-    database_id: Option<u64>,
-    #[serde(rename = "updatedAt")]
-    updated_at: Option<String>,
-    url: Option<String>,
-}
+    #[test]
+    fn detects_ci_service_configuration_files() {
+        let temp = tempfile::tempdir().unwrap();
+        assert!(detect_ci_services(temp.path()).is_empty());
+        std::fs::create_dir_all(temp.path().join(".circleci")).unwrap();
+        std::fs::write(temp.path().join(".circleci/config.yml"), "version: 2.1\n").unwrap();
+        std::fs::create_dir_all(temp.path().join(".azure-pipelines")).unwrap();
+        std::fs::write(temp.path().join("Jenkinsfile"), "pipeline {}\n").unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::detects_codecov;
+        let services = detect_ci_services(temp.path())
+            .into_iter()
+            .map(|found| (found.service, found.file))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            services,
+            vec![
+                (CiService::CircleCi, ".circleci/config.yml".to_string()),
+                (CiService::AzurePipelines, ".azure-pipelines/".to_string()),
+                (CiService::Jenkins, "Jenkinsfile".to_string()),
+            ]
+        );
+    }
 
     #[test]
-    fn detects_codecov_from_config_or_workflow() {
+    fn matches_branch_filter_globs() {
+        assert!(glob_match("main", "main"));
+        assert!(glob_match("release/*", "release/1.x"));
+        assert!(!glob_match("release/*", "release/1/x"));
+        assert!(glob_match("release/**", "release/1/x"));
+        assert!(glob_match("v?", "v1"));
+        assert!(!glob_match("feature-*", "main"));
+    }
+
+    #[test]
+    fn detects_gitlab_ci_and_coverage_keyword() {
         let temp = tempfile::tempdir().unwrap();
-        assert!(!detects_codecov(temp.path()));
+        assert_eq!(detect_gitlab_ci(temp.path()), None);
 
-        std::fs::write(temp.path().join(".codecov.yml"), "coverage: {}\n").unwrap();
-        assert!(detects_codecov(temp.path()));
-        std::fs::remove_file(temp.path().join(".codecov.yml")).unwrap();
+        std::fs::write(
+            temp.path().join(".gitlab-ci.yml"),
+            "test:\n  script: cargo test\n",
+        )
+        .unwrap();
+        let ci = detect_gitlab_ci(temp.path()).expect("gitlab ci");
+        assert_eq!(ci.file, ".gitlab-ci.yml");
+        assert!(!ci.coverage);
 
-        let workflows = temp.path().join(".github/workflows");
-        std::fs::create_dir_all(&workflows).unwrap();
         std::fs::write(
-            workflows.join("ci.yml"),
-            "steps:\n  - uses: codecov/codecov-action@v5\n",
+            temp.path().join(".gitlab-ci.yml"),
+            "test:\n  script: cargo llvm-cov\n  coverage: '/TOTAL.* (\\d+%)/'\n",
         )
         .unwrap();
-        assert!(detects_codecov(temp.path()));
+        assert!(detect_gitlab_ci(temp.path()).expect("gitlab ci").coverage);
     }
 }
@@ -194,3 +194,26 @@ fn catalog_add_url_adds_one_off_external_badge() {
         stdout.contains("[![health](https://example.com/health.svg)](https://example.com/health)")
     );
 }
+
+#[test]
+fn catalog_add_url_warns_about_malformed_config() {
+    let temp = fixture();
+    std::fs::write(temp.path().join(".bdg.toml"), "readme = [\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args([
+            "catalog",
+            "add-url",
+            "https://example.com/health.svg",
+            "--label",
+            "health",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("warning: invalid config"), "{stderr}");
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(readme.contains("![health](https://example.com/health.svg)"));
+}
@@ -71,19 +71,25 @@ repository = "https://github.com/f4ah6o/bdg-rs"
 }
 
 #[test]
-fn sync_default_does_not_add_optional_repository_badges() {
+fn sync_adds_static_toolchain_badges_from_local_manifests() {
     let temp = tempfile::tempdir().unwrap();
     std::fs::write(
         temp.path().join("Cargo.toml"),
         r#"
 [package]
-name = "bdg-sync-default-fixture"
+name = "bdg-toolchain-fixture-unpublished"
 version = "0.1.0"
-license = "MIT"
-repository = "https://github.com/f4ah6o/bdg-rs"
+edition = "2024"
+rust-version = "1.85"
+publish = false
 "#,
     )
     .unwrap();
+    std::fs::write(
+        temp.path().join("package.json"),
+        r#"{ "name": "bdg-toolchain-fixture-unpublished", "engines": { "node": ">=20" } }"#,
+    )
+    .unwrap();
     std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
 
     let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
@@ -94,197 +100,1555 @@ repository = "https://github.com/f4ah6o/bdg-rs"
 
     assert_eq!(output.status.code(), Some(2));
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("img.shields.io/crates/v/bdg-sync-default-fixture.svg"));
-    assert!(!stdout.contains("img.shields.io/crates/msrv/bdg-sync-default-fixture.svg"));
-    assert!(!stdout.contains("img.shields.io/github/stars/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/github/forks/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/github/issues/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/github/issues-pr/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/github/last-commit/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/github/downloads/f4ah6o/bdg-rs/total.svg"));
+    assert!(stdout.contains("img.shields.io/badge/rustc-1.85%2B-orange.svg"));
+    assert!(stdout.contains("img.shields.io/badge/edition-2024-orange.svg"));
+    assert!(stdout.contains("img.shields.io/badge/node-%3E%3D20-339933.svg"));
+    assert!(!stdout.contains("img.shields.io/crates/msrv/"));
 }
 
 #[test]
-fn sync_only_can_select_optional_badges() {
+fn sync_adds_gitlab_badges_from_origin_remote_and_gitlab_ci() {
     let temp = tempfile::tempdir().unwrap();
     std::fs::write(
         temp.path().join("Cargo.toml"),
         r#"
 [package]
-name = "bdg-sync-optional-fixture"
+name = "bdg-gitlab-fixture-unpublished"
 version = "0.1.0"
-repository = "https://github.com/f4ah6o/bdg-rs"
+publish = false
 "#,
     )
     .unwrap();
     std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::write(
+        temp.path().join(".gitlab-ci.yml"),
+        "test:\n  script: cargo test\n  coverage: '/^TOTAL.*\\s(\\d+%)$/'\n",
+    )
+    .unwrap();
+    for args in [
+        &["init", "--quiet", "--initial-branch", "main"][..],
+        &[
+            "remote",
+            "add",
+            "origin",
+            "git@gitlab.com:group/sub/app.git",
+        ][..],
+    ] {
+        let status = Command::new("git")
+            .current_dir(temp.path())
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
 
     let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
         .current_dir(temp.path())
-        .args(["sync", "--only", "msrv,stars,issues,activity", "--dry-run"])
+        .args(["sync", "--dry-run"])
         .output()
         .unwrap();
 
     assert_eq!(output.status.code(), Some(2));
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("img.shields.io/crates/msrv/bdg-sync-optional-fixture.svg"));
-    assert!(stdout.contains("img.shields.io/github/stars/f4ah6o/bdg-rs.svg"));
-    assert!(stdout.contains("img.shields.io/github/issues/f4ah6o/bdg-rs.svg"));
-    assert!(stdout.contains("img.shields.io/github/last-commit/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/github/forks/f4ah6o/bdg-rs.svg"));
+    assert!(stdout.contains(
+        "[![pipeline](https://gitlab.com/group/sub/app/badges/main/pipeline.svg)](https://gitlab.com/group/sub/app/-/commits/main)"
+    ));
+    assert!(stdout.contains("https://gitlab.com/group/sub/app/badges/main/coverage.svg"));
+    assert!(stdout.contains("https://gitlab.com/group/sub/app/-/badges/release.svg"));
+    assert!(stdout.contains("img.shields.io/gitlab/license/group/sub/app.svg"));
+    assert!(!stdout.contains("img.shields.io/github/"));
 }
 
 #[test]
-fn add_yes_honors_configured_badge_exclusions() {
+fn gitlab_badges_pin_the_checked_out_branch_when_the_default_is_unknown() {
     let temp = tempfile::tempdir().unwrap();
     std::fs::write(
         temp.path().join("Cargo.toml"),
         r#"
 [package]
-name = "bdg-exclusions-fixture"
+name = "bdg-gitlab-branch-fixture-unpublished"
+version = "0.1.0"
+publish = false
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::write(
+        temp.path().join(".gitlab-ci.yml"),
+        "test:\n  script: make\n",
+    )
+    .unwrap();
+    for args in [
+        &["init", "--quiet", "--initial-branch", "trunk"][..],
+        &["remote", "add", "origin", "git@gitlab.com:group/app.git"][..],
+        &["add", "."][..],
+        &[
+            "-c",
+            "user.name=bdg",
+            "-c",
+            "user.email=bdg@example.com",
+            "commit",
+            "--quiet",
+            "-m",
+            "init",
+        ][..],
+    ] {
+        let status = Command::new("git")
+            .current_dir(temp.path())
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+    let bdg = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    assert!(bdg(&["sync", "--only", "ci"]).status.success());
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(readme.contains("https://gitlab.com/group/app/badges/trunk/pipeline.svg"));
+
+    let output = bdg(&["check", "--strict"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        bdg(&["sync", "--only", "ci", "--check"]).status.code(),
+        Some(0)
+    );
+}
+
+#[test]
+fn sync_adds_forgejo_and_woodpecker_badges_for_configured_self_hosted_forge() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-forgejo-fixture-unpublished"
 version = "0.1.0"
 license = "MIT"
-repository = "https://github.com/f4ah6o/bdg-rs"
+publish = false
 "#,
     )
     .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
     std::fs::write(
         temp.path().join(".bdg.toml"),
         r#"
-[badges]
-exclude = ["release", "coverage"]
+[forge]
+kind = "forgejo"
+url = "https://git.example.com"
+woodpecker_url = "https://ci.example.com"
 "#,
     )
     .unwrap();
-    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    let workflows = temp.path().join(".forgejo/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(workflows.join("ci.yml"), "on: [push]\n").unwrap();
+    std::fs::write(temp.path().join(".woodpecker.yml"), "steps: {}\n").unwrap();
+    for args in [
+        &["init", "--quiet"][..],
+        &[
+            "remote",
+            "add",
+            "origin",
+            "ssh://git@git.example.com:2222/team/app.git",
+        ][..],
+    ] {
+        let status = Command::new("git")
+            .current_dir(temp.path())
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
 
     let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
         .current_dir(temp.path())
-        .args(["add", "--yes", "--dry-run"])
+        .args(["sync", "--dry-run"])
         .output()
         .unwrap();
 
     assert_eq!(output.status.code(), Some(2));
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("img.shields.io/crates/v/bdg-exclusions-fixture.svg"));
-    assert!(stdout.contains("img.shields.io/crates/d/bdg-exclusions-fixture.svg"));
-    assert!(!stdout.contains("img.shields.io/github/v/release/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/codecov/c/github/f4ah6o/bdg-rs.svg"));
-    assert!(output.stderr.is_empty());
+    assert!(stdout.contains(
+        "[![CI](https://git.example.com/team/app/actions/workflows/ci.yml/badge.svg)](https://git.example.com/team/app/actions?workflow=ci.yml)"
+    ));
+    assert!(stdout.contains(
+        "[![Woodpecker CI](https://ci.example.com/api/badges/team/app/status.svg)](https://ci.example.com/repos/team/app)"
+    ));
+    assert!(stdout.contains(
+        "img.shields.io/gitea/v/release/team/app.svg?gitea_url=https%3A%2F%2Fgit.example.com"
+    ));
+    assert!(!stdout.contains("img.shields.io/github/"));
 }
 
 #[test]
-fn add_only_overrides_configured_badge_exclusions() {
+fn github_enterprise_badges_follow_remote_host_and_configured_shields() {
     let temp = tempfile::tempdir().unwrap();
     std::fs::write(
         temp.path().join("Cargo.toml"),
         r#"
 [package]
-name = "bdg-only-exclusions-fixture"
+name = "bdg-ghe-fixture-unpublished"
 version = "0.1.0"
-repository = "https://github.com/f4ah6o/bdg-rs"
+publish = false
 "#,
     )
     .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(workflows.join("ci.yml"), "on: [push]\n").unwrap();
+    for args in [
+        &["init", "--quiet"][..],
+        &[
+            "remote",
+            "add",
+            "origin",
+            "git@ghe.corp.example:team/app.git",
+        ][..],
+    ] {
+        let status = Command::new("git")
+            .current_dir(temp.path())
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+    let sync = || {
+        let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(["sync", "--only", "ci,release,stars", "--dry-run"])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2));
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[forge.hosts.\"ghe.corp.example\"]\nkind = \"github\"\n",
+    )
+    .unwrap();
+    let stdout = sync();
+    assert!(stdout.contains(
+        "[![CI](https://ghe.corp.example/team/app/actions/workflows/ci.yml/badge.svg)](https://ghe.corp.example/team/app/actions/workflows/ci.yml)"
+    ));
+    assert!(!stdout.contains("shields"));
+
     std::fs::write(
         temp.path().join(".bdg.toml"),
         r#"
-[badges]
-exclude = ["coverage"]
+[forge.hosts."ghe.corp.example"]
+kind = "github"
+shields_url = "https://shields.corp.example"
+"#,
+    )
+    .unwrap();
+    let stdout = sync();
+    assert!(stdout.contains(
+        "[![release](https://shields.corp.example/github/v/release/team/app.svg)](https://ghe.corp.example/team/app/releases)"
+    ));
+    assert!(stdout.contains("https://shields.corp.example/github/stars/team/app.svg"));
+    assert!(!stdout.contains("github.com"));
+}
+
+#[test]
+fn sync_default_does_not_add_optional_repository_badges() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-sync-default-fixture"
+version = "0.1.0"
+license = "MIT"
+repository = "https://github.com/f4ah6o/bdg-rs"
 "#,
     )
     .unwrap();
     std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
-    std::fs::write(temp.path().join(".codecov.yml"), "coverage: {}\n").unwrap();
 
     let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
         .current_dir(temp.path())
-        .args(["add", "--yes", "--only", "coverage", "--dry-run"])
+        .args(["sync", "--dry-run"])
         .output()
         .unwrap();
 
     assert_eq!(output.status.code(), Some(2));
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("img.shields.io/codecov/c/github/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/crates/v/bdg-only-exclusions-fixture.svg"));
-    assert!(output.stderr.is_empty());
+    assert!(stdout.contains("img.shields.io/crates/v/bdg-sync-default-fixture.svg"));
+    assert!(!stdout.contains("img.shields.io/crates/msrv/bdg-sync-default-fixture.svg"));
+    assert!(!stdout.contains("img.shields.io/github/stars/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/github/forks/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/github/issues/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/github/issues-pr/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/github/last-commit/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/github/downloads/f4ah6o/bdg-rs/total.svg"));
 }
 
 #[test]
-fn add_only_coverage_filters_practical_badges() {
+fn sync_only_can_select_optional_badges() {
     let temp = tempfile::tempdir().unwrap();
     std::fs::write(
         temp.path().join("Cargo.toml"),
         r#"
 [package]
-name = "bdg-coverage-fixture"
+name = "bdg-sync-optional-fixture"
 version = "0.1.0"
 repository = "https://github.com/f4ah6o/bdg-rs"
 "#,
     )
     .unwrap();
     std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
-    std::fs::write(temp.path().join(".codecov.yml"), "coverage: {}\n").unwrap();
 
     let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
         .current_dir(temp.path())
-        .args(["add", "--yes", "--only", "coverage", "--dry-run"])
+        .args(["sync", "--only", "msrv,stars,issues,activity", "--dry-run"])
         .output()
         .unwrap();
 
     assert_eq!(output.status.code(), Some(2));
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("img.shields.io/codecov/c/github/f4ah6o/bdg-rs.svg"));
-    assert!(!stdout.contains("img.shields.io/crates/v/bdg-coverage-fixture.svg"));
-    assert!(!stdout.contains("img.shields.io/github/v/release/f4ah6o/bdg-rs.svg"));
-    assert!(output.stderr.is_empty());
+    assert!(stdout.contains("img.shields.io/crates/msrv/bdg-sync-optional-fixture.svg"));
+    assert!(stdout.contains("img.shields.io/github/stars/f4ah6o/bdg-rs.svg"));
+    assert!(stdout.contains("img.shields.io/github/issues/f4ah6o/bdg-rs.svg"));
+    assert!(stdout.contains("img.shields.io/github/last-commit/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/github/forks/f4ah6o/bdg-rs.svg"));
 }
 
 #[test]
-fn help_command_prints_usage() {
+fn rust_crate_with_npm_wrapper_merges_metadata_across_ecosystems() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-multi-fixture"
+version = "0.1.0"
+license = "MIT"
+repository = "https://github.com/f4ah6o/bdg-multi-fixture"
+"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(temp.path().join("pkg")).unwrap();
+    std::fs::write(
+        temp.path().join("pkg/package.json"),
+        r#"{ "name": "bdg-multi-fixture-wasm", "version": "0.1.0" }"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+
     let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
-        .arg("--help")
+        .current_dir(temp.path())
+        .args(["sync", "--dry-run"])
         .output()
         .unwrap();
 
-    assert!(output.status.success());
+    assert_eq!(output.status.code(), Some(2));
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("Usage:"));
-    assert!(stdout.contains("bdg <COMMAND> [OPTIONS]"));
-    assert!(stdout.contains("remove"));
-    assert!(stdout.contains("msrv, stars, forks, issues, pulls, activity"));
-    assert!(output.stderr.is_empty());
+    assert!(stdout.contains("img.shields.io/crates/v/bdg-multi-fixture.svg"));
+    assert!(stdout.contains("img.shields.io/badge/license-MIT-blue.svg"));
+    assert!(stdout.contains("img.shields.io/github/v/release/f4ah6o/bdg-multi-fixture.svg"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["list", "--json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ecosystems = value["ecosystems"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|ecosystem| ecosystem["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(ecosystems, vec!["node", "rust"]);
+    assert_eq!(value["ecosystems"][0]["primary"], true);
 }
 
 #[test]
-fn version_command_prints_package_version() {
+fn sync_workspace_updates_member_readmes_and_root_aggregate() {
+    let temp = tempfile::tempdir().unwrap();
+    let root = temp.path();
+    std::fs::write(
+        root.join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.package]
+license = "MIT"
+repository = "https://github.com/f4ah6o/bdg-workspace-fixture"
+"#,
+    )
+    .unwrap();
+    for (dir, extra) in [
+        ("core", ""),
+        ("cli", "readme = \"USAGE.md\"\n"),
+        ("internal", "publish = false\n"),
+    ] {
+        let member = root.join("crates").join(dir);
+        std::fs::create_dir_all(&member).unwrap();
+        std::fs::write(
+            member.join("Cargo.toml"),
+            format!(
+                "[package]\nname = \"bdg-ws-{dir}\"\nversion = \"0.1.0\"\nlicense.workspace = true\n{extra}"
+            ),
+        )
+        .unwrap();
+    }
+    std::fs::write(root.join("crates/core/README.md"), "# core\n").unwrap();
+    std::fs::write(root.join("crates/cli/USAGE.md"), "# cli\n").unwrap();
+    std::fs::write(root.join("crates/internal/README.md"), "# internal\n").unwrap();
+    std::fs::write(root.join("README.md"), "# workspace\n").unwrap();
+
     let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
-        .arg("--version")
+        .current_dir(root)
+        .args(["sync", "--workspace", "--dry-run"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("+++ b/README.md"));
+    assert!(stdout.contains("+++ b/crates/core/README.md"));
+    assert!(stdout.contains("+++ b/crates/cli/USAGE.md"));
+    assert!(stdout.contains("+++ b/crates/internal/README.md"));
+    assert!(stdout.contains("img.shields.io/crates/d/bdg-ws-core.svg"));
+    assert!(!stdout.contains("img.shields.io/crates/v/bdg-ws-internal.svg"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(root)
+        .args(["sync", "--workspace", "--dry-run", "--json"])
         .output()
         .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["schema"], "bdg.dryrun.workspace/v1");
+    let plans = value["plans"].as_array().unwrap();
+    assert_eq!(plans.len(), 4, "{value}");
+    assert!(plans.iter().all(|plan| plan["schema"] == "bdg.dryrun/v1"));
+    assert!(plans.iter().any(|plan| {
+        plan["diff"]
+            .as_str()
+            .unwrap()
+            .contains("+++ b/crates/cli/USAGE.md")
+    }));
 
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(root)
+        .args(["sync", "--workspace"])
+        .output()
+        .unwrap();
     assert!(output.status.success());
+
+    let root_readme = std::fs::read_to_string(root.join("README.md")).unwrap();
+    assert!(root_readme.contains("img.shields.io/crates/v/bdg-ws-core.svg"));
+    assert!(root_readme.contains("img.shields.io/crates/v/bdg-ws-cli.svg"));
+    assert!(!root_readme.contains("img.shields.io/crates/d/"));
+    let usage = std::fs::read_to_string(root.join("crates/cli/USAGE.md")).unwrap();
+    assert!(usage.contains("img.shields.io/crates/v/bdg-ws-cli.svg"));
+    assert!(usage.contains("img.shields.io/badge/license-MIT-blue.svg"));
+    let internal = std::fs::read_to_string(root.join("crates/internal/README.md")).unwrap();
+    assert!(internal.contains("img.shields.io/badge/license-MIT-blue.svg"));
+    assert!(!internal.contains("crates.io"));
+}
+
+#[test]
+fn sync_workspace_writes_npm_workspace_package_readmes() {
+    let temp = tempfile::tempdir().unwrap();
+    let root = temp.path();
+    std::fs::write(
+        root.join("package.json"),
+        r#"{ "name": "bdg-npm-ws-root", "private": true, "workspaces": ["packages/*", "!packages/skip"] }"#,
+    )
+    .unwrap();
+    for (dir, manifest) in [
+        ("lib", r#"{ "name": "bdg-npm-ws-lib", "license": "MIT" }"#),
+        (
+            "internal",
+            r#"{ "name": "bdg-npm-ws-internal", "private": true, "license": "MIT" }"#,
+        ),
+        ("skip", r#"{ "name": "bdg-npm-ws-skip", "license": "MIT" }"#),
+    ] {
+        let package = root.join("packages").join(dir);
+        std::fs::create_dir_all(&package).unwrap();
+        std::fs::write(package.join("package.json"), manifest).unwrap();
+        std::fs::write(package.join("README.md"), format!("# {dir}\n")).unwrap();
+    }
+    std::fs::write(root.join("README.md"), "# workspace\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(root)
+        .args(["sync", "--workspace", "--dry-run"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("+++ b/packages/lib/README.md"));
+    assert!(!stdout.contains("packages/internal/README.md"));
+    assert!(!stdout.contains("packages/skip/README.md"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(root)
+        .args(["list", "--json"])
+        .output()
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
     assert_eq!(
-        String::from_utf8(output.stdout).unwrap(),
-        format!(
-            "bdg {} ({})\n",
-            env!("CARGO_PKG_VERSION"),
-            bdg::build_info::RELEASE_COMMIT
-        )
+        value["manifests"]["node_workspace"]["source"],
+        "package.json"
     );
-    assert!(output.stderr.is_empty());
+    let names = value["manifests"]["node_packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|package| package["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["bdg-npm-ws-lib"]);
 }
 
 #[test]
-fn parse_error_exits_with_code_two() {
+fn sync_detects_python_project_badges() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("pyproject.toml"),
+        r#"
+[project]
+name = "bdg-python-fixture"
+version = "0.1.0"
+license = "MIT"
+
+[project.urls]
+Repository = "https://github.com/f4ah6o/bdg-rs"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+
     let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
-        .args(["add", "--unknown"])
+        .current_dir(temp.path())
+        .args(["sync", "--dry-run"])
         .output()
         .unwrap();
 
     assert_eq!(output.status.code(), Some(2));
-    let stderr = String::from_utf8(output.stderr).unwrap();
-    assert!(stderr.contains("unexpected argument `--unknown`"));
-    assert!(stderr.contains("Usage:"));
-    assert!(output.stdout.is_empty());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("img.shields.io/pypi/v/bdg-python-fixture.svg"));
+    assert!(stdout.contains("img.shields.io/pypi/dm/bdg-python-fixture.svg"));
+    assert!(stdout.contains("img.shields.io/pypi/pyversions/bdg-python-fixture.svg"));
+    assert!(stdout.contains("img.shields.io/github/v/release/f4ah6o/bdg-rs.svg"));
+}
+
+#[test]
+fn sync_detects_go_module_badges_and_infers_repo_from_module_path() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("go.mod"),
+        "module github.com/f4ah6o/bdg-go-fixture\n\ngo 1.22\n",
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["sync", "--dry-run"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("https://pkg.go.dev/badge/github.com/f4ah6o/bdg-go-fixture.svg"));
+    assert!(stdout.contains("img.shields.io/badge/go-1.22-00ADD8.svg"));
+    assert!(stdout.contains("https://goreportcard.com/badge/github.com/f4ah6o/bdg-go-fixture"));
+    assert!(stdout.contains("img.shields.io/github/license/f4ah6o/bdg-go-fixture.svg"));
+    assert!(stdout.contains("img.shields.io/github/v/release/f4ah6o/bdg-go-fixture.svg"));
+}
+
+#[test]
+fn sync_detects_container_image_from_build_push_workflow() {
+    let temp = tempfile::tempdir().unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(
+        temp.path().join("go.mod"),
+        "module github.com/f4ah6o/bdg-image-fixture\n",
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("Dockerfile"), "FROM scratch\n").unwrap();
+    std::fs::write(
+        workflows.join("image.yml"),
+        "jobs:\n  push:\n    steps:\n      - uses: docker/build-push-action@v6\n        with:\n          tags: ghcr.io/${{ github.repository }}:latest\n",
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["sync", "--dry-run"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("https://ghcr-badge.egpl.dev/f4ah6o/bdg-image-fixture/latest_tag"));
+    assert!(!stdout.contains("/size"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["sync", "--dry-run", "--only", "container"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("https://ghcr-badge.egpl.dev/f4ah6o/bdg-image-fixture/size"));
+
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[badges]\nghcr_url = \"https://ghcr-badge.corp.example\"\n",
+    )
+    .unwrap();
+    let sync = || {
+        let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(["sync", "--dry-run", "--only", "container"])
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+    assert!(sync().contains("https://ghcr-badge.corp.example/f4ah6o/bdg-image-fixture/latest_tag"));
+
+    std::fs::write(temp.path().join(".bdg.toml"), "[badges]\nghcr_url = \"\"\n").unwrap();
+    assert!(!sync().contains("ghcr"));
+}
+
+#[test]
+fn sync_detects_jsr_package_badges() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("deno.json"),
+        r#"{ "name": "@f4ah6o/bdg-jsr-fixture", "version": "0.1.0", "exports": "./mod.ts" }"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["sync", "--dry-run"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("https://jsr.io/badges/@f4ah6o/bdg-jsr-fixture)"));
+    assert!(stdout.contains("https://jsr.io/badges/@f4ah6o/bdg-jsr-fixture/score"));
+}
+
+#[test]
+fn add_yes_honors_configured_badge_exclusions() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-exclusions-fixture"
+version = "0.1.0"
+license = "MIT"
+repository = "https://github.com/f4ah6o/bdg-rs"
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        r#"
+[badges]
+exclude = ["release", "coverage"]
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["add", "--yes", "--dry-run"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("img.shields.io/crates/v/bdg-exclusions-fixture.svg"));
+    assert!(stdout.contains("img.shields.io/crates/d/bdg-exclusions-fixture.svg"));
+    assert!(!stdout.contains("img.shields.io/github/v/release/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/codecov/c/github/f4ah6o/bdg-rs.svg"));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn add_only_overrides_configured_badge_exclusions() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-only-exclusions-fixture"
+version = "0.1.0"
+repository = "https://github.com/f4ah6o/bdg-rs"
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        r#"
+[badges]
+exclude = ["coverage"]
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::write(temp.path().join(".codecov.yml"), "coverage: {}\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["add", "--yes", "--only", "coverage", "--dry-run"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("img.shields.io/codecov/c/github/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/crates/v/bdg-only-exclusions-fixture.svg"));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn add_only_coverage_filters_practical_badges() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-coverage-fixture"
+version = "0.1.0"
+repository = "https://github.com/f4ah6o/bdg-rs"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::write(temp.path().join(".codecov.yml"), "coverage: {}\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["add", "--yes", "--only", "coverage", "--dry-run"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("img.shields.io/codecov/c/github/f4ah6o/bdg-rs.svg"));
+    assert!(!stdout.contains("img.shields.io/crates/v/bdg-coverage-fixture.svg"));
+    assert!(!stdout.contains("img.shields.io/github/v/release/f4ah6o/bdg-rs.svg"));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn help_command_prints_usage() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .arg("--help")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Usage:"));
+    assert!(stdout.contains("bdg <COMMAND> [OPTIONS]"));
+    assert!(stdout.contains("remove"));
+    assert!(stdout.contains("msrv, stars, forks, issues, pulls, activity"));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn version_command_prints_package_version() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .arg("--version")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!(
+            "bdg {} ({})\n",
+            env!("CARGO_PKG_VERSION"),
+            bdg::build_info::RELEASE_COMMIT
+        )
+    );
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn parse_error_exits_with_code_two() {
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .args(["add", "--unknown"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("unexpected argument `--unknown`"));
+    assert!(stderr.contains("Usage:"));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn sync_badges_only_ci_workflows_labelled_by_workflow_name() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-workflow-selection-fixture"
+version = "0.1.0"
+publish = false
+repository = "https://github.com/owner/app"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(
+        workflows.join("rust.yml"),
+        "name: Rust\non:\n  push:\n    branches: [main]\n  pull_request:\n",
+    )
+    .unwrap();
+    std::fs::write(workflows.join("lint.yml"), "on: pull_request\n").unwrap();
+    std::fs::write(
+        workflows.join("release.yml"),
+        "name: Release\non:\n  push:\n    tags: ['v*']\n",
+    )
+    .unwrap();
+    std::fs::write(workflows.join("automerge.yml"), "on: pull_request_target\n").unwrap();
+    std::fs::write(workflows.join("deploy.yml"), "on: workflow_dispatch\n").unwrap();
+
+    let sync = || {
+        let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(["sync", "--only", "ci", "--dry-run"])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2));
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let stdout = sync();
+    assert!(stdout.contains(
+        "[![Rust](https://github.com/owner/app/actions/workflows/rust.yml/badge.svg)](https://github.com/owner/app/actions/workflows/rust.yml)"
+    ));
+    assert!(
+        stdout
+            .contains("[![CI](https://github.com/owner/app/actions/workflows/lint.yml/badge.svg)")
+    );
+    for file in ["release.yml", "automerge.yml", "deploy.yml"] {
+        assert!(!stdout.contains(file), "{file}");
+    }
+
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[ci]\ninclude = [\"Release\"]\nexclude = [\"lint\"]\n",
+    )
+    .unwrap();
+    let stdout = sync();
+    assert!(stdout.contains("actions/workflows/rust.yml/badge.svg"));
+    assert!(stdout.contains(
+        "[![Release](https://github.com/owner/app/actions/workflows/release.yml/badge.svg)"
+    ));
+    assert!(!stdout.contains("lint.yml"));
+
+    // Once the remote default branch is known, badges report on it only.
+    let git = temp.path().join(".git");
+    std::fs::create_dir_all(git.join("refs/remotes/origin")).unwrap();
+    std::fs::write(git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+    std::fs::write(
+        git.join("config"),
+        "[remote \"origin\"]\n\turl = https://github.com/owner/app.git\n",
+    )
+    .unwrap();
+    std::fs::write(
+        git.join("refs/remotes/origin/HEAD"),
+        "ref: refs/remotes/origin/trunk\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[ci]\ninclude = [\"Release\"]\nexclude = [\"lint\"]\n[ci.workflows.release]\nbranch = \"\"\nevent = \"push\"\n",
+    )
+    .unwrap();
+    let stdout = sync();
+    assert!(stdout.contains(
+        "[![Rust](https://github.com/owner/app/actions/workflows/rust.yml/badge.svg?branch=trunk)](https://github.com/owner/app/actions/workflows/rust.yml?query=branch%3Atrunk)"
+    ));
+    assert!(stdout.contains(
+        "[![Release](https://github.com/owner/app/actions/workflows/release.yml/badge.svg?event=push)](https://github.com/owner/app/actions/workflows/release.yml?query=event%3Apush)"
+    ));
+}
+
+#[test]
+fn sync_adds_badges_for_detected_ci_services() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-ci-services-fixture"
+version = "0.1.0"
+publish = false
+repository = "https://github.com/owner/app"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::create_dir_all(temp.path().join(".circleci")).unwrap();
+    std::fs::write(temp.path().join(".circleci/config.yml"), "version: 2.1\n").unwrap();
+    std::fs::write(temp.path().join(".travis.yml"), "language: rust\n").unwrap();
+    std::fs::write(temp.path().join("azure-pipelines.yml"), "pool: {}\n").unwrap();
+    std::fs::create_dir_all(temp.path().join(".buildkite")).unwrap();
+    std::fs::write(temp.path().join(".buildkite/pipeline.yml"), "steps: []\n").unwrap();
+    std::fs::write(temp.path().join("Jenkinsfile"), "pipeline {}\n").unwrap();
+
+    let sync = || {
+        let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(["sync", "--only", "ci", "--dry-run"])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2));
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let stdout = sync();
+    assert!(stdout.contains(
+        "[![CircleCI](https://dl.circleci.com/status-badge/img/gh/owner/app.svg?style=svg)](https://dl.circleci.com/status-badge/redirect/gh/owner/app)"
+    ));
+    assert!(stdout.contains(
+        "[![Travis CI](https://app.travis-ci.com/owner/app.svg)](https://app.travis-ci.com/owner/app)"
+    ));
+    // These need settings the repository cannot provide.
+    for host in ["dev.azure.com", "buildkite", "buildStatus"] {
+        assert!(!stdout.contains(host), "{host}");
+    }
+
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        r#"
+[ci.azure_pipelines]
+organization = "org"
+project = "proj"
+
+[ci.buildkite]
+badge = "abc123"
+pipeline = "org/app"
+
+[ci.jenkins]
+url = "https://jenkins.example.com"
+"#,
+    )
+    .unwrap();
+    let stdout = sync();
+    assert!(stdout.contains(
+        "[![Azure Pipelines](https://dev.azure.com/org/proj/_apis/build/status/owner.app)](https://dev.azure.com/org/proj/_build)"
+    ));
+    assert!(stdout.contains(
+        "[![Buildkite](https://badge.buildkite.com/abc123.svg)](https://buildkite.com/org/app)"
+    ));
+    assert!(stdout.contains(
+        "[![Jenkins](https://jenkins.example.com/buildStatus/icon?job=app)](https://jenkins.example.com/job/app/)"
+    ));
+}
+
+#[test]
+fn ci_service_badges_report_on_the_default_branch_from_a_feature_branch() {
+    let temp = tempfile::tempdir().unwrap();
+    let git = temp.path().join(".git");
+    std::fs::create_dir_all(git.join("refs/remotes/origin")).unwrap();
+    std::fs::write(
+        git.join("refs/remotes/origin/HEAD"),
+        "ref: refs/remotes/origin/main\n",
+    )
+    .unwrap();
+    std::fs::write(
+        git.join("config"),
+        "[remote \"origin\"]\n\turl = https://github.com/owner/app.git\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        "[package]\nname = \"bdg-ci-branch-fixture\"\nversion = \"0.1.0\"\npublish = false\n",
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::create_dir_all(temp.path().join(".circleci")).unwrap();
+    std::fs::write(temp.path().join(".circleci/config.yml"), "version: 2.1\n").unwrap();
+    std::fs::write(temp.path().join(".travis.yml"), "language: rust\n").unwrap();
+    let bdg = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    std::fs::write(git.join("HEAD"), "ref: refs/heads/topic\n").unwrap();
+    assert!(bdg(&["sync", "--only", "ci"]).status.success());
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(readme.contains("/status-badge/img/gh/owner/app/tree/main.svg"));
+    assert!(readme.contains("https://app.travis-ci.com/owner/app.svg?branch=main"));
+    assert!(!readme.contains("topic"));
+
+    std::fs::write(git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+    assert_eq!(
+        bdg(&["sync", "--only", "ci", "--check"]).status.code(),
+        Some(0)
+    );
+}
+
+#[test]
+fn sync_badges_each_detected_coverage_provider_unless_one_is_configured() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-coverage-fixture"
+version = "0.1.0"
+publish = false
+repository = "https://github.com/owner/app"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(
+        workflows.join("test.yml"),
+        "on: push\njobs:\n  test:\n    steps:\n      - uses: coverallsapp/github-action@v2\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join("sonar-project.properties"),
+        "sonar.organization=owner\nsonar.projectKey=owner_app-core\n",
+    )
+    .unwrap();
+
+    let sync = || {
+        let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(["sync", "--only", "coverage", "--dry-run"])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2));
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let stdout = sync();
+    assert!(stdout.contains(
+        "[![coveralls](https://img.shields.io/coveralls/github/owner/app.svg)](https://coveralls.io/github/owner/app)"
+    ));
+    assert!(stdout.contains("img.shields.io/sonar/coverage/owner_app-core.svg"));
+    assert!(!stdout.contains("codecov"));
+
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[coverage]\nprovider = \"codecov\"\n\n[coverage.codecov]\nurl = \"https://codecov.corp.example\"\n",
+    )
+    .unwrap();
+    let stdout = sync();
+    assert!(stdout.contains(
+        "[![codecov](https://codecov.corp.example/gh/owner/app/graph/badge.svg)](https://codecov.corp.example/gh/owner/app)"
+    ));
+    assert!(!stdout.contains("coveralls"));
+    assert!(!stdout.contains("sonar"));
+}
+
+#[test]
+fn sync_applies_style_settings_to_shields_badges() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-style-fixture"
+version = "0.1.0"
+edition = "2024"
+license = "MIT"
+repository = "https://github.com/owner/app"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(
+        workflows.join("ci.yml"),
+        "name: CI\non: push\njobs:\n  test:\n    steps:\n      - run: cargo test\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[style]\nstyle = \"flat-square\"\nlabelColor = \"555\"\n\n[style.kinds.version]\nlogo = \"rust\"\nlabelColor = \"\"\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .arg("sync")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(readme.contains(
+        "[![crates.io](https://img.shields.io/crates/v/bdg-style-fixture.svg?style=flat-square&logo=rust)](https://crates.io/crates/bdg-style-fixture)"
+    ));
+    assert!(readme.contains(
+        "[![license](https://img.shields.io/badge/license-MIT-blue.svg?style=flat-square&labelColor=555)](https://github.com/owner/app)"
+    ));
+    // Badges served by other hosts take no shields.io parameters.
+    assert!(readme.contains(
+        "[![docs.rs](https://docs.rs/bdg-style-fixture/badge.svg)](https://docs.rs/bdg-style-fixture)"
+    ));
+    assert!(readme.contains(
+        "[![CI](https://github.com/owner/app/actions/workflows/ci.yml/badge.svg)](https://github.com/owner/app/actions/workflows/ci.yml)"
+    ));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["sync", "--check"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+}
+
+#[test]
+fn badges_render_on_the_configured_service_and_keep_their_ids() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-service-fixture"
+version = "0.1.0"
+edition = "2024"
+license = "MIT"
+repository = "https://github.com/owner/app"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[badges]\nservice = \"badgen\"\nurl = \"https://badgen.corp.example\"\n",
+    )
+    .unwrap();
+    let bdg = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    assert!(bdg(&["sync"]).status.success());
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(readme.contains(
+        "[![crates.io](https://badgen.corp.example/crates/v/bdg-service-fixture)](https://crates.io/crates/bdg-service-fixture)"
+    ));
+    assert!(readme.contains(
+        "[![license](https://badgen.corp.example/badge/license/MIT/blue)](https://github.com/owner/app)"
+    ));
+    assert!(readme.contains("(https://badgen.corp.example/github/release/owner/app)"));
+    assert!(readme.contains("(https://docs.rs/bdg-service-fixture/badge.svg)"));
+
+    let output = bdg(&["list", "--json"]);
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ids = value["readme_block"]["badges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|badge| badge["id"].as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert!(
+        ids.contains(&"crates:bdg-service-fixture".to_string()),
+        "{ids:?}"
+    );
+    assert!(ids.contains(&"license:static".to_string()), "{ids:?}");
+    assert!(ids.contains(&"release:github".to_string()), "{ids:?}");
+
+    assert!(bdg(&["remove", "--kind", "license"]).status.success());
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(!readme.contains("badge/license"), "{readme}");
+    assert!(readme.contains("crates/v/bdg-service-fixture"));
+}
+
+#[test]
+fn html_render_mode_writes_a_centered_block_that_check_and_remove_understand() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-html-fixture"
+version = "0.1.0"
+license = "MIT"
+repository = "https://github.com/owner/app"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[render]\nmode = \"html\"\ncenter = true\n\n[style]\nstyle = \"flat-square\"\nlabelColor = \"555\"\n",
+    )
+    .unwrap();
+    let bdg = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    assert!(bdg(&["sync"]).status.success());
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(
+        readme.contains(
+            "<!-- bdg:begin -->\n<p align=\"center\">\n<a href=\"https://crates.io/crates/bdg-html-fixture\"><img alt=\"crates.io\" src=\"https://img.shields.io/crates/v/bdg-html-fixture.svg?style=flat-square&amp;labelColor=555\"></a>\n"
+        ),
+        "{readme}"
+    );
+    assert!(readme.contains("</p>\n<!-- bdg:end -->"), "{readme}");
+    assert!(bdg(&["sync", "--check"]).status.success());
+
+    let output = bdg(&["check", "--strict", "--json"]);
+    assert!(output.status.success(), "{output:?}");
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["issues"], serde_json::json!([]));
+
+    let output = bdg(&["list", "--json"]);
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ids = value["readme_block"]["badges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|badge| badge["id"].as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert!(
+        ids.contains(&"crates:bdg-html-fixture".to_string()),
+        "{ids:?}"
+    );
+    assert!(ids.contains(&"license:static".to_string()), "{ids:?}");
+
+    assert!(bdg(&["remove", "--id", "license:static"]).status.success());
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(!readme.contains("badge/license"), "{readme}");
+    assert!(readme.contains("<p align=\"center\">"), "{readme}");
+
+    let remaining = ids
+        .iter()
+        .filter(|id| *id != "license:static")
+        .flat_map(|id| ["--id", id.as_str()])
+        .collect::<Vec<_>>();
+    assert!(
+        bdg(&[&["remove"], remaining.as_slice()].concat())
+            .status
+            .success()
+    );
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(!readme.contains("<p align"), "{readme}");
+}
+
+#[test]
+fn picture_render_mode_writes_theme_variants_that_keep_badge_ids() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-picture-fixture"
+version = "0.1.0"
+license = "MIT"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[render]\nmode = \"picture\"\n\n[style]\nstyle = \"flat-square\"\n\n[style.dark]\nlabelColor = \"333\"\n\n[style.light]\nlabelColor = \"eee\"\nstyle = \"flat\"\n",
+    )
+    .unwrap();
+    let bdg = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    assert!(bdg(&["sync"]).status.success());
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    let crate_line = "<a href=\"https://crates.io/crates/bdg-picture-fixture\"><picture><source media=\"(prefers-color-scheme: dark)\" srcset=\"https://img.shields.io/crates/v/bdg-picture-fixture.svg?style=flat-square&amp;labelColor=333\"><source media=\"(prefers-color-scheme: light)\" srcset=\"https://img.shields.io/crates/v/bdg-picture-fixture.svg?style=flat&amp;labelColor=eee\"><img alt=\"crates.io\" src=\"https://img.shields.io/crates/v/bdg-picture-fixture.svg?style=flat-square\"></picture></a>";
+    assert!(readme.contains(&format!("\n{crate_line}\n")), "{readme}");
+    // docs.rs renders its own badge, so there is no variant to offer.
+    assert!(
+        readme.contains(
+            "\n<a href=\"https://docs.rs/bdg-picture-fixture\"><img alt=\"docs.rs\" src=\"https://docs.rs/bdg-picture-fixture/badge.svg\"></a>\n"
+        ),
+        "{readme}"
+    );
+    assert!(bdg(&["sync", "--check"]).status.success());
+
+    let output = bdg(&["check", "--strict", "--json"]);
+    assert!(output.status.success(), "{output:?}");
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["issues"], serde_json::json!([]));
+
+    let output = bdg(&["list", "--json"]);
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let crate_badge = value["readme_block"]["badges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|badge| badge["id"] == "crates:bdg-picture-fixture")
+        .unwrap();
+    assert_eq!(crate_badge["kind"], "crates_version");
+    assert_eq!(crate_badge["raw"], crate_line);
+
+    // A hand-added Markdown copy of a themed badge is still a duplicate.
+    std::fs::write(
+        temp.path().join("README.md"),
+        readme.replace(
+            "<!-- bdg:end -->",
+            "[![crates.io](https://img.shields.io/crates/v/bdg-picture-fixture.svg)](https://crates.io/crates/bdg-picture-fixture)\n<!-- bdg:end -->",
+        ),
+    )
+    .unwrap();
+    let output = bdg(&["check", "--json"]);
+    assert!(!output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["issues"][0]["code"], "DUPLICATE_BADGE", "{value}");
+
+    assert!(
+        bdg(&["remove", "--id", "crates:bdg-picture-fixture"])
+            .status
+            .success()
+    );
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert!(!readme.contains("crates/v/"), "{readme}");
+    assert!(readme.contains("<picture>"), "{readme}");
+}
+
+#[test]
+fn rst_and_asciidoc_readmes_are_synced_checked_and_edited_in_their_own_markup() {
+    for (readme, title, expected) in [
+        (
+            "README.rst",
+            "fixture\n=======\n\nIntro\n",
+            "fixture\n=======\n\n.. bdg:begin\n.. image:: https://img.shields.io/crates/v/bdg-markup-fixture.svg\n   :target: https://crates.io/crates/bdg-markup-fixture\n   :alt: crates.io\n",
+        ),
+        (
+            "README.adoc",
+            "= fixture\n\nIntro\n",
+            "= fixture\n\n// bdg:begin\nimage:https://img.shields.io/crates/v/bdg-markup-fixture.svg[crates.io,link=https://crates.io/crates/bdg-markup-fixture]\n",
+        ),
+    ] {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp.path().join("Cargo.toml"),
+            r#"
+[package]
+name = "bdg-markup-fixture"
+version = "0.1.0"
+license = "MIT"
+"#,
+        )
+        .unwrap();
+        std::fs::write(temp.path().join(readme), title).unwrap();
+        // HTML rendering only applies to Markdown READMEs.
+        std::fs::write(temp.path().join(".bdg.toml"), "[render]\nmode = \"html\"\n").unwrap();
+        let bdg = |args: &[&str]| {
+            Command::new(env!("CARGO_BIN_EXE_bdg"))
+                .current_dir(temp.path())
+                .args(args)
+                .output()
+                .unwrap()
+        };
+
+        assert!(bdg(&["sync"]).status.success(), "{readme}");
+        let content = std::fs::read_to_string(temp.path().join(readme)).unwrap();
+        assert!(content.starts_with(expected), "{content}");
+        assert!(content.ends_with("\n\nIntro\n"), "{content}");
+        assert!(!temp.path().join("README.md").exists());
+        assert!(bdg(&["sync", "--check"]).status.success(), "{content}");
+
+        let output = bdg(&["check", "--strict", "--json"]);
+        assert!(output.status.success(), "{output:?}");
+        let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(value["issues"], serde_json::json!([]));
+        let badge_count = value["badge_count"].as_u64().unwrap();
+
+        let output = bdg(&["list", "--json"]);
+        let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(
+            value["readme"]["format"],
+            if readme == "README.rst" {
+                "rst"
+            } else {
+                "asciidoc"
+            }
+        );
+        let ids = value["readme_block"]["badges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|badge| badge["id"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(ids.len() as u64, badge_count, "{content}");
+        assert!(ids.contains(&"crates:bdg-markup-fixture".to_string()));
+        assert!(ids.contains(&"license:static".to_string()), "{ids:?}");
+
+        assert!(bdg(&["remove", "--id", "license:static"]).status.success());
+        let content = std::fs::read_to_string(temp.path().join(readme)).unwrap();
+        assert!(!content.contains("badge/license"), "{content}");
+        assert!(!content.contains(":alt: license"), "{content}");
+        assert!(content.starts_with(expected), "{content}");
+        assert!(bdg(&["check", "--strict"]).status.success(), "{content}");
+    }
+}
+
+#[test]
+fn security_badges_are_opt_in() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-posture-fixture"
+version = "0.1.0"
+publish = false
+repository = "https://github.com/owner/app"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    std::fs::write(temp.path().join("SECURITY.md"), "# Security\n").unwrap();
+    std::fs::write(temp.path().join("deny.toml"), "[advisories]\n").unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(
+        workflows.join("scorecard.yml"),
+        "name: Scorecard\non:\n  push:\n    branches: [main]\njobs:\n  analysis:\n    steps:\n      - uses: ossf/scorecard-action@v2\n",
+    )
+    .unwrap();
+    std::fs::write(
+        workflows.join("audit.yml"),
+        "on:\n  schedule:\n    - cron: '0 0 * * *'\njobs:\n  deny:\n    steps:\n      - uses: EmbarkStudios/cargo-deny-action@v2\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[security]\nbest_practices_project = 1234\n",
+    )
+    .unwrap();
+
+    let sync = |only: Option<&str>| {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bdg"));
+        command.current_dir(temp.path()).args(["sync", "--dry-run"]);
+        if let Some(only) = only {
+            command.args(["--only", only]);
+        }
+        let output = command.output().unwrap();
+        assert_eq!(output.status.code(), Some(2));
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let stdout = sync(None);
+    assert!(!stdout.contains("scorecard"), "{stdout}");
+    assert!(!stdout.contains("security"), "{stdout}");
+    assert!(!stdout.contains("audit.yml"), "{stdout}");
+
+    let stdout = sync(Some("security"));
+    assert!(stdout.contains(
+        "[![OpenSSF Scorecard](https://api.scorecard.dev/projects/github.com/owner/app/badge)](https://scorecard.dev/viewer/?uri=github.com/owner/app)"
+    ));
+    assert!(stdout.contains(
+        "[![OpenSSF Best Practices](https://www.bestpractices.dev/projects/1234/badge)](https://www.bestpractices.dev/projects/1234)"
+    ));
+    assert!(stdout.contains(
+        "[![security audit](https://github.com/owner/app/actions/workflows/audit.yml/badge.svg)](https://github.com/owner/app/actions/workflows/audit.yml)"
+    ));
+    assert!(stdout.contains(
+        "[![security policy](https://img.shields.io/badge/security-policy-blue.svg)](https://github.com/owner/app/security/policy)"
+    ));
+    assert!(!stdout.contains("scorecard.yml"), "{stdout}");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["list", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        value["security"],
+        serde_json::json!({
+            "scorecard_workflow": "scorecard.yml",
+            "audit_workflows": ["audit.yml"],
+            "cargo_deny": "deny.toml",
+            "policy": "SECURITY.md",
+        })
+    );
+}
+
+#[test]
+fn ci_workflow_that_also_audits_gets_one_badge() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        r#"
+[package]
+name = "bdg-ci-audit-fixture"
+version = "0.1.0"
+publish = false
+repository = "https://github.com/owner/app"
+"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# fixture\n").unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(
+        workflows.join("ci.yml"),
+        "name: CI\non: [push, pull_request]\njobs:\n  test:\n    steps:\n      - run: cargo test\n      - run: cargo deny check\n",
+    )
+    .unwrap();
+    let bdg = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .current_dir(temp.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    assert!(bdg(&["sync", "--only", "ci,security"]).status.success());
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert_eq!(readme.matches("ci.yml/badge.svg").count(), 1, "{readme}");
+    let output = bdg(&["check", "--strict"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        bdg(&["sync", "--only", "ci,security", "--check"])
+            .status
+            .code(),
+        Some(0)
+    );
 }
@@ -1,4 +1,4 @@
-use bdg::config::load_config;
+use bdg::config::{Theme, load_config};
 use std::fs;
 
 fn write_config(path: &std::path::Path, allow_yy: bool) {
@@ -65,3 +65,53 @@ exclude = ["release", "coverage"]
     let config = load_config(&repo, &repo).unwrap();
     assert_eq!(config.badges.exclude, vec!["release", "coverage"]);
 }
+
+#[test]
+fn resolves_readme_relative_to_config_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path().join("repo");
+    let cwd = repo.join("packages").join("a");
+    fs::create_dir_all(&cwd).unwrap();
+    fs::create_dir_all(repo.join(".git")).unwrap();
+    fs::write(repo.join(".bdg.toml"), "readme = \"docs/README.md\"\n").unwrap();
+
+    let config = load_config(&cwd, &repo).unwrap();
+    assert_eq!(config.readme, Some(repo.join("docs/README.md")));
+}
+
+#[test]
+fn empty_theme_style_settings_drop_inherited_values() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = dir.path().join("repo");
+    fs::create_dir_all(repo.join(".git")).unwrap();
+    fs::write(
+        repo.join(".bdg.toml"),
+        r#"
+[style]
+style = "flat-square"
+labelColor = "555"
+
+[style.kinds.version]
+logo = "rust"
+
+[style.dark]
+labelColor = ""
+logo = ""
+"#,
+    )
+    .unwrap();
+
+    let config = load_config(&repo, &repo).unwrap();
+    assert_eq!(
+        config.style.theme_params("version", Theme::Dark),
+        vec![("style", "flat-square".to_string())]
+    );
+    assert_eq!(
+        config.style.theme_params("version", Theme::Light),
+        vec![
+            ("style", "flat-square".to_string()),
+            ("logo", "rust".to_string()),
+            ("labelColor", "555".to_string()),
+        ]
+    );
+}
@@ -1,6 +1,9 @@
 use bdg::core::detect_manifests;
 use bdg::manifest::{
-    RepositoryField, read_cargo_toml, read_moon_mod, read_package_json, read_resolved_cargo_package,
+    RepositoryField, cargo_workspace_members, go_module_repository, npm_workspace_packages,
+    parse_go_mod, parse_pnpm_workspace_packages, read_cargo_toml, read_jsr_manifest, read_moon_mod,
+    read_npm_workspace, read_package_json, read_resolved_cargo_package,
+    read_resolved_python_package,
 };
 use std::fs;
 use std::path::Path;
@@ -49,6 +52,16 @@ fn parses_moon_mod_json() {
     let module = read_moon_mod(&path).unwrap();
     assert_eq!(module.name.as_deref(), Some("moon-fixture"));
     assert_eq!(module.version.as_deref(), Some("0.4.0"));
+    assert_eq!(module.license.as_deref(), Some("Apache-2.0"));
+    assert_eq!(
+        module.repository.as_deref(),
+        Some("https://github.com/example/moon-fixture")
+    );
+    assert_eq!(module.keywords, vec!["badges", "readme"]);
+    assert_eq!(
+        module.description.as_deref(),
+        Some("MoonBit fixture module")
+    );
 }
 
 #[test]
@@ -120,6 +133,46 @@ fn resolves_workspace_inherited_cargo_package_fields() {
     );
 }
 
+#[test]
+fn resolves_rust_version_and_edition_from_package_and_workspace() {
+    let temp = tempfile::tempdir().unwrap();
+    write_workspace_fixture(temp.path());
+    let manifest_path = temp.path().join("crates/codegraph/Cargo.toml");
+    fs::write(
+        &manifest_path,
+        r#"
+[package]
+name = "cgz"
+version = "0.1.0"
+edition = "2021"
+rust-version.workspace = true
+"#,
+    )
+    .unwrap();
+
+    let package = read_resolved_cargo_package(&manifest_path)
+        .unwrap()
+        .expect("package missing");
+
+    assert_eq!(package.rust_version.as_deref(), Some("1.85"));
+    assert_eq!(package.edition.as_deref(), Some("2021"));
+}
+
+#[test]
+fn parses_package_json_node_engine() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("package.json");
+    fs::write(
+        &path,
+        r#"{ "name": "engines", "engines": { "node": ">=18", "pnpm": ">=9" } }"#,
+    )
+    .unwrap();
+
+    let pkg = read_package_json(&path).unwrap();
+
+    assert_eq!(pkg.node_engine(), Some(">=18"));
+}
+
 #[test]
 fn raw_workspace_inherited_cargo_toml_parses() {
     let temp = tempfile::tempdir().unwrap();
@@ -131,6 +184,338 @@ fn raw_workspace_inherited_cargo_toml_parses() {
     assert!(manifest.package.is_some());
 }
 
+#[test]
+fn parses_pep621_pyproject() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("pyproject.toml");
+    fs::write(
+        &path,
+        r#"
+[project]
+name = "bdg-py"
+version = "0.3.0"
+description = "demo"
+license = { text = "MIT" }
+requires-python = ">=3.9"
+
+[project.urls]
+Homepage = "https://example.com"
+Repository = "https://github.com/example/bdg-py"
+"#,
+    )
+    .unwrap();
+
+    let package = read_resolved_python_package(&path)
+        .unwrap()
+        .expect("package missing");
+
+    assert_eq!(package.name.as_deref(), Some("bdg-py"));
+    assert_eq!(package.version.as_deref(), Some("0.3.0"));
+    assert_eq!(package.license.as_deref(), Some("MIT"));
+    assert_eq!(package.requires_python.as_deref(), Some(">=3.9"));
+    assert_eq!(
+        package.repository.as_deref(),
+        Some("https://github.com/example/bdg-py")
+    );
+}
+
+#[test]
+fn parses_poetry_pyproject() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("pyproject.toml");
+    fs::write(
+        &path,
+        r#"
+[tool.poetry]
+name = "bdg-poetry"
+version = "1.0.0"
+license = "Apache-2.0"
+repository = "https://github.com/example/bdg-poetry"
+
+[tool.poetry.dependencies]
+python = "^3.10"
+"#,
+    )
+    .unwrap();
+
+    let package = read_resolved_python_package(&path)
+        .unwrap()
+        .expect("package missing");
+
+    assert_eq!(package.name.as_deref(), Some("bdg-poetry"));
+    assert_eq!(package.version.as_deref(), Some("1.0.0"));
+    assert_eq!(package.license.as_deref(), Some("Apache-2.0"));
+    assert_eq!(package.requires_python.as_deref(), Some("^3.10"));
+}
+
+#[test]
+fn detects_pyproject_with_package_metadata_only() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(
+        temp.path().join("pyproject.toml"),
+        "[tool.ruff]\nline-length = 100\n",
+    )
+    .unwrap();
+    let manifests = detect_manifests(temp.path(), temp.path(), 3).unwrap();
+    assert!(manifests.pyproject_toml.is_none());
+
+    fs::write(
+        temp.path().join("pyproject.toml"),
+        "[project]\nname = \"demo\"\n",
+    )
+    .unwrap();
+    let manifests = detect_manifests(temp.path(), temp.path(), 3).unwrap();
+    assert!(manifests.pyproject_toml.is_some());
+}
+
+#[test]
+fn parses_go_mod_module_and_go_directive() {
+    let module = parse_go_mod(
+        r#"// demo module
+module "github.com/f4ah6o/demo/v2"
+
+go 1.22 // minimum
+
+toolchain go1.22.3
+
+require (
+	golang.org/x/mod v0.17.0
+)
+"#,
+    );
+
+    assert_eq!(module.module.as_deref(), Some("github.com/f4ah6o/demo/v2"));
+    assert_eq!(module.go.as_deref(), Some("1.22"));
+    assert_eq!(
+        go_module_repository("github.com/f4ah6o/demo/v2").as_deref(),
+        Some("https://github.com/f4ah6o/demo")
+    );
+    assert_eq!(go_module_repository("example/local"), None);
+}
+
+#[test]
+fn parses_deno_jsonc_with_comments() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("deno.jsonc");
+    fs::write(
+        &path,
+        r#"{
+  // package identity
+  "name": "@f4ah6o/demo",
+  /* published version */
+  "version": "0.2.0",
+  "exports": "./mod.ts",
+  "imports": { "url": "https://example.com/a//b" }
+}"#,
+    )
+    .unwrap();
+
+    let manifest = read_jsr_manifest(&path).unwrap();
+
+    assert_eq!(manifest.name.as_deref(), Some("@f4ah6o/demo"));
+    assert_eq!(manifest.version.as_deref(), Some("0.2.0"));
+}
+
+#[test]
+fn prefers_jsr_json_and_skips_unnamed_deno_config() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(
+        temp.path().join("deno.json"),
+        r#"{ "name": "@f4ah6o/demo", "version": "0.1.0" }"#,
+    )
+    .unwrap();
+    fs::write(
+        temp.path().join("jsr.json"),
+        r#"{ "name": "@f4ah6o/demo", "version": "0.2.0" }"#,
+    )
+    .unwrap();
+    fs::create_dir_all(temp.path().join("app")).unwrap();
+    fs::write(
+        temp.path().join("app/deno.json"),
+        r#"{ "tasks": { "dev": "deno run main.ts" } }"#,
+    )
+    .unwrap();
+
+    let manifests = detect_manifests(temp.path(), &temp.path().join("app"), 3).unwrap();
+    let manifest = manifests.jsr_manifest.as_ref().expect("jsr manifest");
+
+    assert!(manifest.ends_with("jsr.json"));
+}
+
+#[test]
+fn expands_workspace_member_globs_and_excludes() {
+    let temp = tempfile::tempdir().unwrap();
+    let root = temp.path();
+    for member in ["crates/alpha", "crates/beta", "crates/scratch", "tools/gen"] {
+        fs::create_dir_all(root.join(member)).unwrap();
+        fs::write(
+            root.join(member).join("Cargo.toml"),
+            format!(
+                "[package]\nname = \"{}\"\n",
+                member.rsplit('/').next().unwrap()
+            ),
+        )
+        .unwrap();
+    }
+    fs::create_dir_all(root.join("crates/empty")).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        r#"
+[workspace]
+members = ["crates/*", "tools/gen"]
+exclude = ["crates/scratch"]
+"#,
+    )
+    .unwrap();
+
+    let members = cargo_workspace_members(&root.join("Cargo.toml")).unwrap();
+    let relative = members
+        .iter()
+        .map(|path| {
+            path.strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        relative,
+        vec![
+            "crates/alpha/Cargo.toml",
+            "crates/beta/Cargo.toml",
+            "tools/gen/Cargo.toml"
+        ]
+    );
+}
+
+#[test]
+fn resolves_cargo_readme_and_publish_fields() {
+    let temp = tempfile::tempdir().unwrap();
+    write_workspace_fixture(temp.path());
+    let member = temp.path().join("crates/codegraph");
+    fs::write(member.join("README.md"), "# cgz\n").unwrap();
+
+    let package = read_resolved_cargo_package(&member.join("Cargo.toml"))
+        .unwrap()
+        .expect("package missing");
+    assert_eq!(package.readme, Some(member.join("README.md")));
+    assert!(package.is_publishable());
+
+    fs::write(
+        member.join("Cargo.toml"),
+        r#"
+[package]
+name = "cgz"
+readme = "docs/intro.md"
+publish = false
+"#,
+    )
+    .unwrap();
+    let package = read_resolved_cargo_package(&member.join("Cargo.toml"))
+        .unwrap()
+        .expect("package missing");
+    assert_eq!(package.readme, Some(member.join("docs/intro.md")));
+    assert!(!package.is_publishable());
+
+    fs::write(
+        member.join("Cargo.toml"),
+        "[package]\nname = \"cgz\"\nreadme = false\n",
+    )
+    .unwrap();
+    let package = read_resolved_cargo_package(&member.join("Cargo.toml"))
+        .unwrap()
+        .expect("package missing");
+    assert_eq!(package.readme, None);
+}
+
+#[test]
+fn parses_pnpm_workspace_packages() {
+    let patterns = parse_pnpm_workspace_packages(
+        r#"# workspace
+packages:
+  - "packages/*"
+  - 'apps/**'
+  - "!**/test/**" # fixtures
+catalog:
+  react: ^18
+"#,
+    );
+
+    assert_eq!(patterns, vec!["packages/*", "apps/**", "!**/test/**"]);
+    assert_eq!(
+        parse_pnpm_workspace_packages("packages: [\"packages/*\", tools/cli]\n"),
+        vec!["packages/*", "tools/cli"]
+    );
+}
+
+#[test]
+fn resolves_npm_workspace_packages_with_exclusions() {
+    let temp = tempfile::tempdir().unwrap();
+    let root = temp.path();
+    for dir in [
+        "packages/a",
+        "packages/b",
+        "packages/legacy",
+        "examples/demo",
+    ] {
+        fs::create_dir_all(root.join(dir)).unwrap();
+        fs::write(root.join(dir).join("package.json"), "{}").unwrap();
+    }
+    fs::write(
+        root.join("package.json"),
+        r#"{ "private": true, "workspaces": { "packages": ["packages/*", "!packages/legacy"] } }"#,
+    )
+    .unwrap();
+
+    let workspace = read_npm_workspace(root).expect("workspace");
+    assert_eq!(workspace.source, "package.json");
+    let packages = npm_workspace_packages(root, &workspace)
+        .iter()
+        .map(|path| {
+            path.strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        packages,
+        vec!["packages/a/package.json", "packages/b/package.json"]
+    );
+
+    fs::write(
+        root.join("pnpm-workspace.yaml"),
+        "packages:\n  - examples/*\n",
+    )
+    .unwrap();
+    let workspace = read_npm_workspace(root).expect("workspace");
+    assert_eq!(workspace.source, "pnpm-workspace.yaml");
+    assert_eq!(npm_workspace_packages(root, &workspace).len(), 1);
+}
+
+#[test]
+fn reads_nx_workspace_only_with_layout_or_default_dirs() {
+    let temp = tempfile::tempdir().unwrap();
+    let root = temp.path();
+    fs::write(root.join("nx.json"), r#"{ "npmScope": "acme" }"#).unwrap();
+    assert_eq!(read_npm_workspace(root), None);
+
+    fs::create_dir_all(root.join("libs")).unwrap();
+    let workspace = read_npm_workspace(root).expect("workspace");
+    assert_eq!(workspace.source, "nx.json");
+    assert_eq!(workspace.patterns, vec!["libs/*"]);
+
+    fs::write(
+        root.join("nx.json"),
+        r#"{ "workspaceLayout": { "appsDir": "projects" } }"#,
+    )
+    .unwrap();
+    let workspace = read_npm_workspace(root).expect("workspace");
+    assert_eq!(workspace.patterns, vec!["projects/*", "libs/*"]);
+}
+
 fn write_workspace_fixture(root: &Path) {
     fs::create_dir_all(root.join("crates/codegraph")).unwrap();
     fs::write(
@@ -142,6 +527,7 @@ members = ["crates/codegraph"]
 [workspace.package]
 license = "MIT"
 repository = "https://github.com/f4ah6o/codegraph"
+rust-version = "1.85"
 "#,
     )
     .unwrap();
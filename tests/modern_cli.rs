@@ -75,3 +75,324 @@ fn list_does_not_pretend_missing_markers_exist() {
     assert_eq!(value["readme"]["markers"]["present"], false);
     assert_eq!(value["readme"]["markers"]["count"], 0);
 }
+
+#[test]
+fn list_explains_manifest_declared_readme() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp.path().join("moon.mod.json"),
+        r#"{"name":"example/demo","version":"1.2.3","readme":"docs/INTRO.md"}"#,
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("README.md"), "# demo\n").unwrap();
+    std::fs::create_dir_all(temp.path().join("docs")).unwrap();
+    std::fs::write(temp.path().join("docs/INTRO.md"), "# intro\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["list", "--json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(
+        value["readme"]["path"]
+            .as_str()
+            .unwrap()
+            .ends_with("docs/INTRO.md")
+    );
+    assert_eq!(value["readme"]["source"], "manifest");
+    assert!(
+        value["readme"]["declared_by"]
+            .as_str()
+            .unwrap()
+            .ends_with("moon.mod.json")
+    );
+}
+
+#[test]
+fn readme_option_overrides_detected_readme() {
+    let temp = tempfile::tempdir().unwrap();
+    write_moon_project(temp.path());
+    std::fs::write(temp.path().join("OTHER.md"), "# other\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["--readme", "OTHER.md", "sync", "--only", "version"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let other = std::fs::read_to_string(temp.path().join("OTHER.md")).unwrap();
+    assert!(other.contains("<!-- bdg:begin -->"));
+    assert!(other.contains("moonbit"));
+    let readme = std::fs::read_to_string(temp.path().join("README.md")).unwrap();
+    assert_eq!(readme, "# demo\n");
+
+    let list = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .current_dir(temp.path())
+        .args(["list", "--json", "--readme", "OTHER.md"])
+        .output()
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&list.stdout).unwrap();
+    assert_eq!(value["readme"]["source"], "cli");
+}
+
+#[test]
+fn list_reads_git_metadata_without_git_binary_and_honors_remote_preference() {
+    let temp = tempfile::tempdir().unwrap();
+    let git = temp.path().join(".git");
+    std::fs::create_dir_all(git.join("refs/remotes/upstream")).unwrap();
+    std::fs::write(git.join("HEAD"), "ref: refs/heads/topic\n").unwrap();
+    std::fs::write(
+        git.join("config"),
+        "[remote \"origin\"]\n\turl = git@github.com:me/demo.git\n[remote \"upstream\"]\n\turl = https://gitlab.com/group/sub/demo.git\n",
+    )
+    .unwrap();
+    std::fs::write(
+        git.join("refs/remotes/upstream/HEAD"),
+        "ref: refs/remotes/upstream/trunk\n",
+    )
+    .unwrap();
+    write_moon_project(temp.path());
+
+    // A submodule checkout whose `.git` is a `gitdir:` file.
+    let module_git = git.join("modules/vendor/lib");
+    std::fs::create_dir_all(&module_git).unwrap();
+    std::fs::write(module_git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+    std::fs::write(
+        module_git.join("config"),
+        "[remote \"origin\"]\n\turl = https://codeberg.org/team/lib.git\n",
+    )
+    .unwrap();
+    let submodule = temp.path().join("vendor/lib");
+    std::fs::create_dir_all(&submodule).unwrap();
+    std::fs::write(
+        submodule.join(".git"),
+        "gitdir: ../../.git/modules/vendor/lib\n",
+    )
+    .unwrap();
+    write_moon_project(&submodule);
+
+    let list = |dir: &std::path::Path| -> serde_json::Value {
+        let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .arg("-C")
+            .arg(dir)
+            .args(["list", "--json"])
+            .env("PATH", "")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+
+    let value = list(temp.path());
+    assert_eq!(value["repo"]["remote_name"], "origin");
+    assert_eq!(value["repo"]["owner"], "me");
+    assert_eq!(value["repo"]["branch"], "topic");
+
+    std::fs::write(
+        temp.path().join(".bdg.toml"),
+        "[git]\nremotes = [\"upstream\", \"origin\"]\n",
+    )
+    .unwrap();
+    let value = list(temp.path());
+    assert_eq!(value["repo"]["remote_name"], "upstream");
+    assert_eq!(value["repo"]["forge"], "gitlab");
+    assert_eq!(value["repo"]["owner"], "group/sub");
+    assert_eq!(value["repo"]["default_branch"], "trunk");
+
+    let value = list(&submodule);
+    assert_eq!(value["repo"]["remote"], "https://codeberg.org/team/lib.git");
+    assert_eq!(value["repo"]["forge"], "forgejo");
+    assert!(
+        value["repo"]["git_root"]
+            .as_str()
+            .unwrap()
+            .ends_with("vendor/lib")
+    );
+}
+
+#[test]
+fn list_json_reports_latest_run_from_github_api() {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let api_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let bodies = [
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n",
+            "HTTP/1.1 403 Forbidden\r\nx-ratelimit-remaining: 0\r\nx-ratelimit-reset: 1700000000\r\n",
+        ];
+        let mut requests = Vec::new();
+        for head in bodies {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            reader.read_line(&mut request).unwrap();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                if line.to_ascii_lowercase().starts_with("authorization:") {
+                    request.push_str(&line);
+                }
+            }
+            requests.push(request);
+            let body = r#"{"workflow_runs":[{"id":7,"status":"completed","conclusion":"failure","html_url":"https://github.com/owner/demo/actions/runs/7","updated_at":"2026-01-01T00:00:00Z"}]}"#;
+            let response = format!(
+                "{head}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+
+    let temp = tempfile::tempdir().unwrap();
+    let git = temp.path().join(".git");
+    std::fs::create_dir_all(git.join("refs/remotes/origin")).unwrap();
+    std::fs::write(git.join("HEAD"), "ref: refs/heads/topic\n").unwrap();
+    std::fs::write(
+        git.join("refs/remotes/origin/HEAD"),
+        "ref: refs/remotes/origin/main\n",
+    )
+    .unwrap();
+    std::fs::write(
+        git.join("config"),
+        "[remote \"origin\"]\n\turl = https://github.com/owner/demo.git\n",
+    )
+    .unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(workflows.join("ci.yml"), "name: CI\non: push\n").unwrap();
+    write_moon_project(temp.path());
+
+    let list = || -> serde_json::Value {
+        let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .arg("-C")
+            .arg(temp.path())
+            .args(["list", "--json"])
+            .env("BDG_GITHUB_API_URL", &api_url)
+            .env("GITHUB_TOKEN", "test-token")
+            .env_remove("GH_TOKEN")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+
+    let value = list();
+    let status = &value["ci"]["workflows"][0]["latest_status"];
+    assert_eq!(status["source"], "github_api");
+    assert_eq!(status["ok"], true);
+    assert_eq!(status["conclusion"], "failure");
+    assert_eq!(status["run_id"], 7);
+
+    let value = list();
+    let status = &value["ci"]["workflows"][0]["latest_status"];
+    assert_eq!(status["ok"], false);
+    assert_eq!(status["reason"], "rate_limited");
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with(
+        "GET /repos/owner/demo/actions/workflows/ci.yml/runs?per_page=1&branch=main "
+    ));
+    assert!(requests[0].contains("Bearer test-token"));
+}
+
+#[test]
+fn github_token_only_goes_to_explicitly_configured_enterprise_hosts() {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let api_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let mut authorized = Vec::new();
+        for _ in 0..2 {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut has_token = false;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                has_token |= line.to_ascii_lowercase().starts_with("authorization:");
+            }
+            authorized.push(has_token);
+            let body = r#"{"workflow_runs":[]}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        authorized
+    });
+
+    let temp = tempfile::tempdir().unwrap();
+    let git = temp.path().join(".git");
+    std::fs::create_dir_all(&git).unwrap();
+    std::fs::write(git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+    std::fs::write(
+        git.join("config"),
+        "[remote \"origin\"]\n\turl = git@ghe.corp.example:team/app.git\n",
+    )
+    .unwrap();
+    let workflows = temp.path().join(".github/workflows");
+    std::fs::create_dir_all(&workflows).unwrap();
+    std::fs::write(workflows.join("ci.yml"), "name: CI\non: push\n").unwrap();
+    write_moon_project(temp.path());
+
+    let list = |config: &str| {
+        std::fs::write(temp.path().join(".bdg.toml"), config).unwrap();
+        let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+            .arg("-C")
+            .arg(temp.path())
+            .args(["list", "--json"])
+            .env("BDG_GITHUB_API_URL", &api_url)
+            .env("GITHUB_TOKEN", "test-token")
+            .env_remove("GH_TOKEN")
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(value["repo"]["forge"], "github");
+    };
+
+    list("[forge]\nkind = \"github\"\n");
+    list("[forge.hosts.\"ghe.corp.example\"]\nkind = \"github\"\n");
+    assert_eq!(server.join().unwrap(), [false, true]);
+}
+
+#[test]
+fn check_reports_malformed_config_as_an_issue() {
+    let temp = tempfile::tempdir().unwrap();
+    write_moon_project(temp.path());
+    std::fs::write(
+        temp.path().join("README.md"),
+        "# demo\n<!-- bdg:begin -->\n<!-- bdg:end -->\n",
+    )
+    .unwrap();
+    std::fs::write(temp.path().join(".bdg.toml"), "[badges\nexclude = 1\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bdg"))
+        .args(["-C", temp.path().to_str().unwrap(), "check", "--json"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["ok"], false);
+    assert_eq!(value["marker"]["begin_count"], 1);
+    assert_eq!(value["issues"][0]["code"], "CONFIG_INVALID");
+    assert!(
+        value["issues"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains(".bdg.toml")
+    );
+}
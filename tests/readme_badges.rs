@@ -84,6 +84,134 @@ fn parses_downloads_kinds() {
     assert_eq!(badge.id, "github_downloads:github");
 }
 
+#[test]
+fn parses_pypi_kinds() {
+    let cases = [
+        (
+            "![PyPI](https://img.shields.io/pypi/v/requests.svg)",
+            "pypi_version",
+            "pypi:requests",
+        ),
+        (
+            "![dl](https://img.shields.io/pypi/dm/requests.svg)",
+            "pypi_downloads",
+            "pypi_downloads:requests",
+        ),
+        (
+            "![py](https://img.shields.io/pypi/pyversions/requests.svg)",
+            "pypi_pyversions",
+            "pypi_pyversions:requests",
+        ),
+    ];
+
+    for (line, kind, id) in cases {
+        let badge = parse_badge_line(line);
+        assert_eq!(badge.kind, kind);
+        assert_eq!(badge.id, id);
+    }
+}
+
+#[test]
+fn parses_go_kinds() {
+    let badge = parse_badge_line(
+        "[![Go Reference](https://pkg.go.dev/badge/github.com/o/r.svg)](https://pkg.go.dev/github.com/o/r)",
+    );
+    assert_eq!(badge.kind, "docs");
+    assert_eq!(badge.id, "docs:gopkg:github.com/o/r");
+
+    let badge = parse_badge_line(
+        "[![Go Report Card](https://goreportcard.com/badge/github.com/o/r)](https://goreportcard.com/report/github.com/o/r)",
+    );
+    assert_eq!(badge.kind, "go_report_card");
+    assert_eq!(badge.id, "goreportcard:github.com/o/r");
+
+    let badge = parse_badge_line("![Go](https://img.shields.io/badge/go-1.22-00ADD8.svg)");
+    assert_eq!(badge.kind, "go_version");
+    assert_eq!(badge.id, "go_version:static");
+    assert_eq!(badge.meta.unwrap(), serde_json::json!({ "go": "1.22" }));
+}
+
+#[test]
+fn parses_moonbit_version_kind() {
+    let badge = parse_badge_line(
+        "[![moonbit](https://img.shields.io/badge/moonbit-0.4.2-informational)](https://mooncakes.io/docs/example/demo)",
+    );
+    assert_eq!(badge.kind, "moonbit_version");
+    assert_eq!(badge.id, "moonbit:static");
+    assert_eq!(
+        badge.meta.unwrap(),
+        serde_json::json!({ "version": "0.4.2" })
+    );
+}
+
+#[test]
+fn parses_static_toolchain_kinds() {
+    let badge = parse_badge_line(
+        "[![MSRV](https://img.shields.io/badge/rustc-1.85%2B-orange.svg)](https://www.rust-lang.org/)",
+    );
+    assert_eq!(badge.kind, "rust_version");
+    assert_eq!(badge.id, "rust_version:static");
+    assert_eq!(
+        badge.meta.unwrap(),
+        serde_json::json!({ "rust_version": "1.85" })
+    );
+
+    let badge =
+        parse_badge_line("![Rust edition](https://img.shields.io/badge/edition-2024-orange.svg)");
+    assert_eq!(badge.kind, "rust_edition");
+    assert_eq!(badge.id, "rust_edition:static");
+
+    let badge = parse_badge_line("![Node](https://img.shields.io/badge/node-%3E%3D18-339933.svg)");
+    assert_eq!(badge.kind, "node_version");
+    assert_eq!(badge.meta.unwrap(), serde_json::json!({ "node": ">=18" }));
+}
+
+#[test]
+fn parses_jsr_kinds() {
+    let badge =
+        parse_badge_line("[![JSR](https://jsr.io/badges/@std/path)](https://jsr.io/@std/path)");
+    assert_eq!(badge.kind, "jsr_version");
+    assert_eq!(badge.id, "jsr:@std/path");
+
+    let badge = parse_badge_line(
+        "[![JSR score](https://jsr.io/badges/@std/path/score)](https://jsr.io/@std/path)",
+    );
+    assert_eq!(badge.kind, "jsr_score");
+    assert_eq!(badge.id, "jsr_score:@std/path");
+    assert_eq!(
+        badge.meta.unwrap(),
+        serde_json::json!({ "package": "@std/path" })
+    );
+}
+
+#[test]
+fn parses_container_kinds() {
+    let badge = parse_badge_line(
+        "[![Docker Hub](https://img.shields.io/docker/v/f4ah6o/bdg.svg?sort=semver)](https://hub.docker.com/r/f4ah6o/bdg)",
+    );
+    assert_eq!(badge.kind, "docker_version");
+    assert_eq!(badge.id, "docker:f4ah6o/bdg");
+
+    let badge =
+        parse_badge_line("![Docker pulls](https://img.shields.io/docker/pulls/f4ah6o/bdg.svg)");
+    assert_eq!(badge.kind, "docker_pulls");
+    assert_eq!(badge.id, "docker_pulls:f4ah6o/bdg");
+
+    let badge = parse_badge_line(
+        "[![GHCR](https://ghcr-badge.egpl.dev/f4ah6o/bdg/latest_tag?trim=major&label=ghcr)](https://ghcr.io/f4ah6o/bdg)",
+    );
+    assert_eq!(badge.kind, "ghcr_version");
+    assert_eq!(badge.id, "ghcr:f4ah6o/bdg");
+    assert_eq!(
+        badge.meta.unwrap(),
+        serde_json::json!({ "image": "f4ah6o/bdg", "registry": "ghcr" })
+    );
+
+    let badge = parse_badge_line("![GHCR image size](https://ghcr-badge.egpl.dev/f4ah6o/bdg/size)");
+    assert_eq!(badge.kind, "ghcr_image_size");
+    assert_eq!(badge.id, "ghcr_size:f4ah6o/bdg");
+}
+
 #[test]
 fn parses_coverage_kind() {
     let line = "![codecov](https://img.shields.io/codecov/c/github/OWNER/REPO.svg)";
@@ -206,3 +334,445 @@ fn id_is_stable() {
     let second = parse_badge_line(line).id;
     assert_eq!(first, second);
 }
+
+#[test]
+fn parses_gitlab_kinds() {
+    let badge = parse_badge_line(
+        "[![pipeline](https://gitlab.com/group/sub/app/badges/main/pipeline.svg)](https://gitlab.com/group/sub/app/-/commits/main)",
+    );
+    assert_eq!(badge.kind, "gitlab_pipeline");
+    assert_eq!(badge.id, "ci:gitlab:main");
+    assert_eq!(
+        badge.meta.unwrap(),
+        serde_json::json!({ "project": "group/sub/app", "branch": "main" })
+    );
+
+    let badge = parse_badge_line(
+        "![coverage](https://gitlab.example.com/team/app/badges/main/coverage.svg)",
+    );
+    assert_eq!(badge.kind, "gitlab_coverage");
+    assert_eq!(badge.id, "coverage:gitlab");
+
+    let badge = parse_badge_line("![release](https://gitlab.com/group/app/-/badges/release.svg)");
+    assert_eq!(badge.kind, "gitlab_release");
+    assert_eq!(badge.id, "release:gitlab");
+
+    let badge = parse_badge_line("![license](https://img.shields.io/gitlab/license/group/app.svg)");
+    assert_eq!(badge.kind, "license");
+    assert_eq!(badge.id, "license:gitlab");
+
+    let badge =
+        parse_badge_line("![codecov](https://img.shields.io/codecov/c/gitlab/group/sub/app.svg)");
+    assert_eq!(badge.id, "coverage:codecov");
+    assert_eq!(
+        badge.meta.unwrap(),
+        serde_json::json!({ "owner": "group/sub", "repo": "app" })
+    );
+}
+
+#[test]
+fn parses_forgejo_and_woodpecker_kinds() {
+    let badge = parse_badge_line(
+        "[![CI](https://codeberg.org/owner/repo/actions/workflows/ci.yml/badge.svg)](https://codeberg.org/owner/repo/actions?workflow=ci.yml)",
+    );
+    assert_eq!(badge.kind, "forgejo_actions");
+    assert_eq!(badge.id, "ci:forgejo:ci.yml");
+
+    let badge = parse_badge_line(
+        "[![Woodpecker CI](https://ci.example.com/api/badges/owner/repo/status.svg)](https://ci.example.com/repos/owner/repo)",
+    );
+    assert_eq!(badge.kind, "woodpecker");
+    assert_eq!(badge.id, "ci:woodpecker");
+    assert_eq!(
+        badge.meta.unwrap(),
+        serde_json::json!({ "server": "https://ci.example.com", "repo": "owner/repo" })
+    );
+}
+
+#[test]
+fn workflow_branch_and_event_go_to_meta_not_id() {
+    let badge = parse_badge_line(
+        "[![CI](https://github.com/o/r/actions/workflows/ci.yml/badge.svg?branch=release%2F1.x&event=push)](https://github.com/o/r/actions/workflows/ci.yml?query=branch%3Arelease%2F1.x)",
+    );
+    assert_eq!(badge.kind, "github_actions");
+    assert_eq!(badge.id, "ci:ci.yml");
+    assert_eq!(
+        badge.meta,
+        Some(serde_json::json!({
+            "workflow_file": "ci.yml",
+            "branch": "release/1.x",
+            "event": "push",
+        }))
+    );
+}
+
+#[test]
+fn recognizes_ci_service_badges() {
+    let cases = [
+        (
+            "[![CircleCI](https://dl.circleci.com/status-badge/img/gh/o/r/tree/release%2F1.x.svg?style=svg)](https://dl.circleci.com/status-badge/redirect/gh/o/r/tree/release%2F1.x)",
+            "circleci",
+            serde_json::json!({ "vcs": "gh", "project": "o/r", "branch": "release/1.x" }),
+        ),
+        (
+            "[![Travis CI](https://app.travis-ci.com/o/r.svg?branch=main)](https://app.travis-ci.com/o/r)",
+            "travis",
+            serde_json::json!({ "repo": "o/r", "branch": "main" }),
+        ),
+        (
+            "[![Azure Pipelines](https://dev.azure.com/org/My%20Project/_apis/build/status/o.r?branchName=main)](https://dev.azure.com/org/My%20Project/_build)",
+            "azure_pipelines",
+            serde_json::json!({
+                "organization": "org",
+                "project": "My Project",
+                "pipeline": "o.r",
+                "branch": "main",
+            }),
+        ),
+        (
+            "[![Buildkite](https://badge.buildkite.com/abc123.svg)](https://buildkite.com/org/app)",
+            "buildkite",
+            serde_json::json!({ "key": "abc123" }),
+        ),
+        (
+            "[![Jenkins](https://jenkins.example.com/buildStatus/icon?job=team%2Fapp)](https://jenkins.example.com/job/team/job/app/)",
+            "jenkins",
+            serde_json::json!({ "server": "https://jenkins.example.com", "job": "team/app" }),
+        ),
+    ];
+    for (line, kind, meta) in cases {
+        let badge = parse_badge_line(line);
+        assert_eq!(badge.kind, kind, "{line}");
+        assert_eq!(badge.id, format!("ci:{kind}"), "{line}");
+        assert_eq!(badge.meta, Some(meta), "{line}");
+    }
+}
+
+#[test]
+fn recognizes_coverage_provider_badges() {
+    let cases = [
+        (
+            "[![coveralls](https://img.shields.io/coveralls/github/o/r.svg)](https://coveralls.io/github/o/r)",
+            "coveralls",
+            "coverage:coveralls",
+            serde_json::json!({ "service": "github", "repo": "o/r" }),
+        ),
+        (
+            "[![Coverage Status](https://coveralls.io/repos/github/o/r/badge.svg?branch=main)](https://coveralls.io/github/o/r?branch=main)",
+            "coveralls",
+            "coverage:coveralls",
+            serde_json::json!({ "service": "github", "repo": "o/r" }),
+        ),
+        (
+            "[![codacy](https://img.shields.io/codacy/coverage/0123abcd.svg)](https://app.codacy.com/gh/o/r/dashboard)",
+            "codacy",
+            "coverage:codacy",
+            serde_json::json!({ "project_id": "0123abcd" }),
+        ),
+        (
+            "[![Codacy Badge](https://app.codacy.com/project/badge/Coverage/0123abcd)](https://app.codacy.com/gh/o/r/dashboard)",
+            "codacy",
+            "coverage:codacy",
+            serde_json::json!({ "project_id": "0123abcd" }),
+        ),
+        (
+            "[![sonarcloud](https://img.shields.io/sonar/coverage/o_r.svg?server=https%3A%2F%2Fsonarcloud.io)](https://sonarcloud.io/summary/new_code?id=o_r)",
+            "sonarcloud",
+            "coverage:sonarcloud",
+            serde_json::json!({ "project_key": "o_r", "server": "https://sonarcloud.io" }),
+        ),
+        (
+            "[![Coverage](https://sonarcloud.io/api/project_badges/measure?project=o_r&metric=coverage)](https://sonarcloud.io/summary/new_code?id=o_r)",
+            "sonarcloud",
+            "coverage:sonarcloud",
+            serde_json::json!({ "project_key": "o_r" }),
+        ),
+        (
+            "[![codecov](https://codecov.corp.example/ghe/o/r/graph/badge.svg)](https://codecov.corp.example/ghe/o/r)",
+            "coverage",
+            "coverage:codecov",
+            serde_json::json!({
+                "server": "https://codecov.corp.example",
+                "service": "ghe",
+                "repo": "o/r",
+            }),
+        ),
+        (
+            "[![codecov](https://codecov.io/gl/group/sub/app/graph/badge.svg?token=T)](https://codecov.io/gl/group/sub/app)",
+            "coverage",
+            "coverage:codecov",
+            serde_json::json!({
+                "server": "https://codecov.io",
+                "service": "gl",
+                "repo": "group/sub/app",
+            }),
+        ),
+    ];
+    for (line, kind, id, meta) in cases {
+        let badge = parse_badge_line(line);
+        assert_eq!(badge.kind, kind, "{line}");
+        assert_eq!(badge.id, id, "{line}");
+        assert_eq!(badge.meta, Some(meta), "{line}");
+    }
+}
+
+#[test]
+fn recognizes_security_badges() {
+    let cases = [
+        (
+            "[![OpenSSF Scorecard](https://api.scorecard.dev/projects/github.com/o/r/badge)](https://scorecard.dev/viewer/?uri=github.com/o/r)",
+            "ossf_scorecard",
+            "security:scorecard",
+            serde_json::json!({ "project": "github.com/o/r" }),
+        ),
+        (
+            "[![OpenSSF Best Practices](https://www.bestpractices.dev/projects/1234/badge)](https://www.bestpractices.dev/projects/1234)",
+            "best_practices",
+            "security:best_practices",
+            serde_json::json!({ "project": 1234 }),
+        ),
+        (
+            "[![security policy](https://img.shields.io/badge/security-policy-blue.svg)](https://github.com/o/r/security/policy)",
+            "security_policy",
+            "security:policy",
+            serde_json::json!({}),
+        ),
+    ];
+    for (line, kind, id, meta) in cases {
+        let badge = parse_badge_line(line);
+        assert_eq!(badge.kind, kind, "{line}");
+        assert_eq!(badge.id, id, "{line}");
+        assert_eq!(badge.meta, Some(meta), "{line}");
+    }
+}
+
+/// One badge from each shields-based constructor, plus a few that are not.
+fn sample_badges() -> Vec<bdg::badges::Badge> {
+    use bdg::badges::*;
+    use bdg::forge::parse_repo_url;
+
+    let github = parse_repo_url("https://github.com/o/r").unwrap();
+    let gitlab = parse_repo_url("https://gitlab.com/group/sub/app").unwrap();
+    let codeberg = parse_repo_url("https://codeberg.org/team/app").unwrap();
+    let mut badges = vec![
+        badge_for_npm("@scope/pkg"),
+        badge_for_crates("bdg"),
+        badge_for_npm_downloads("pkg"),
+        badge_for_crates_downloads("bdg"),
+        badge_for_crates_msrv("bdg"),
+        badge_for_rust_version("1.85"),
+        badge_for_rust_edition("2024"),
+        badge_for_node_engine(">=18"),
+        badge_for_pypi("pkg"),
+        badge_for_pypi_downloads("pkg"),
+        badge_for_pypi_python_versions("pkg"),
+        badge_for_go_reference("example.com/mod"),
+        badge_for_go_version("1.22"),
+        badge_for_go_report_card("example.com/mod"),
+        badge_for_jsr("@std/path"),
+        badge_for_jsr_score("@std/path"),
+        badge_for_docker_version("o/app"),
+        badge_for_docker_image_size("o/app"),
+        badge_for_docker_pulls("o/app"),
+        badge_for_ghcr_version("o/app"),
+        badge_for_ghcr_image_size("o/app"),
+        badge_for_docs_url("https://example.com/docs"),
+        badge_for_moonbit("o/mod", "1.0.0"),
+        badge_for_license_text("MIT OR Apache-2.0", None),
+        badge_for_release(&github),
+        badge_for_release(&gitlab),
+        badge_for_release(&codeberg),
+        badge_for_github_downloads(&github),
+        badge_for_github_stars(&github),
+        badge_for_github_forks(&github),
+        badge_for_github_issues(&github),
+        badge_for_github_pull_requests(&github),
+        badge_for_github_last_commit(&github),
+        badge_for_codacy(&github, "abc"),
+        badge_for_sonarcloud(&github, "o_r"),
+        badge_for_security_policy(&github, "SECURITY.md", "main"),
+        badge_for_gitlab_pipeline(&gitlab, "main"),
+        badge_for_gitlab_coverage(&gitlab, "main"),
+    ];
+    badges.extend(badge_for_license(&github));
+    badges.extend(badge_for_license(&gitlab));
+    badges.extend(badge_for_license(&codeberg));
+    badges.extend(badge_for_codecov(&github));
+    badges.extend(badge_for_codecov(&gitlab));
+    badges.extend(badge_for_coveralls(&github));
+    badges
+}
+
+#[test]
+fn ids_ignore_style_query_parameters() {
+    let style = [
+        ("style", "flat-square".to_string()),
+        ("logo", "rust".to_string()),
+        ("logoColor", "white".to_string()),
+        ("color", "#fe7d37".to_string()),
+        ("labelColor", "555".to_string()),
+    ];
+    for badge in sample_badges() {
+        let plain = parse_badge_line(&badge.render_markdown());
+        let styled = parse_badge_line(&badge.with_query_params(&style).render_markdown());
+        assert_ne!(plain.kind, "unknown", "{}", plain.raw);
+        assert_eq!(styled.kind, plain.kind, "{}", styled.raw);
+        assert_eq!(styled.id, plain.id, "{}", styled.raw);
+    }
+}
+
+#[test]
+fn ids_are_stable_across_badge_services() {
+    use bdg::badge_service::{BadgeBackend, BadgeHosts};
+    use bdg::config::Config;
+    use bdg::readme_badges::parse_badge_line_with;
+
+    for toml in [
+        "[badges]\nservice = \"badgen\"\n",
+        "[badges]\nservice = \"badgen\"\nurl = \"https://badgen.corp.example\"\n",
+        "[badges]\nurl = \"https://shields.corp.example\"\n",
+    ] {
+        let config: Config = toml::from_str(toml).unwrap();
+        let backend = BadgeBackend::from_config(&config.badges);
+        let hosts = BadgeHosts::from_config(&config);
+        let mut moved = 0;
+        for badge in sample_badges() {
+            let plain = parse_badge_line(&badge.render_markdown());
+            let rendered = backend.render(badge);
+            moved += usize::from(rendered.image_url.starts_with(&backend.url));
+            let parsed = parse_badge_line_with(&rendered.render_markdown(), &hosts);
+            assert_eq!(parsed.kind, plain.kind, "{toml}{}", parsed.raw);
+            assert_eq!(parsed.id, plain.id, "{toml}{}", parsed.raw);
+        }
+        assert!(moved > 20, "{toml}: {moved}");
+    }
+}
+
+#[test]
+fn html_badges_parse_like_their_markdown() {
+    for badge in sample_badges() {
+        let badge = badge.with_query_params(&[("style", "flat-square".to_string())]);
+        let markdown = parse_badge_line(&badge.render_markdown());
+        let html = parse_badge_line(&badge.render_html());
+        assert_eq!(html.kind, markdown.kind, "{}", html.raw);
+        assert_eq!(html.id, markdown.id, "{}", html.raw);
+        assert_eq!(html.label, markdown.label);
+        assert_eq!(html.image, markdown.image);
+        assert_eq!(html.link, markdown.link);
+    }
+}
+
+#[test]
+fn parses_hand_written_html_badges() {
+    let badge = parse_badge_line(
+        "  <A class=badge HREF='https://crates.io/crates/foo'><img src=\"https://img.shields.io/crates/v/foo.svg?style=flat&amp;logo=rust\" alt=\"crate &quot;foo&quot;\" /></a>",
+    );
+    assert_eq!(badge.kind, "crates_version");
+    assert_eq!(badge.id, "crates:foo");
+    assert_eq!(badge.label, "crate \"foo\"");
+    assert_eq!(
+        badge.image,
+        "https://img.shields.io/crates/v/foo.svg?style=flat&logo=rust"
+    );
+    assert_eq!(badge.link.as_deref(), Some("https://crates.io/crates/foo"));
+
+    let badge = parse_badge_line("<img src=\"https://img.shields.io/npm/v/pkg.svg\">");
+    assert_eq!(badge.id, "npm:pkg");
+    assert_eq!(badge.label, "");
+    assert_eq!(badge.link, None);
+
+    for line in [
+        "<p align=\"center\">",
+        "<img alt=\"no source\">",
+        "<a href=\"x\"><img src=\"y\"></a> trailing",
+    ] {
+        assert_eq!(parse_badge_line(line).kind, "unknown", "{line}");
+    }
+}
+
+#[test]
+fn rst_and_asciidoc_badges_parse_like_their_markdown() {
+    use bdg::config::RenderMode;
+    use bdg::readme::ReadmeFormat;
+
+    for badge in sample_badges() {
+        let markdown = parse_badge_line(&badge.render_markdown());
+        for format in [ReadmeFormat::Rst, ReadmeFormat::AsciiDoc] {
+            let parsed = parse_badge_line(&format.render_badge(&badge, RenderMode::Markdown));
+            assert_eq!(parsed.kind, markdown.kind, "{}", parsed.raw);
+            assert_eq!(parsed.id, markdown.id, "{}", parsed.raw);
+            assert_eq!(parsed.label, markdown.label, "{}", parsed.raw);
+            assert_eq!(parsed.image, markdown.image);
+            assert_eq!(parsed.link, markdown.link);
+        }
+    }
+}
+
+#[test]
+fn parses_hand_written_rst_and_asciidoc_badges() {
+    let badge = parse_badge_line(
+        ".. |pypi| image:: https://img.shields.io/pypi/v/pkg.svg\n    :alt: PyPI\n    :target: https://pypi.org/project/pkg/",
+    );
+    assert_eq!(badge.id, "pypi:pkg");
+    assert_eq!(badge.label, "PyPI");
+    assert_eq!(badge.link.as_deref(), Some("https://pypi.org/project/pkg/"));
+
+    let badge = parse_badge_line(".. image:: https://img.shields.io/npm/v/pkg.svg");
+    assert_eq!(badge.id, "npm:pkg");
+    assert_eq!(badge.label, "");
+    assert_eq!(badge.link, None);
+
+    let badge = parse_badge_line(
+        "image::https://img.shields.io/crates/v/foo.svg[alt=\"crate [foo\\]\",link=\"https://crates.io/crates/foo\",width=90]",
+    );
+    assert_eq!(badge.id, "crates:foo");
+    assert_eq!(badge.label, "crate [foo]");
+    assert_eq!(badge.link.as_deref(), Some("https://crates.io/crates/foo"));
+
+    for line in [
+        ".. note:: image:: https://img.shields.io/npm/v/pkg.svg",
+        "image:https://img.shields.io/npm/v/pkg.svg[x] trailing",
+        ".. image::",
+    ] {
+        assert_eq!(parse_badge_line(line).kind, "unknown", "{line}");
+    }
+}
+
+#[test]
+fn picture_badges_parse_like_their_markdown() {
+    use bdg::badges::render_picture_badge;
+    use bdg::config::Theme;
+
+    for badge in sample_badges() {
+        let markdown = parse_badge_line(&badge.render_markdown());
+        let variants = [
+            (Theme::Dark, format!("{}?color=black", badge.image_url)),
+            (Theme::Light, format!("{}?color=white", badge.image_url)),
+        ];
+        let line = render_picture_badge(
+            &badge.label,
+            &badge.image_url,
+            &variants,
+            badge.link_url.as_deref(),
+        );
+        let picture = parse_badge_line(&line);
+        assert_eq!(picture.kind, markdown.kind, "{line}");
+        assert_eq!(picture.id, markdown.id, "{line}");
+        assert_eq!(picture.label, markdown.label);
+        assert_eq!(picture.image, markdown.image);
+        assert_eq!(picture.link, markdown.link);
+    }
+
+    let badge = parse_badge_line(
+        "<PICTURE> <source media=\"(prefers-color-scheme: dark)\" srcset=\"https://img.shields.io/npm/v/pkg.svg?style=for-the-badge\"> <img src=\"https://img.shields.io/npm/v/pkg.svg\" alt=\"npm\"></Picture>",
+    );
+    assert_eq!(badge.id, "npm:pkg");
+    assert_eq!(badge.label, "npm");
+    for line in [
+        "<picture><img src=\"https://img.shields.io/npm/v/pkg.svg\">",
+        "<picture><p>x</p><img src=\"https://img.shields.io/npm/v/pkg.svg\"></picture>",
+        "<picture><source srcset=\"https://img.shields.io/npm/v/pkg.svg\"></picture>",
+    ] {
+        assert_eq!(parse_badge_line(line).kind, "unknown", "{line}");
+    }
+}
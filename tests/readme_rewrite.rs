@@ -1,5 +1,7 @@
+use bdg::config::{RenderConfig, RenderMode};
 use bdg::readme::{
-    ensure_marker_block, extract_managed_block, remove_marker_block, rewrite_marker_block,
+    ReadmeFormat, badge_block_lines, ensure_marker_block, extract_managed_block, marker_state,
+    remove_marker_block, rewrite_marker_block,
 };
 use std::fs;
 use tempfile::tempdir;
@@ -82,3 +84,120 @@ fn ignores_markers_inside_code_fence() {
     let updated = rewrite_marker_block(input, &["![a](a)".to_string()]).unwrap();
     assert!(updated.contains("![a](a)"));
 }
+
+#[test]
+fn centered_html_block_round_trips() {
+    let badges = vec![
+        "<a href=\"l\"><img alt=\"a\" src=\"a\"></a>".to_string(),
+        "<img alt=\"b\" src=\"b\">".to_string(),
+    ];
+    let render = RenderConfig {
+        mode: RenderMode::Html,
+        center: true,
+    };
+    let lines = badge_block_lines(badges.clone(), ReadmeFormat::Markdown, &render);
+    assert_eq!(
+        lines.first().map(String::as_str),
+        Some("<p align=\"center\">")
+    );
+    assert_eq!(lines.last().map(String::as_str), Some("</p>"));
+    let input = "# Title\n<!-- bdg:begin -->\n<!-- bdg:end -->\n";
+    let updated = rewrite_marker_block(input, &lines).unwrap();
+    assert_eq!(extract_managed_block(&updated), badges);
+
+    // Centering applies to HTML only; Markdown inside HTML is not rendered.
+    let markdown = RenderConfig {
+        mode: RenderMode::Markdown,
+        center: true,
+    };
+    assert_eq!(
+        badge_block_lines(badges.clone(), ReadmeFormat::Markdown, &markdown),
+        badges
+    );
+    assert!(badge_block_lines(Vec::new(), ReadmeFormat::Markdown, &render).is_empty());
+}
+
+#[test]
+fn rst_markers_go_below_the_title() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("README.rst");
+    fs::write(&path, "=====\nTitle\n=====\nIntro\n").unwrap();
+    let content = ensure_marker_block(&path).unwrap();
+    assert_eq!(
+        content,
+        "=====\nTitle\n=====\n\n.. bdg:begin\n.. bdg:end\n\nIntro\n"
+    );
+
+    let badge = ReadmeFormat::Rst.render_parts(
+        "ci",
+        "https://ci/badge.svg",
+        Some("https://ci"),
+        RenderMode::Html,
+    );
+    assert_eq!(
+        badge,
+        ".. image:: https://ci/badge.svg\n   :target: https://ci\n   :alt: ci"
+    );
+    let updated = rewrite_marker_block(&content, &[badge.clone(), badge.clone()]).unwrap();
+    assert!(
+        updated
+            .contains("\n.. bdg:begin\n.. image:: https://ci/badge.svg\n   :target: https://ci\n")
+    );
+    assert_eq!(extract_managed_block(&updated), vec![badge.clone(), badge]);
+}
+
+#[test]
+fn asciidoc_markers_go_below_the_header() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("README.adoc");
+    fs::write(&path, "= Title\n:toc:\n\nIntro\n").unwrap();
+    let content = ensure_marker_block(&path).unwrap();
+    assert_eq!(
+        content,
+        "= Title\n:toc:\n\n// bdg:begin\n// bdg:end\n\nIntro\n"
+    );
+    assert_eq!(
+        ReadmeFormat::AsciiDoc.render_parts(
+            "a, b",
+            "https://i/x.svg",
+            Some("https://l"),
+            RenderMode::Markdown
+        ),
+        "image:https://i/x.svg[\"a, b\",link=https://l]"
+    );
+}
+
+#[test]
+fn format_follows_markers_before_extension() {
+    assert_eq!(
+        ReadmeFormat::from_path(std::path::Path::new("docs/README.ADOC")),
+        ReadmeFormat::AsciiDoc
+    );
+    // Markdown markers shown as an example do not count next to AsciiDoc ones.
+    let adoc =
+        "= T\n\n----\n<!-- bdg:begin -->\n<!-- bdg:end -->\n----\n\n// bdg:begin\n// bdg:end\n";
+    assert_eq!(
+        ReadmeFormat::from_markers(adoc),
+        Some(ReadmeFormat::AsciiDoc)
+    );
+    assert!(marker_state(adoc).is_valid());
+    let rst = "Intro\n\n.. bdg:begin\n.. bdg:end\n";
+    assert_eq!(
+        ReadmeFormat::of(std::path::Path::new("README.md"), rst),
+        ReadmeFormat::Rst
+    );
+    assert_eq!(ReadmeFormat::from_markers("# Title\n"), None);
+}
+
+#[test]
+fn centering_is_markdown_only() {
+    let render = RenderConfig {
+        mode: RenderMode::Html,
+        center: true,
+    };
+    let badges = vec!["image:https://i/x.svg[x]".to_string()];
+    assert_eq!(
+        badge_block_lines(badges.clone(), ReadmeFormat::AsciiDoc, &render),
+        badges
+    );
+}
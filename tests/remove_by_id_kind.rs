@@ -51,6 +51,20 @@ fn remove_by_kind_multiple() {
     assert!(updated.contains("crates/v/foo"));
 }
 
+#[test]
+fn remove_by_jsr_kind() {
+    let lines = vec![
+        "[![JSR](https://jsr.io/badges/@std/path)](https://jsr.io/@std/path)",
+        "[![JSR score](https://jsr.io/badges/@std/path/score)](https://jsr.io/@std/path)",
+    ];
+    let content = wrap_block(&lines);
+    let outcome =
+        remove_block_lines_by_id_kind(&content, &[], &["jsr_score".to_string()], false).unwrap();
+    let updated = rewrite_marker_block_lines(&content, &outcome.remaining).unwrap();
+    assert!(!updated.contains("/score"));
+    assert!(updated.contains("jsr.io/badges/@std/path)"));
+}
+
 #[test]
 fn unknown_can_be_removed_by_id() {
     let line = "[![label][imgref]](linkref)";
@@ -96,3 +110,109 @@ fn does_not_remove_inside_code_fence() {
     assert!(updated.contains("crates/v/foo"));
     assert!(!updated.contains("crates/v/bar"));
 }
+
+#[test]
+fn remove_ci_kind_covers_every_forge() {
+    let lines = vec![
+        "[![CI](https://github.com/OWNER/REPO/actions/workflows/ci.yaml/badge.svg)](https://github.com/OWNER/REPO/actions/workflows/ci.yaml)",
+        "[![CI](https://codeberg.org/OWNER/REPO/actions/workflows/ci.yml/badge.svg)](https://codeberg.org/OWNER/REPO/actions?workflow=ci.yml)",
+        "[![Woodpecker CI](https://ci.codeberg.org/api/badges/OWNER/REPO/status.svg)](https://ci.codeberg.org/repos/OWNER/REPO)",
+        "[![pipeline](https://gitlab.com/OWNER/REPO/badges/main/pipeline.svg)](https://gitlab.com/OWNER/REPO/-/commits/main)",
+        "![crate](https://img.shields.io/crates/v/foo.svg)",
+    ];
+    let content = wrap_block(&lines);
+    let outcome = remove_block_lines_by_id_kind(&content, &[], &["ci".to_string()], false).unwrap();
+    assert_eq!(outcome.removed, 4);
+    assert_eq!(outcome.removed_kinds.get("forgejo_actions"), Some(&1));
+    assert_eq!(outcome.removed_kinds.get("woodpecker"), Some(&1));
+    let updated = rewrite_marker_block_lines(&content, &outcome.remaining).unwrap();
+    assert!(updated.contains("crates/v/foo"));
+    assert!(!updated.contains("status.svg"));
+}
+
+#[test]
+fn remove_targets_each_ci_service_kind() {
+    let lines = vec![
+        "[![CircleCI](https://dl.circleci.com/status-badge/img/gh/OWNER/REPO/tree/main.svg?style=svg)](https://dl.circleci.com/status-badge/redirect/gh/OWNER/REPO/tree/main)",
+        "[![Travis CI](https://app.travis-ci.com/OWNER/REPO.svg?branch=main)](https://app.travis-ci.com/OWNER/REPO)",
+        "[![Azure Pipelines](https://dev.azure.com/org/proj/_apis/build/status/OWNER.REPO?branchName=main)](https://dev.azure.com/org/proj/_build)",
+        "[![Buildkite](https://badge.buildkite.com/abc123.svg?branch=main)](https://buildkite.com/org/app)",
+        "[![Jenkins](https://ci.example.com/buildStatus/icon?job=app)](https://ci.example.com/job/app/)",
+        "![crate](https://img.shields.io/crates/v/foo.svg)",
+    ];
+    let content = wrap_block(&lines);
+    let outcome =
+        remove_block_lines_by_id_kind(&content, &[], &["circleci".to_string()], false).unwrap();
+    assert_eq!(outcome.removed, 1);
+    assert!(
+        outcome
+            .remaining
+            .iter()
+            .all(|line| !line.contains("circleci"))
+    );
+
+    let outcome = remove_block_lines_by_id_kind(
+        &content,
+        &["ci:jenkins".to_string()],
+        &["travis".to_string()],
+        false,
+    )
+    .unwrap();
+    assert_eq!(outcome.removed, 2);
+
+    let outcome = remove_block_lines_by_id_kind(&content, &[], &["ci".to_string()], false).unwrap();
+    assert_eq!(outcome.removed, 5);
+    for kind in [
+        "circleci",
+        "travis",
+        "azure_pipelines",
+        "buildkite",
+        "jenkins",
+    ] {
+        assert_eq!(outcome.removed_kinds.get(kind), Some(&1), "{kind}");
+    }
+}
+
+#[test]
+fn remove_coverage_kind_targets_every_provider() {
+    let lines = vec![
+        "[![codecov](https://img.shields.io/codecov/c/github/OWNER/REPO.svg)](https://codecov.io/gh/OWNER/REPO)",
+        "[![coveralls](https://img.shields.io/coveralls/github/OWNER/REPO.svg)](https://coveralls.io/github/OWNER/REPO)",
+        "[![codacy](https://img.shields.io/codacy/coverage/abc123.svg)](https://app.codacy.com/gh/OWNER/REPO/dashboard)",
+        "[![sonarcloud](https://img.shields.io/sonar/coverage/OWNER_REPO.svg?server=https%3A%2F%2Fsonarcloud.io)](https://sonarcloud.io/summary/new_code?id=OWNER_REPO)",
+        "![crate](https://img.shields.io/crates/v/foo.svg)",
+    ];
+    let content = wrap_block(&lines);
+    let outcome =
+        remove_block_lines_by_id_kind(&content, &["coverage:codacy".to_string()], &[], false)
+            .unwrap();
+    assert_eq!(outcome.removed, 1);
+
+    let outcome =
+        remove_block_lines_by_id_kind(&content, &[], &["coverage".to_string()], false).unwrap();
+    assert_eq!(outcome.removed, 4);
+    assert_eq!(outcome.remaining.len(), 1);
+    for kind in ["coverage", "coveralls", "codacy", "sonarcloud"] {
+        assert_eq!(outcome.removed_kinds.get(kind), Some(&1), "{kind}");
+    }
+}
+
+#[test]
+fn remove_security_kind_targets_every_security_badge() {
+    let lines = vec![
+        "[![OpenSSF Scorecard](https://api.scorecard.dev/projects/github.com/OWNER/REPO/badge)](https://scorecard.dev/viewer/?uri=github.com/OWNER/REPO)",
+        "[![OpenSSF Best Practices](https://www.bestpractices.dev/projects/1234/badge)](https://www.bestpractices.dev/projects/1234)",
+        "[![security policy](https://img.shields.io/badge/security-policy-blue.svg)](https://github.com/OWNER/REPO/security/policy)",
+        "![crate](https://img.shields.io/crates/v/foo.svg)",
+    ];
+    let content = wrap_block(&lines);
+    let outcome =
+        remove_block_lines_by_id_kind(&content, &[], &["security".to_string()], false).unwrap();
+    assert_eq!(outcome.removed, 3);
+    assert!(
+        outcome
+            .remaining
+            .iter()
+            .all(|line| !line.contains("security"))
+    );
+}
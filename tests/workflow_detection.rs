@@ -1,5 +1,8 @@
 use bdg::badges::badge_for_workflow;
-use bdg::workflows::detect_workflows;
+use bdg::forge::parse_repo_url;
+use bdg::workflows::{
+    ContainerRegistry, detect_container_images, detect_dockerfiles, detect_workflows,
+};
 use std::fs;
 
 #[test]
@@ -21,7 +24,8 @@ fn detects_yaml_and_yml_workflows() {
 
 #[test]
 fn workflow_badge_uses_actual_workflow_file_extension() {
-    let badge = badge_for_workflow("f4ah6o", "codegraph", "rust.yml");
+    let repo = parse_repo_url("https://github.com/f4ah6o/codegraph").unwrap();
+    let badge = badge_for_workflow(&repo, "rust.yml", None, None);
 
     assert_eq!(
         badge.image_url,
@@ -32,3 +36,155 @@ fn workflow_badge_uses_actual_workflow_file_extension() {
         Some("https://github.com/f4ah6o/codegraph/actions/workflows/rust.yml")
     );
 }
+
+#[test]
+fn workflow_badge_scopes_to_branch_and_event() {
+    let repo = parse_repo_url("https://github.com/f4ah6o/codegraph").unwrap();
+    let badge = badge_for_workflow(&repo, "rust.yml", Some("release/1.x"), Some("push"));
+
+    assert_eq!(
+        badge.image_url,
+        "https://github.com/f4ah6o/codegraph/actions/workflows/rust.yml/badge.svg?branch=release%2F1.x&event=push"
+    );
+    assert_eq!(
+        badge.link_url.as_deref(),
+        Some(
+            "https://github.com/f4ah6o/codegraph/actions/workflows/rust.yml?query=branch%3Arelease%2F1.x+event%3Apush"
+        )
+    );
+}
+
+#[test]
+fn detects_ghcr_image_from_build_push_workflow_env() {
+    let temp = tempfile::tempdir().unwrap();
+    let workflows_dir = temp.path().join(".github/workflows");
+    fs::create_dir_all(&workflows_dir).unwrap();
+    fs::write(temp.path().join("Dockerfile"), "FROM scratch\n").unwrap();
+    fs::write(
+        workflows_dir.join("docker.yml"),
+        r#"name: docker
+on: push
+env:
+  REGISTRY: ghcr.io
+  IMAGE_NAME: ${{ github.repository }}
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: docker/metadata-action@v5
+        id: meta
+        with:
+          images: ${{ env.REGISTRY }}/${{ env.IMAGE_NAME }}
+      - uses: docker/build-push-action@v6
+        with:
+          push: true
+          tags: ${{ steps.meta.outputs.tags }}
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(detect_dockerfiles(temp.path()), vec!["Dockerfile"]);
+    let images = detect_container_images(temp.path(), Some("F4ah6o"), Some("bdg-rs"));
+
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].registry, ContainerRegistry::Ghcr);
+    assert_eq!(images[0].name, "f4ah6o/bdg-rs");
+    assert_eq!(images[0].workflow, "docker.yml");
+
+    assert!(detect_container_images(temp.path(), None, None).is_empty());
+}
+
+#[test]
+fn detects_docker_hub_image_from_tags_block() {
+    let temp = tempfile::tempdir().unwrap();
+    let workflows_dir = temp.path().join(".github/workflows");
+    fs::create_dir_all(&workflows_dir).unwrap();
+    fs::create_dir_all(temp.path().join("docker")).unwrap();
+    fs::write(temp.path().join("docker/app.Dockerfile"), "FROM scratch\n").unwrap();
+    fs::write(
+        workflows_dir.join("release.yml"),
+        r#"jobs:
+  push:
+    steps:
+      - uses: docker/build-push-action@v6
+        with:
+          file: docker/app.Dockerfile
+          tags: |
+            example/app:latest
+            example/app:${{ github.ref_name }}
+      - run: echo done
+"#,
+    )
+    .unwrap();
+    fs::write(
+        workflows_dir.join("ci.yml"),
+        "jobs:\n  test:\n    steps:\n      - run: docker build -t example/other .\n",
+    )
+    .unwrap();
+
+    let images = detect_container_images(temp.path(), None, None);
+
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].registry, ContainerRegistry::DockerHub);
+    assert_eq!(images[0].name, "example/app");
+}
+
+#[test]
+fn reads_images_only_from_docker_action_inputs() {
+    let temp = tempfile::tempdir().unwrap();
+    let workflows_dir = temp.path().join(".github/workflows");
+    fs::create_dir_all(&workflows_dir).unwrap();
+    fs::write(temp.path().join("Dockerfile"), "FROM scratch\n").unwrap();
+    fs::write(
+        workflows_dir.join("docker.yml"),
+        r#"env:
+  BASE_IMAGE: ghcr.io/other/base
+jobs:
+  build:
+    services:
+      cache:
+        image: ghcr.io/other/cache:7
+    steps:
+      - run: docker pull ghcr.io/other/tool:latest
+      - uses: docker/metadata-action@v5
+        env:
+          IMAGE: ghcr.io/team/app
+        with:
+          images: |
+            name=${{ env.IMAGE }},enable=true
+            name=team/app-mirror
+          tags: type=semver,pattern={{version}}
+      - uses: docker/build-push-action@v6
+        with:
+          build-args: BASE=${{ env.BASE_IMAGE }}
+"#,
+    )
+    .unwrap();
+
+    let images = detect_container_images(temp.path(), None, None)
+        .into_iter()
+        .map(|image| (image.registry, image.name))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        images,
+        [
+            (ContainerRegistry::Ghcr, "team/app".to_string()),
+            (ContainerRegistry::DockerHub, "team/app-mirror".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn ignores_build_push_workflow_without_dockerfile() {
+    let temp = tempfile::tempdir().unwrap();
+    let workflows_dir = temp.path().join(".github/workflows");
+    fs::create_dir_all(&workflows_dir).unwrap();
+    fs::write(
+        workflows_dir.join("docker.yml"),
+        "steps:\n  - uses: docker/build-push-action@v6\n    with:\n      tags: ghcr.io/example/app:latest\n",
+    )
+    .unwrap();
+
+    assert!(detect_container_images(temp.path(), None, None).is_empty());
+}